@@ -14,10 +14,14 @@ impl Comments {
         self.leading.insert(pos, cmt);
     }
 
+    /// Adding a comment again is a no-op, so that a parser can lex the same
+    /// input multiple times.
     pub fn add_trailing(&self, pos: BytePos, cmt: Comment) {
         self.trailing.alter(pos, |v| match v {
             Some(mut value) => {
-                value.push(cmt);
+                if !value.contains(&cmt) {
+                    value.push(cmt);
+                }
                 Some(value)
             }
             None => Some(vec![cmt]),
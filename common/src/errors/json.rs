@@ -0,0 +1,169 @@
+//! Emits diagnostics as JSON, one object per line.
+//!
+//! ```json
+//! {"message":"with statement","code":null,"level":"error","spans":[{"file":"a.js",...}],"children":[]}
+//! ```
+use super::{
+    emitter::Emitter, Applicability, CodeSuggestion, DiagnosticBuilder, DiagnosticId,
+    SourceMapperDyn, SubDiagnostic,
+};
+use serde::Serialize;
+use serde_json;
+use std::{
+    io::{self, Write},
+    sync::Arc,
+};
+use syntax_pos::{MultiSpan, Span};
+
+pub struct JsonEmitter {
+    dst: Box<dyn Write + Send>,
+    cm: Arc<SourceMapperDyn>,
+}
+
+impl JsonEmitter {
+    pub fn stderr(cm: Arc<SourceMapperDyn>) -> Self {
+        Self::new(Box::new(io::stderr()), cm)
+    }
+
+    pub fn new(dst: Box<dyn Write + Send>, cm: Arc<SourceMapperDyn>) -> Self {
+        JsonEmitter { dst, cm }
+    }
+
+    fn diagnostic(&self, db: &DiagnosticBuilder) -> Diagnostic {
+        let children = db
+            .children
+            .iter()
+            .map(|c| self.sub_diagnostic(c))
+            .chain(db.suggestions.iter().map(|s| self.suggestion(s)))
+            .collect();
+
+        Diagnostic {
+            message: db.message(),
+            code: db.code.as_ref().map(|code| match *code {
+                DiagnosticId::Error(ref s) | DiagnosticId::Lint(ref s) => s.clone(),
+            }),
+            level: db.level.to_str(),
+            spans: self.spans(&db.span),
+            children,
+        }
+    }
+
+    fn sub_diagnostic(&self, sub: &SubDiagnostic) -> Diagnostic {
+        Diagnostic {
+            message: sub.message(),
+            code: None,
+            level: sub.level.to_str(),
+            spans: self.spans(sub.render_span.as_ref().unwrap_or(&sub.span)),
+            children: vec![],
+        }
+    }
+
+    /// Suggestions are reported as help messages with replacements.
+    fn suggestion(&self, sugg: &CodeSuggestion) -> Diagnostic {
+        let spans = sugg
+            .substitutions
+            .iter()
+            .flat_map(|s| s.parts.iter())
+            .filter(|part| !part.span.is_dummy())
+            .map(|part| DiagnosticSpan {
+                suggested_replacement: Some(part.snippet.clone()),
+                suggestion_applicability: Some(applicability(sugg.applicability)),
+                ..self.span(part.span, true, None)
+            })
+            .collect();
+
+        Diagnostic {
+            message: sugg.msg.clone(),
+            code: None,
+            level: "help",
+            spans,
+            children: vec![],
+        }
+    }
+
+    fn spans(&self, span: &MultiSpan) -> Vec<DiagnosticSpan> {
+        span.span_labels()
+            .into_iter()
+            .filter(|l| !l.span.is_dummy())
+            .map(|l| self.span(l.span, l.is_primary, l.label))
+            .collect()
+    }
+
+    fn span(&self, span: Span, is_primary: bool, label: Option<String>) -> DiagnosticSpan {
+        let start = self.cm.lookup_char_pos(span.lo());
+        let end = self.cm.lookup_char_pos(span.hi());
+
+        DiagnosticSpan {
+            file: start.file.name.to_string(),
+            byte_start: (span.lo() - start.file.start_pos).0,
+            byte_end: (span.hi() - start.file.start_pos).0,
+            line_start: start.line,
+            line_end: end.line,
+            column_start: start.col.0 + 1,
+            column_end: end.col.0 + 1,
+            is_primary,
+            label,
+            suggested_replacement: None,
+            suggestion_applicability: None,
+        }
+    }
+}
+
+impl Emitter for JsonEmitter {
+    fn emit(&mut self, db: &DiagnosticBuilder) {
+        let diagnostic = self.diagnostic(db);
+
+        let result = serde_json::to_writer(&mut self.dst, &diagnostic)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(self.dst))
+            .and_then(|()| self.dst.flush());
+        if let Err(e) = result {
+            panic!("failed to emit error: {}", e)
+        }
+    }
+
+    fn should_show_explain(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Diagnostic {
+    message: String,
+    code: Option<String>,
+    level: &'static str,
+    spans: Vec<DiagnosticSpan>,
+    /// Notes, help messages and suggestions.
+    children: Vec<Diagnostic>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticSpan {
+    file: String,
+    /// Offset from the start of the file.
+    byte_start: u32,
+    byte_end: u32,
+    /// 1-based
+    line_start: usize,
+    line_end: usize,
+    /// 1-based, in characters.
+    column_start: usize,
+    column_end: usize,
+    is_primary: bool,
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suggested_replacement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suggestion_applicability: Option<&'static str>,
+}
+
+fn applicability(a: Applicability) -> &'static str {
+    match a {
+        Applicability::MachineApplicable => "machine-applicable",
+        Applicability::HasPlaceholders => "has-placeholders",
+        Applicability::MaybeIncorrect => "maybe-incorrect",
+        Applicability::Unspecified => "unspecified",
+    }
+}
@@ -13,6 +13,7 @@ pub use self::{
     diagnostic::{Diagnostic, DiagnosticId, DiagnosticStyledString, SubDiagnostic},
     diagnostic_builder::DiagnosticBuilder,
     emitter::{ColorConfig, Emitter, EmitterWriter},
+    json::JsonEmitter,
 };
 use crate::{
     rustc_data_structures::stable_hasher::StableHasher,
@@ -34,6 +35,7 @@ use termcolor::{Color, ColorSpec};
 mod diagnostic;
 mod diagnostic_builder;
 pub mod emitter;
+mod json;
 mod lock;
 mod snippet;
 mod styled_buffer;
@@ -357,6 +359,20 @@ impl Handler {
         Handler::with_emitter_and_flags(emitter, flags)
     }
 
+    /// Creates a handler which prints diagnostics to stderr as JSON, one
+    /// object per line.
+    pub fn with_json_emitter(
+        can_emit_warnings: bool,
+        treat_err_as_bug: bool,
+        cm: Arc<SourceMapperDyn>,
+    ) -> Handler {
+        Handler::with_emitter(
+            can_emit_warnings,
+            treat_err_as_bug,
+            Box::new(JsonEmitter::stderr(cm)),
+        )
+    }
+
     pub fn with_emitter(
         can_emit_warnings: bool,
         treat_err_as_bug: bool,
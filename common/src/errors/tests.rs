@@ -73,3 +73,77 @@ fn test() {
         .emit();
     })
 }
+
+#[derive(Clone, Default)]
+struct Buf(Arc<::std::sync::Mutex<Vec<u8>>>);
+
+impl io::Write for Buf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn json() {
+    let cm = SourceMap::with_file_loader(box MyFileLoader, FilePathMapping::empty());
+    let file_map = cm
+        .load_file(Path::new("tmp.js").into())
+        .expect("failed to load tmp.js");
+    let with_span = Span::new(
+        file_map.start_pos + BytePos(22),
+        file_map.start_pos + BytePos(26),
+        Default::default(),
+    );
+
+    let buf = Buf::default();
+    let handler = Handler::with_emitter(
+        true,
+        false,
+        box JsonEmitter::new(box buf.clone(), Arc::new(cm)),
+    );
+
+    ::syntax_pos::GLOBALS.set(&::syntax_pos::Globals::new(), || {
+        DiagnosticBuilder::new_with_code(
+            &handler,
+            super::Warning,
+            Some(DiagnosticId::Lint("no-with".into())),
+            "with statement",
+        )
+        .span(with_span)
+        .multipart_suggestion_with_applicability(
+            "remove",
+            vec![(with_span, String::new())],
+            Applicability::MaybeIncorrect,
+        )
+        .emit();
+    });
+
+    let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+    assert_eq!(output.lines().count(), 1);
+    let json: ::serde_json::Value = ::serde_json::from_str(&output).unwrap();
+
+    assert_eq!(json["message"], "with statement");
+    assert_eq!(json["code"], "no-with");
+    assert_eq!(json["level"], "warning");
+
+    let span = &json["spans"][0];
+    assert_eq!(span["file"], "tmp.js");
+    assert_eq!(span["byteStart"], 22);
+    assert_eq!(span["byteEnd"], 26);
+    assert_eq!(span["lineStart"], 3);
+    assert_eq!(span["columnStart"], 5);
+    assert_eq!(span["columnEnd"], 9);
+    assert_eq!(span["isPrimary"], true);
+
+    let help = &json["children"][0];
+    assert_eq!(help["level"], "help");
+    assert_eq!(help["spans"][0]["suggestedReplacement"], "");
+    assert_eq!(
+        help["spans"][0]["suggestionApplicability"],
+        "maybe-incorrect"
+    );
+}
@@ -10,6 +10,7 @@ extern crate log;
 extern crate parking_lot;
 extern crate scoped_tls;
 extern crate serde;
+extern crate serde_json;
 extern crate string_cache;
 extern crate termcolor;
 extern crate unicode_width;
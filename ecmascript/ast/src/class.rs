@@ -103,6 +103,10 @@ macro_rules! property {
 
             #[serde(default)]
             pub definite: bool,
+
+            /// Typescript extension.
+            #[serde(default)]
+            pub declare: bool,
         }
     };
 }
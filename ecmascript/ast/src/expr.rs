@@ -143,6 +143,20 @@ pub enum Expr {
 
     #[tag("TsOptionalChainingExpression")]
     TsOptChain(TsOptChain),
+
+    #[tag("Invalid")]
+    Invalid(Invalid),
+}
+
+/// Placeholder for an expression which failed to parse.
+///
+/// This is created only if the parser recovers from syntax errors, and it is
+/// used as the expression of an expression statement which replaces the
+/// statement which failed to parse.
+#[ast_node("Invalid")]
+#[derive(Copy)]
+pub struct Invalid {
+    pub span: Span,
 }
 
 #[ast_node("ThisExpression")]
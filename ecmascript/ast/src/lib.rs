@@ -23,9 +23,9 @@ pub use self::{
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
     expr::{
         ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, ClassExpr,
-        CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr, NewExpr,
-        ObjectLit, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr, SpreadElement, Super, TaggedTpl,
-        ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+        CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, Invalid, MemberExpr, MetaPropExpr,
+        NewExpr, ObjectLit, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr, SpreadElement, Super,
+        TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
     },
     function::{Function, PatOrTsParamProp},
     ident::{Ident, IdentExt, PrivateName},
@@ -36,7 +36,7 @@ pub use self::{
         JSXOpeningFragment, JSXSpreadChild, JSXText,
     },
     lit::{Bool, Lit, Null, Number, Regex, RegexFlags, Str},
-    module::{Module, ModuleItem, Script},
+    module::{Module, ModuleItem, Program, Script},
     module_decl::{
         DefaultDecl, DefaultExportSpecifier, ExportAll, ExportDecl, ExportDefaultDecl,
         ExportDefaultExpr, ExportSpecifier, ImportDecl, ImportDefault, ImportSpecific,
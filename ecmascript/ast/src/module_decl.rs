@@ -62,6 +62,10 @@ pub struct ImportDecl {
 
     #[serde(rename = "source")]
     pub src: Str,
+
+    /// `import type` and `import typeof` of flow.
+    #[serde(default)]
+    pub type_only: bool,
 }
 
 /// `export * from 'mod'`
@@ -9,6 +9,10 @@ macro_rules! write_comments {
         };
 
         for cmt in cmts.iter() {
+            if !$e.cfg.comment_retention.retains(cmt) {
+                continue;
+            }
+
             match cmt.kind {
                 CommentKind::Line => {
                     if $prefix_space {
@@ -16,6 +20,13 @@ macro_rules! write_comments {
                     }
                     $e.wr.write_comment(cmt.span, "//")?;
                     $e.wr.write_comment(cmt.span, &cmt.text)?;
+                    // Line comments at the end of a file don't have a line
+                    // break, and code emitted after them should not be
+                    // commented out.
+                    match cmt.text.chars().last() {
+                        Some('\n') | Some('\r') | Some('\u{2028}') | Some('\u{2029}') => {}
+                        _ => $e.wr.write_line()?,
+                    }
                 }
                 CommentKind::Block => {
                     if $prefix_space {
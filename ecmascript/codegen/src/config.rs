@@ -1,4 +1,72 @@
+use swc_common::comments::{Comment, CommentKind};
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Config {
     pub minify: bool,
+
+    /// Quote character used for string literals.
+    ///
+    /// The other one is used if the string contains the preferred one. String
+    /// literals which are not modified are printed as written in the source if
+    /// they use the preferred quote.
+    pub quote_style: QuoteStyle,
+
+    /// Emit a trailing comma after the last element of multi-line array
+    /// literals, object literals and enums.
+    ///
+    /// Argument lists are excluded, as trailing commas in them require es2017.
+    pub trailing_comma: bool,
+
+    /// If set, argument lists of call and new expressions which don't fit in
+    /// this width are broken into one argument per line.
+    pub line_width: Option<usize>,
+
+    /// Escape non-ascii characters in identifiers, string literals, template
+    /// literals, regular expressions and jsx text.
+    pub ascii_only: bool,
+
+    /// Comments to print, if comments are given to the emitter.
+    pub comment_retention: CommentRetention,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    Single,
+    Double,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Single
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentRetention {
+    All,
+    /// Legal comments, which are block comments starting with `/*!` and
+    /// comments containing `@license`, `@preserve` or `@cc_on`.
+    Some,
+    None,
+}
+
+impl Default for CommentRetention {
+    fn default() -> Self {
+        CommentRetention::All
+    }
+}
+
+impl CommentRetention {
+    pub fn retains(self, cmt: &Comment) -> bool {
+        match self {
+            CommentRetention::All => true,
+            CommentRetention::Some => {
+                (cmt.kind == CommentKind::Block && cmt.text.starts_with('!'))
+                    || cmt.text.contains("@license")
+                    || cmt.text.contains("@preserve")
+                    || cmt.text.contains("@cc_on")
+            }
+            CommentRetention::None => false,
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use super::{Emitter, Result};
+use super::{escape_non_ascii_jsx, Emitter, Result};
 use crate::list::ListFormat;
 use swc_common::Spanned;
 use swc_ecma_ast::*;
@@ -23,13 +23,17 @@ impl<'a> Emitter<'a> {
         punct!("<");
         emit!(node.name);
 
-        self.emit_list(
-            node.span(),
-            Some(&node.attrs),
-            ListFormat::JsxElementAttributes,
-        )?;
+        if !node.attrs.is_empty() {
+            space!();
+            self.emit_list(
+                node.span(),
+                Some(&node.attrs),
+                ListFormat::JsxElementAttributes,
+            )?;
+        }
 
         if node.self_closing {
+            formatting_space!();
             punct!("/");
         }
         punct!(">");
@@ -49,8 +53,29 @@ impl<'a> Emitter<'a> {
         emit!(node.name);
 
         if let Some(ref value) = node.value {
-            punct!(" ");
-            emit!(value);
+            punct!("=");
+            match **value {
+                // Escape sequences are not allowed in jsx strings.
+                Expr::Lit(Lit::Str(ref s)) if !is_jsx_str(&s.value) => {
+                    punct!("{");
+                    emit!(value);
+                    punct!("}");
+                }
+                Expr::Lit(Lit::Str(ref s)) if self.cfg.ascii_only && !s.value.is_ascii() => {
+                    let quote = if s.value.contains('"') { "'" } else { "\"" };
+                    punct!(quote);
+                    self.wr.write_str_lit(s.span, &escape_non_ascii_jsx(&s.value))?;
+                    punct!(quote);
+                }
+                Expr::Lit(Lit::Str(..)) | Expr::JSXElement(..) | Expr::JSXFragment(..) => {
+                    emit!(value)
+                }
+                _ => {
+                    punct!("{");
+                    emit!(value);
+                    punct!("}");
+                }
+            }
         }
     }
 
@@ -66,7 +91,11 @@ impl<'a> Emitter<'a> {
     pub fn emit_jsx_attr_or_spread(&mut self, node: &JSXAttrOrSpread) -> Result {
         match *node {
             JSXAttrOrSpread::JSXAttr(ref n) => emit!(n),
-            JSXAttrOrSpread::SpreadElement(ref n) => emit!(n),
+            JSXAttrOrSpread::SpreadElement(ref n) => {
+                punct!("{");
+                emit!(n);
+                punct!("}");
+            }
         }
     }
 
@@ -146,7 +175,11 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_jsx_text(&mut self, node: &JSXText) -> Result {
-        self.emit_js_word(node.span(), &node.value)?;
+        if self.cfg.ascii_only {
+            self.wr.write_str_lit(node.span(), &escape_non_ascii_jsx(&node.value))?;
+        } else {
+            self.emit_js_word(node.span(), &node.value)?;
+        }
     }
 
     #[emitter]
@@ -164,3 +197,37 @@ impl<'a> Emitter<'a> {
         }
     }
 }
+
+/// Returns true if `value` can be printed as a jsx string without escaping.
+fn is_jsx_str(value: &str) -> bool {
+    !value.chars().any(|c| match c {
+        '"' | '\\' | '\u{2028}' | '\u{2029}' => true,
+        _ => c.is_ascii_control(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::assert_min_jsx;
+
+    #[test]
+    fn namespaced_name() {
+        assert_min_jsx("<svg:rect xlink:href='a' />;", "<svg:rect xlink:href='a'/>;");
+        assert_min_jsx("<a:b></a:b>;", "<a:b></a:b>;");
+    }
+
+    #[test]
+    fn attrs() {
+        assert_min_jsx("<a b={c} {...d} />;", "<a b={c} {...d}/>;");
+        assert_min_jsx("<A.B c=<d /> />;", "<A.B c=<d/>/>;");
+    }
+
+    #[test]
+    fn attr_str_with_escapes() {
+        assert_min_jsx(r#"<a b={"c"} />;"#, "<a b='c'/>;");
+        assert_min_jsx(r#"<a b={"c\"d"} />;"#, r#"<a b={'c"d'}/>;"#);
+        assert_min_jsx(r#"<a b={"c\\d"} />;"#, r#"<a b={'c\\d'}/>;"#);
+        assert_min_jsx(r#"<a b={"c\nd"} />;"#, r#"<a b={'c\nd'}/>;"#);
+        assert_min_jsx(r#"<a b='c"d' />;"#, r#"<a b={'c"d'}/>;"#);
+    }
+}
@@ -13,14 +13,14 @@ extern crate swc_ecma_ast;
 #[cfg(test)]
 extern crate testing;
 
-pub use self::config::Config;
+pub use self::config::{CommentRetention, Config, QuoteStyle};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
     util::{SourceMapperExt, SpanExt, StartsWithAlphaNum},
 };
 use hashbrown::HashSet;
-use std::{io, sync::Arc};
+use std::{borrow::Cow, io, iter::Peekable, str::Chars, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{comments::Comments, BytePos, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -48,6 +48,10 @@ pub trait Handlers {
     // fn on_after_emit_token(&mut self, _node: &Any) {}
 }
 
+struct NoopHandlers;
+
+impl Handlers for NoopHandlers {}
+
 pub trait Node: Spanned {
     fn emit_with(&self, e: &mut Emitter) -> Result;
 }
@@ -89,6 +93,14 @@ impl<'a> Emitter<'a> {
         Ok(())
     }
 
+    #[emitter]
+    pub fn emit_program(&mut self, node: &Program) -> Result {
+        match *node {
+            Program::Module(ref m) => emit!(m),
+            Program::Script(ref s) => emit!(s),
+        }
+    }
+
     #[emitter]
     pub fn emit_module(&mut self, node: &Module) -> Result {
         if let Some(ref shebang) = node.shebang {
@@ -179,6 +191,11 @@ impl<'a> Emitter<'a> {
         keyword!("import");
         space!();
 
+        if node.type_only {
+            keyword!("type");
+            space!();
+        }
+
         let mut specifiers = vec![];
         let mut emitted_default = false;
         let mut emitted_ns = false;
@@ -325,7 +342,11 @@ impl<'a> Emitter<'a> {
             Lit::Num(ref n) => emit!(n),
             Lit::Regex(ref n) => {
                 punct!("/");
-                self.emit_js_word(n.exp.span, &n.exp.value)?;
+                if self.cfg.ascii_only {
+                    self.wr.write_str_lit(n.exp.span, &escape_non_ascii(&n.exp.value))?;
+                } else {
+                    self.emit_js_word(n.exp.span, &n.exp.value)?;
+                }
                 punct!("/");
                 if let Some(ref flags) = n.flags {
                     self.emit_js_word(flags.span, &flags.value)?;
@@ -345,48 +366,49 @@ impl<'a> Emitter<'a> {
     pub fn emit_str_lit(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        // if let Some(s) = get_text_of_node(&self.cm, node, false) {
-        //     self.wr.write_str_lit(node.span, &s)?;
-        //     return Ok(());
-        // }
-        let value = node
-            .value
-            .replace("\\", "\\\\")
-            .replace('\u{0008}', "\\b")
-            .replace('\u{000C}', "\\f")
-            .replace("\n", "\\n")
-            .replace("\r", "\\r")
-            .replace("\t", "\\t")
-            .replace('\u{000B}', "\\v")
-            .replace("\00", "\\x000")
-            .replace("\01", "\\x001")
-            .replace("\02", "\\x002")
-            .replace("\03", "\\x003")
-            .replace("\04", "\\x004")
-            .replace("\05", "\\x005")
-            .replace("\06", "\\x006")
-            .replace("\07", "\\x007")
-            .replace("\08", "\\x008")
-            .replace("\09", "\\x009")
-            .replace("\0", "\\0");
-        // let value = node.value.replace("\n", "\\n");
-
-        if !node.value.contains("'") {
-            punct!("'");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("'");
-        } else {
-            if !node.value.contains("\"") {
-                punct!("\"");
-                self.wr.write_str_lit(node.span, &value)?;
-                punct!("\"");
-            } else {
-                punct!("'");
-                self.wr
-                    .write_str_lit(node.span, &value.replace("'", "\\'"))?;
-                punct!("'");
+        let (preferred, other) = match self.cfg.quote_style {
+            QuoteStyle::Single => ('\'', '"'),
+            QuoteStyle::Double => ('"', '\''),
+        };
+
+        if let Some(raw) = self.get_raw_str_lit(node) {
+            // The original quote is kept if the printer would choose it too.
+            if raw.starts_with(preferred) || node.value.contains(preferred) {
+                if self.cfg.ascii_only {
+                    self.wr.write_str_lit(node.span, &escape_non_ascii(&raw))?;
+                } else {
+                    self.wr.write_str_lit(node.span, &raw)?;
+                }
+                return Ok(());
             }
         }
+
+        let quote = if node.value.contains(preferred) && !node.value.contains(other) {
+            other
+        } else {
+            preferred
+        };
+        let mut value = escape_str_lit(&node.value, quote);
+        if self.cfg.ascii_only {
+            value = escape_non_ascii(&value).into_owned();
+        }
+
+        self.wr.write_str_lit(node.span, &value)?;
+    }
+
+    /// Returns the source text of `node` if it's not modified.
+    fn get_raw_str_lit(&self, node: &Str) -> Option<String> {
+        let raw = get_text_of_node(&self.cm, node, false)?;
+        let quote = raw.chars().next()?;
+        if (quote != '\'' && quote != '"') || raw.len() < 2 || !raw.ends_with(quote) {
+            return None;
+        }
+
+        if unescape_str_lit(&raw[1..raw.len() - 1])? != *node.value {
+            return None;
+        }
+
+        Some(raw)
     }
 
     #[emitter]
@@ -400,7 +422,26 @@ impl<'a> Emitter<'a> {
             }
             self.wr.write_str_lit(num.span, "Infinity")?;
         } else {
-            self.wr.write_str_lit(num.span, &format!("{}", num.value))?;
+            let value = self.get_num_lit_text(num);
+            self.wr.write_str_lit(num.span, &value)?;
+        }
+    }
+
+    /// Returns the source text of `num` if it's not modified, and the shortest
+    /// representation of the value otherwise.
+    fn get_num_lit_text(&self, num: &Number) -> String {
+        if let Some(raw) = get_text_of_node(&self.cm, num, false) {
+            if parse_num_lit(&raw) == Some(num.value) {
+                return raw;
+            }
+        }
+
+        let decimal = format!("{}", num.value);
+        let exponential = format!("{:e}", num.value);
+        if exponential.len() < decimal.len() {
+            exponential
+        } else {
+            decimal
         }
     }
 
@@ -480,9 +521,20 @@ impl<'a> Emitter<'a> {
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::TsTypeCast(ref n) => emit!(n),
             Expr::TsOptChain(ref n) => emit!(n),
+
+            Expr::Invalid(ref n) => emit!(n),
         }
     }
 
+    #[emitter]
+    pub fn emit_invalid(&mut self, node: &Invalid) -> Result {
+        // Source code which failed to parse can't be printed.
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("cannot emit invalid node at {:?}", node.span),
+        ));
+    }
+
     #[emitter]
     pub fn emit_call_expr(&mut self, node: &CallExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
@@ -540,20 +592,14 @@ impl<'a> Emitter<'a> {
         match *expr {
             ExprOrSuper::Expr(ref expr) => {
                 match **expr {
-                    Expr::Lit(Lit::Num(Number { span, value })) => {
-                        if value.fract() == 0.0 {
-                            return true;
-                        }
-                        // check if numeric literal is a decimal literal that was originally written
-                        // with a dot
-                        if let Ok(text) = self.cm.span_to_snippet(span) {
-                            if text.contains(".") {
-                                return false;
-                            }
-                            return text.starts_with("0") || text.ends_with(" ");
-                        } else {
-                            true
+                    Expr::Lit(Lit::Num(ref num)) => {
+                        if num.value.is_infinite() {
+                            return false;
                         }
+                        // `1.toString` is a syntax error, while `1.5.toString`, `1e3.toString`
+                        // and `0xF.toString` are not.
+                        let text = self.get_num_lit_text(num);
+                        text.bytes().all(|b| b.is_ascii_digit()) && !is_legacy_octal(&text)
                     }
                     _ => false,
                 }
@@ -939,7 +985,11 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_quasi(&mut self, node: &TplElement) -> Result {
-        self.wr.write_str_lit(node.span, &node.raw.value)?;
+        if self.cfg.ascii_only {
+            self.wr.write_str_lit(node.span, &escape_non_ascii(&node.raw.value))?;
+        } else {
+            self.wr.write_str_lit(node.span, &node.raw.value)?;
+        }
         return Ok(());
     }
 
@@ -1008,9 +1058,54 @@ impl<'a> Emitter<'a> {
         nodes: &[ExprOrSpread],
         format: ListFormat,
     ) -> Result {
+        let format = if self.should_break_args(parent_node, nodes, format)? {
+            format | ListFormat::MultiLine | ListFormat::Indented
+        } else {
+            format
+        };
+
         self.emit_list(parent_node, Some(nodes), format)
     }
 
+    /// Returns true if `nodes` does not fit in `cfg.line_width`.
+    ///
+    /// Arguments are emitted to a scratch buffer on a single line to measure
+    /// them. Only the first line is measured, so a trailing function
+    /// expression does not break the argument list.
+    fn should_break_args(
+        &mut self,
+        parent_node: Span,
+        nodes: &[ExprOrSpread],
+        format: ListFormat,
+    ) -> io::Result<bool> {
+        let line_width = match self.cfg.line_width {
+            Some(line_width) if !self.cfg.minify && !nodes.is_empty() => line_width,
+            _ => return Ok(false),
+        };
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Config {
+                    line_width: None,
+                    ..self.cfg
+                },
+                cm: self.cm.clone(),
+                comments: None,
+                wr: Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", &mut buf, None)),
+                handlers: Box::new(NoopHandlers),
+                pos_of_leading_comments: Default::default(),
+            };
+            emitter.emit_list(parent_node, Some(nodes), format)?;
+        }
+
+        let first_line = buf.split(|&b| b == b'\n').next().unwrap_or(&[]);
+        // `)`, if the arguments are on a single line.
+        let closing = if first_line.len() == buf.len() { 1 } else { 0 };
+
+        Ok(self.wr.column() + first_line.len() + closing > line_width)
+    }
+
     #[emitter]
     pub fn emit_expr_or_spread(&mut self, node: &ExprOrSpread) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
@@ -1176,7 +1271,11 @@ impl<'a> Emitter<'a> {
             unimplemented!()
         } else {
             // TODO: span
-            self.wr.write_symbol(ident.span, &ident.sym)?
+            if self.cfg.ascii_only {
+                self.wr.write_symbol(ident.span, &escape_non_ascii_ident(&ident.sym))?
+            } else {
+                self.wr.write_symbol(ident.span, &ident.sym)?
+            }
 
             // self.wr
             //     .write(get_text_of_node(&self.cm, &ident, /* includeTrivia */
@@ -1365,9 +1464,20 @@ impl<'a> Emitter<'a> {
                     _ => false,
                 }
             };
-            if format.contains(ListFormat::CommaDelimited) && has_trailing_comma {
-                self.wr.write_punct(",")?;
-                formatting_space!(self);
+            let should_write_closing_line_terminator = self
+                .cm
+                .should_write_closing_line_terminator(parent_node, children, format);
+            if format.contains(ListFormat::CommaDelimited) {
+                if has_trailing_comma {
+                    self.wr.write_punct(",")?;
+                    formatting_space!(self);
+                } else if self.cfg.trailing_comma
+                    && !self.cfg.minify
+                    && !format.contains(ListFormat::Parenthesis)
+                    && should_write_closing_line_terminator
+                {
+                    self.wr.write_punct(",")?;
+                }
             }
 
             {
@@ -1404,10 +1514,7 @@ impl<'a> Emitter<'a> {
             }
 
             // Write the closing line terminator or closing whitespace.
-            if self
-                .cm
-                .should_write_closing_line_terminator(parent_node, children, format)
-            {
+            if should_write_closing_line_terminator {
                 if !self.cfg.minify {
                     self.wr.write_line()?;
                 }
@@ -1615,7 +1722,8 @@ impl<'a> Emitter<'a> {
     pub fn emit_empty_stmt(&mut self, node: &EmptyStmt) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        punct!(";");
+        // Not `write_semi`, as `text_writer::omit_semi` would drop it.
+        self.wr.write_punct(";")?;
     }
 
     #[emitter]
@@ -1946,7 +2054,6 @@ impl<'a> Emitter<'a> {
     }
 }
 
-#[allow(dead_code)]
 fn get_text_of_node<T: Spanned>(
     cm: &Arc<SourceMap>,
     node: &T,
@@ -1958,13 +2065,233 @@ fn get_text_of_node<T: Spanned>(
         return None;
     }
 
-    let s = cm.span_to_snippet(span).unwrap();
+    let s = cm.span_to_snippet(span).ok()?;
     if s == "" {
         return None;
     }
     Some(s)
 }
 
+/// Quotes `value` with `quote`, escaping characters which can't appear in a
+/// string literal as is.
+fn escape_str_lit(value: &str, quote: char) -> String {
+    let mut buf = String::with_capacity(value.len() + 2);
+    buf.push(quote);
+
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => buf.push_str("\\\\"),
+            '\u{0008}' => buf.push_str("\\b"),
+            '\u{000C}' => buf.push_str("\\f"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\u{000B}' => buf.push_str("\\v"),
+            '\0' => match chars.peek() {
+                // `\01` is an octal escape.
+                Some(c) if c.is_ascii_digit() => buf.push_str("\\x00"),
+                _ => buf.push_str("\\0"),
+            },
+            // Line terminators are not allowed in string literals before es2019.
+            '\u{2028}' => buf.push_str("\\u2028"),
+            '\u{2029}' => buf.push_str("\\u2029"),
+            _ if c == quote => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            _ if c.is_ascii_control() => buf.push_str(&format!("\\x{:02x}", c as u32)),
+            _ => buf.push(c),
+        }
+    }
+
+    buf.push(quote);
+    buf
+}
+
+/// Escapes non-ascii characters in the source text of a string, template or
+/// regular expression literal.
+///
+/// Characters outside of the basic multilingual plane are escaped as a
+/// surrogate pair, which works in all of them.
+fn escape_non_ascii(s: &str) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len() + 16);
+    let mut units = [0; 2];
+    // True if the last character is a backslash which is not escaped.
+    let mut escaped = false;
+
+    for c in s.chars() {
+        if c.is_ascii() {
+            escaped = c == '\\' && !escaped;
+            buf.push(c);
+            continue;
+        }
+
+        if escaped {
+            escaped = false;
+            // `\é` is `é`, and a backslash followed by a line terminator is a line
+            // continuation.
+            buf.pop();
+            if c == '\u{2028}' || c == '\u{2029}' {
+                continue;
+            }
+        }
+
+        for unit in c.encode_utf16(&mut units) {
+            buf.push_str(&format!("\\u{:04x}", unit));
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+/// Escapes non-ascii characters in an identifier.
+///
+/// Surrogate pairs are not allowed in identifiers, so characters outside of
+/// the basic multilingual plane are escaped as `\u{XXXXX}`.
+fn escape_non_ascii_ident(s: &str) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len() + 16);
+    for c in s.chars() {
+        match c as u32 {
+            0..=0x7F => buf.push(c),
+            c @ 0x80..=0xFFFF => buf.push_str(&format!("\\u{:04x}", c)),
+            c => buf.push_str(&format!("\\u{{{:x}}}", c)),
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+/// Escapes non-ascii characters in jsx text or a jsx attribute string, which
+/// do not support escape sequences, using html entities.
+fn escape_non_ascii_jsx(s: &str) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len() + 16);
+    for c in s.chars() {
+        if c.is_ascii() {
+            buf.push(c);
+        } else {
+            buf.push_str(&format!("&#x{:x};", c as u32));
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+/// Returns the value of a string literal. `raw` should not contain quotes.
+///
+/// Returns `None` if `raw` is invalid or contains a lone surrogate.
+fn unescape_str_lit(raw: &str) -> Option<String> {
+    fn read_hex(chars: &mut Peekable<Chars>, len: usize) -> Option<u32> {
+        (0..len).try_fold(0, |acc, _| Some(acc * 16 + chars.next()?.to_digit(16)?))
+    }
+
+    let mut buf: Vec<u16> = Vec::with_capacity(raw.len());
+    let mut units = [0; 2];
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            buf.extend_from_slice(c.encode_utf16(&mut units));
+            continue;
+        }
+
+        match chars.next()? {
+            'b' => buf.push(0x08),
+            'f' => buf.push(0x0C),
+            'n' => buf.push(0x0A),
+            'r' => buf.push(0x0D),
+            't' => buf.push(0x09),
+            'v' => buf.push(0x0B),
+            // Line continuation
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            'x' => buf.push(read_hex(&mut chars, 2)? as u16),
+            'u' => {
+                if chars.peek() == Some(&'{') {
+                    chars.next();
+                    let mut code_point = 0;
+                    loop {
+                        match chars.next()? {
+                            '}' => break,
+                            c => code_point = code_point * 16 + c.to_digit(16)?,
+                        }
+                        if code_point > 0x10FFFF {
+                            return None;
+                        }
+                    }
+                    match std::char::from_u32(code_point) {
+                        Some(c) => buf.extend_from_slice(c.encode_utf16(&mut units)),
+                        // Surrogates
+                        None => buf.push(code_point as u16),
+                    }
+                } else {
+                    buf.push(read_hex(&mut chars, 4)? as u16);
+                }
+            }
+            // Legacy octal escape
+            c @ '0'..='7' => {
+                let max_len = if c <= '3' { 3 } else { 2 };
+                let mut value = c.to_digit(8)?;
+                for _ in 1..max_len {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            chars.next();
+                            value = value * 8 + digit;
+                        }
+                        None => break,
+                    }
+                }
+                buf.push(value as u16);
+            }
+            c => buf.extend_from_slice(c.encode_utf16(&mut units)),
+        }
+    }
+
+    String::from_utf16(&buf).ok()
+}
+
+/// Returns the value of a numeric literal.
+fn parse_num_lit(raw: &str) -> Option<f64> {
+    match raw.as_bytes().first() {
+        Some(c) if c.is_ascii_digit() || *c == b'.' => {}
+        // e.g. `Infinity` replaced with a number.
+        _ => return None,
+    }
+
+    let (radix, digits) = match raw.get(..2) {
+        Some("0x") | Some("0X") => (16, &raw[2..]),
+        Some("0o") | Some("0O") => (8, &raw[2..]),
+        Some("0b") | Some("0B") => (2, &raw[2..]),
+        _ if is_legacy_octal(raw) => (8, &raw[1..]),
+        _ => return raw.parse().ok(),
+    };
+
+    digits.chars().try_fold(0.0, |acc, c| {
+        Some(acc * f64::from(radix) + f64::from(c.to_digit(radix)?))
+    })
+}
+
+/// `0777`
+fn is_legacy_octal(raw: &str) -> bool {
+    raw.len() > 1 && raw.starts_with('0') && raw.bytes().all(|b| b >= b'0' && b <= b'7')
+}
+
 /// In some cases, we need to emit a space between the operator and the operand.
 /// One obvious case is when the operator is an identifier, like delete or
 /// typeof. We also need to do this for plus and minus expressions in certain
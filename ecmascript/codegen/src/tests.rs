@@ -1,5 +1,5 @@
 extern crate swc_ecma_parser;
-use self::swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+use self::swc_ecma_parser::{EsConfig, Parser, Session, SourceFileInput, Syntax};
 use super::*;
 use crate::config::Config;
 use std::{
@@ -7,7 +7,10 @@ use std::{
     io::Write,
     sync::{Arc, RwLock},
 };
-use swc_common::{comments::Comments, FileName, SourceMap};
+use swc_common::{
+    comments::{Comment, CommentKind, Comments},
+    FileName, SourceMap,
+};
 
 struct Noop;
 impl Handlers for Noop {}
@@ -16,6 +19,8 @@ struct Builder {
     cfg: Config,
     cm: Arc<SourceMap>,
     comments: Comments,
+    indent_str: &'static str,
+    omit_semi: bool,
 }
 
 impl Builder {
@@ -23,10 +28,16 @@ impl Builder {
     where
         F: FnOnce(&mut Emitter) -> Ret,
     {
+        let wr = text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)
+            .with_indent_str(self.indent_str);
         let mut e = Emitter {
             cfg: self.cfg,
             cm: self.cm.clone(),
-            wr: Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)),
+            wr: if self.omit_semi {
+                Box::new(text_writer::omit_semi(wr))
+            } else {
+                Box::new(wr)
+            },
             comments: Some(&self.comments),
             handlers: Box::new(Noop),
             pos_of_leading_comments: Default::default(),
@@ -49,7 +60,17 @@ impl Builder {
     }
 }
 
-fn parse_then_emit(from: &str, cfg: Config) -> String {
+fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
+    parse_then_emit_with(from, cfg, syntax, "    ", false)
+}
+
+fn parse_then_emit_with(
+    from: &str,
+    cfg: Config,
+    syntax: Syntax,
+    indent_str: &'static str,
+    omit_semi: bool,
+) -> String {
     ::testing::run_test(false, |cm, handler| {
         let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
         println!(
@@ -61,7 +82,7 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
         let res = {
             let mut parser = Parser::new(
                 Session { handler: &handler },
-                Syntax::default(),
+                syntax,
                 SourceFileInput::from(&*src),
                 Some(&comments),
             );
@@ -75,6 +96,8 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
             cfg,
             cm: cm.clone(),
             comments,
+            indent_str,
+            omit_semi,
         }
         .text(from, |e| e.emit_module(&res).unwrap());
         Ok(out)
@@ -83,19 +106,37 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
 }
 
 pub(crate) fn assert_min(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: true });
+    let cfg = Config {
+        minify: true,
+        ..Default::default()
+    };
+    let out = parse_then_emit(from, cfg, Syntax::default());
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
+}
+
+pub(crate) fn assert_min_jsx(from: &str, to: &str) {
+    let syntax = Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+    let cfg = Config {
+        minify: true,
+        ..Default::default()
+    };
+    let out = parse_then_emit(from, cfg, syntax);
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
 
 pub(crate) fn assert_pretty(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: false });
+    let out = parse_then_emit(from, Default::default(), Syntax::default());
 
     assert_eq!(DebugUsingDisplay(&out.trim()), DebugUsingDisplay(to),);
 }
 
 fn test_from_to(from: &str, to: &str) {
-    let out = parse_then_emit(from, Default::default());
+    let out = parse_then_emit(from, Default::default(), Syntax::default());
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to.trim()),);
 }
@@ -158,20 +199,353 @@ a;",
     );
 }
 
+#[test]
+fn comment_retention_some() {
+    let cfg = Config {
+        minify: true,
+        comment_retention: CommentRetention::Some,
+        ..Default::default()
+    };
+    let out = parse_then_emit(
+        "a;\n/*! keep */\n/* drop */\nb;\n/* @license keep */\n// drop\nc;\n// @license keep\nd; \
+         // @preserve keep",
+        cfg,
+        Syntax::default(),
+    );
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(
+            "a;/*! keep */\nb;/* @license keep */\nc;// @license keep\nd; // @preserve keep"
+        )
+    );
+}
+
+#[test]
+fn line_comment_at_eof() {
+    let cfg = Config {
+        minify: true,
+        comment_retention: CommentRetention::Some,
+        ..Default::default()
+    };
+    let out = ::testing::run_test(false, |cm, _| {
+        let span = Span::new(BytePos(1), BytePos(3), Default::default());
+        let comments = Comments::default();
+        comments.add_trailing(
+            span.hi(),
+            Comment {
+                kind: CommentKind::Line,
+                span: DUMMY_SP,
+                text: " @license keep".into(),
+            },
+        );
+
+        // `b;` is appended after the last statement of the file.
+        let stmt = |sym: &str, span| {
+            ModuleItem::Stmt(Stmt::Expr(Box::new(Expr::Ident(Ident::new(sym.into(), span)))))
+        };
+        let module = Module {
+            span: DUMMY_SP,
+            body: vec![stmt("a", span), stmt("b", DUMMY_SP)],
+            shebang: None,
+        };
+
+        Ok(Builder {
+            cfg,
+            cm,
+            comments,
+            indent_str: "    ",
+            omit_semi: false,
+        }
+        .text("", |e| e.emit_module(&module).unwrap()))
+    })
+    .unwrap();
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay("a;// @license keep\nb;"));
+}
+
+#[test]
+fn comment_retention_none() {
+    let cfg = Config {
+        comment_retention: CommentRetention::None,
+        ..Default::default()
+    };
+    let out = parse_then_emit("/*! a */\na;", cfg, Syntax::default());
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay("a;"));
+}
+
 #[test]
 fn no_octal_escape() {
+    assert_eq!(emit_str_lit("\0a", QuoteStyle::Single), r#"'\0a'"#);
+    assert_eq!(emit_str_lit("\00", QuoteStyle::Single), r#"'\x000'"#);
+    assert_eq!(emit_str_lit("\01", QuoteStyle::Single), r#"'\x001'"#);
+    assert_eq!(emit_str_lit("\09", QuoteStyle::Single), r#"'\x009'"#);
+}
+
+#[test]
+fn synthesized_str_lit() {
+    assert_eq!(emit_str_lit("a'b", QuoteStyle::Single), r#""a'b""#);
+    assert_eq!(emit_str_lit("a'\"b", QuoteStyle::Single), r#"'a\'"b'"#);
+    assert_eq!(emit_str_lit("a\u{2028}\u{1}", QuoteStyle::Double), r#""a\u2028\x01""#);
+}
+
+#[test]
+fn raw_str_lit() {
     test_from_to(
+        r#"'\x00a'; '\u00e9'; '\u{1F600}'; 'a\
+b'; "c";"#,
         r#"'\x00a';
-'\x000';
-'\x001';
-'\x009'"#,
-        r#"'\0a';
-'\x000';
-'\x001';
-'\x009';"#,
+'\u00e9';
+'\u{1F600}';
+'a\
+b';
+'c';"#,
+    );
+}
+
+#[test]
+fn raw_num_lit() {
+    test_from_to(
+        "0xFF; 0o17; 1e21; 1.50; 0xF.toString(); 1..toString(); 1.5.toString();",
+        "0xFF;
+0o17;
+1e21;
+1.50;
+0xF.toString();
+1..toString();
+1.5.toString();",
+    );
+}
+
+#[test]
+fn synthesized_num_lit() {
+    assert_eq!(emit_num_lit(255.0), "255");
+    assert_eq!(emit_num_lit(1e21), "1e21");
+    assert_eq!(emit_num_lit(0.5), "0.5");
+}
+
+#[test]
+fn ascii_only() {
+    let cfg = Config {
+        ascii_only: true,
+        ..Default::default()
+    };
+    let out = parse_then_emit(r#"var é = 'é😀\é'; /é[😀]/u; `é${é}\é`;"#, cfg, Syntax::default());
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(
+            r#"var \u00e9 = '\u00e9\ud83d\ude00\u00e9';
+/\u00e9[\ud83d\ude00]/u;
+`\u00e9${\u00e9}\u00e9`;"#
+        )
+    );
+}
+
+#[test]
+fn ascii_only_astral_ident() {
+    let cfg = Config {
+        ascii_only: true,
+        ..Default::default()
+    };
+    let out = emit_synthesized(cfg, |e| {
+        e.emit_ident(&Ident::new("a𐊧".into(), DUMMY_SP)).unwrap()
+    });
+
+    assert_eq!(out, r#"a\u{102a7}"#);
+}
+
+#[test]
+fn ascii_only_jsx() {
+    let cfg = Config {
+        minify: true,
+        ascii_only: true,
+        ..Default::default()
+    };
+    let syntax = Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+    let out = parse_then_emit(r#"<a b="é">é😀</a>;"#, cfg, syntax);
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(r#"<a b="&#xe9;">&#xe9;&#x1f600;</a>;"#)
     );
 }
 
+fn emit_synthesized<F>(cfg: Config, op: F) -> String
+where
+    F: FnOnce(&mut Emitter),
+{
+    ::testing::run_test(false, |cm, _| {
+        Ok(Builder {
+            cfg,
+            cm,
+            comments: Default::default(),
+            indent_str: "    ",
+            omit_semi: false,
+        }
+        .text("", op))
+    })
+    .unwrap()
+}
+
+fn emit_str_lit(value: &str, quote_style: QuoteStyle) -> String {
+    let cfg = Config {
+        quote_style,
+        ..Default::default()
+    };
+    emit_synthesized(cfg, |e| {
+        e.emit_str_lit(&Str {
+            span: DUMMY_SP,
+            value: value.into(),
+            has_escape: false,
+        })
+        .unwrap()
+    })
+}
+
+fn emit_num_lit(value: f64) -> String {
+    emit_synthesized(Default::default(), |e| {
+        e.emit_num_lit(&Number {
+            span: DUMMY_SP,
+            value,
+        })
+        .unwrap()
+    })
+}
+
+#[test]
+fn indent_str() {
+    let out = parse_then_emit_with(
+        "if (a) { b; }",
+        Default::default(),
+        Syntax::default(),
+        "\t",
+        false,
+    );
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay("if (a) {\n\tb;\n}"));
+}
+
+#[test]
+fn quote_style_double() {
+    let cfg = Config {
+        quote_style: QuoteStyle::Double,
+        ..Default::default()
+    };
+    let out = parse_then_emit(r#"'a'; 'b"'; "c'"; 'd"\'';"#, cfg, Syntax::default());
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(r#""a";
+'b"';
+"c'";
+"d\"'";"#)
+    );
+}
+
+#[test]
+fn trailing_comma() {
+    let cfg = Config {
+        trailing_comma: true,
+        ..Default::default()
+    };
+    let out = parse_then_emit("var a = { b: 1, c: [\n1,\n2\n], d: [3] };", cfg, Syntax::default());
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(
+            "var a = {
+    b: 1,
+    c: [
+        1,
+        2,
+    ],
+    d: [3],
+};"
+        )
+    );
+}
+
+#[test]
+fn line_width() {
+    let cfg = Config {
+        line_width: Some(20),
+        ..Default::default()
+    };
+    let out = parse_then_emit(
+        "foo(a, b); foo(first, second); new Foo(first, second, third);",
+        cfg,
+        Syntax::default(),
+    );
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(
+            "foo(a, b);
+foo(first, second);
+new Foo(
+    first,
+    second,
+    third
+);"
+        )
+    );
+}
+
+#[test]
+fn line_width_trailing_function() {
+    let cfg = Config {
+        line_width: Some(20),
+        ..Default::default()
+    };
+    let out = parse_then_emit("foo(a, function() { b; });", cfg, Syntax::default());
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(
+            "foo(a, function() {
+    b;
+});"
+        )
+    );
+}
+
+#[test]
+fn omit_semi() {
+    let out = parse_then_emit_with(
+        "a; (b); c; [d]; for (;;) e; if (f) ; h; `g`",
+        Default::default(),
+        Syntax::default(),
+        "    ",
+        true,
+    );
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(
+            "a
+;(b)
+c
+;[d]
+for(;;)e
+if (f) ;
+h
+;`g`"
+        )
+    );
+}
+
+#[test]
+fn invalid() {
+    emit_synthesized(Default::default(), |e| {
+        assert!(e.emit_invalid(&Invalid { span: DUMMY_SP }).is_err());
+    });
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
@@ -15,6 +15,7 @@ use swc_common::{FileName, SourceMap, Span};
 pub struct JsWriter<'a, W: Write> {
     cm: Arc<SourceMap>,
     indent: usize,
+    indent_str: &'a str,
     line_start: bool,
     line_count: usize,
     line_pos: usize,
@@ -34,6 +35,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
         JsWriter {
             cm,
             indent: Default::default(),
+            indent_str: "    ",
             line_start: true,
             line_count: 0,
             line_pos: Default::default(),
@@ -44,12 +46,17 @@ impl<'a, W: Write> JsWriter<'a, W> {
         }
     }
 
-    fn write_indent_string(&mut self) -> io::Result<usize> {
-        const INDENT: &[u8] = b"    ";
+    /// Sets the string written for each level of indentation, which defaults
+    /// to four spaces.
+    pub fn with_indent_str(mut self, indent_str: &'a str) -> Self {
+        self.indent_str = indent_str;
+        self
+    }
 
+    fn write_indent_string(&mut self) -> io::Result<usize> {
         let mut cnt = 0;
         for _ in 0..self.indent {
-            cnt += self.raw_write(INDENT)?;
+            cnt += self.raw_write(self.indent_str.as_bytes())?;
         }
 
         Ok(cnt)
@@ -197,6 +204,14 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
         self.write(None, s)?;
         Ok(())
     }
+
+    fn column(&self) -> usize {
+        if self.line_start {
+            self.indent * self.indent_str.len()
+        } else {
+            self.line_pos
+        }
+    }
 }
 
 fn compute_line_starts(s: &str) -> Vec<usize> {
@@ -1,4 +1,7 @@
-pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
+pub use self::{
+    basic_impl::JsWriter,
+    semicolon::{omit_semi, omit_trailing_semi},
+};
 use super::*;
 use swc_common::Span;
 
@@ -34,6 +37,10 @@ pub trait WriteJs {
     fn write_symbol(&mut self, span: Span, s: &str) -> Result;
 
     fn write_punct(&mut self, s: &'static str) -> Result;
+
+    /// Width of the current line, including indentation which will be written
+    /// before the next token.
+    fn column(&self) -> usize;
 }
 
 impl<W> WriteJs for Box<W>
@@ -89,4 +96,8 @@ where
     fn write_punct(&mut self, s: &'static str) -> Result {
         (**self).write_punct(s)
     }
+
+    fn column(&self) -> usize {
+        (**self).column()
+    }
 }
@@ -5,6 +5,21 @@ pub fn omit_trailing_semi<W: WriteJs>(w: W) -> impl WriteJs {
     OmitTrailingSemi {
         inner: w,
         pending_semi: false,
+        omit_at_line_end: false,
+        after_omitted_semi: false,
+    }
+}
+
+/// Omits semicolons at the end of lines.
+///
+/// A semicolon is written before the next statement if it starts with a
+/// token which would continue the previous one, e.g. `(` or `[`.
+pub fn omit_semi<W: WriteJs>(w: W) -> impl WriteJs {
+    OmitTrailingSemi {
+        inner: w,
+        pending_semi: false,
+        omit_at_line_end: true,
+        after_omitted_semi: false,
     }
 }
 
@@ -12,6 +27,10 @@ pub fn omit_trailing_semi<W: WriteJs>(w: W) -> impl WriteJs {
 struct OmitTrailingSemi<W: WriteJs> {
     inner: W,
     pending_semi: bool,
+    /// Drop pending semicolons at `write_line`.
+    omit_at_line_end: bool,
+    /// True if a semicolon was dropped and no token is written since then.
+    after_omitted_semi: bool,
 }
 
 macro_rules! with_semi {
@@ -33,26 +52,70 @@ macro_rules! with_semi {
     };
 }
 
+/// Like `with_semi!`, but `$s` is the first token of a new statement if a
+/// semicolon was dropped before it.
+macro_rules! with_token {
+    (
+        $fn_name:ident
+        (
+            $(
+                $arg_name:ident
+                :
+                $arg_ty:ty
+            ),*
+        ),
+        $s:ident
+    ) => {
+        fn $fn_name(&mut self, $($arg_name: $arg_ty),* ) -> Result {
+            self.commit_pending_semi()?;
+            self.guard_omitted_semi($s)?;
+
+            self.inner.$fn_name( $($arg_name),* )
+        }
+    };
+}
+
 impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
-    with_semi!(increase_indent());
-    with_semi!(decrease_indent());
+    fn increase_indent(&mut self) -> Result {
+        self.inner.increase_indent()
+    }
+
+    fn decrease_indent(&mut self) -> Result {
+        self.inner.decrease_indent()
+    }
 
     fn write_semi(&mut self) -> Result {
+        // e.g. `for (;;)`
+        self.commit_pending_semi()?;
         self.pending_semi = true;
         Ok(())
     }
 
     with_semi!(write_space());
     with_semi!(write_comment(span: Span, s: &str));
-    with_semi!(write_keyword(span: Option<Span>, s: &'static str));
-    with_semi!(write_operator(s: &str));
-    with_semi!(write_param(s: &str));
-    with_semi!(write_property(s: &str));
-    with_semi!(write_line());
-    with_semi!(write_lit(span: Span, s: &str));
-    with_semi!(write_str_lit(span: Span, s: &str));
-    with_semi!(write_symbol(span: Span, s: &str));
-    with_semi!(write_punct(s: &'static str));
+    with_token!(write_keyword(span: Option<Span>, s: &'static str), s);
+    with_token!(write_operator(s: &str), s);
+    with_token!(write_param(s: &str), s);
+    with_token!(write_property(s: &str), s);
+
+    fn write_line(&mut self) -> Result {
+        if self.omit_at_line_end && self.pending_semi {
+            self.pending_semi = false;
+            self.after_omitted_semi = true;
+        }
+        self.commit_pending_semi()?;
+
+        self.inner.write_line()
+    }
+
+    with_token!(write_lit(span: Span, s: &str), s);
+    with_token!(write_str_lit(span: Span, s: &str), s);
+    with_token!(write_symbol(span: Span, s: &str), s);
+    with_token!(write_punct(s: &'static str), s);
+
+    fn column(&self) -> usize {
+        self.inner.column() + if self.pending_semi { 1 } else { 0 }
+    }
 }
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
@@ -63,4 +126,18 @@ impl<W: WriteJs> OmitTrailingSemi<W> {
         }
         Ok(())
     }
+
+    fn guard_omitted_semi(&mut self, s: &str) -> Result {
+        if self.after_omitted_semi && !s.is_empty() {
+            self.after_omitted_semi = false;
+
+            match s.as_bytes()[0] {
+                b'(' | b'[' | b'`' | b'+' | b'-' | b'/' | b'*' | b'<' => {
+                    self.inner.write_punct(";")?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 }
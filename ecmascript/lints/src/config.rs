@@ -0,0 +1,56 @@
+use crate::{globals::Env, registry::Registry, rule::Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Severity of each rule, keyed by name of the rule, and globals used by
+/// `no-undef`.
+///
+/// ```json
+/// {
+///   "lints": {
+///     "env": ["browser", "es2017"],
+///     "globals": ["jQuery"],
+///     "no-debugger": "error",
+///     "no-with": "off"
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub env: Vec<Env>,
+
+    /// Additional global variables.
+    #[serde(default)]
+    pub globals: Vec<String>,
+
+    #[serde(flatten)]
+    pub rules: HashMap<String, Severity>,
+}
+
+impl Config {
+    /// Returns `None` if user did not configure the rule.
+    pub fn severity(&self, rule: &str) -> Option<Severity> {
+        self.rules.get(rule).cloned()
+    }
+
+    /// Returns an error if a configured rule is not in `registry`.
+    pub fn validate(&self, registry: &Registry) -> Result<(), String> {
+        let mut unknown = self
+            .rules
+            .keys()
+            .filter(|name| registry.get(name).is_none())
+            .map(|name| format!("`{}`", name))
+            .collect::<Vec<_>>();
+        if unknown.is_empty() {
+            return Ok(());
+        }
+        unknown.sort();
+
+        Err(format!("unknown lint rules: {}", unknown.join(", ")))
+    }
+
+    pub fn is_global(&self, name: &str) -> bool {
+        self.globals.iter().any(|g| g == name) || crate::globals::is_global(&self.env, name)
+    }
+}
@@ -0,0 +1,143 @@
+//! Applies fixes provided by rules to the source code.
+use crate::{config::Config, lint_program, registry::Registry, rule::LintResult};
+use serde::Serialize;
+use std::sync::Arc;
+use swc_common::{comments::Comments, errors::Handler, BytePos, SourceFile, SourceMap, Span};
+use swc_ecma_ast::Program;
+use swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+
+/// Fixes may introduce new problems, so the source code is linted again after
+/// applying fixes.
+const MAX_PASSES: usize = 10;
+
+/// Replaces source code of `span` with `text`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Edit {
+    pub span: Span,
+    pub text: String,
+}
+
+/// Edits which should be applied together. Edits should not overlap.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Fix {
+    pub edits: Vec<Edit>,
+}
+
+impl Fix {
+    pub fn replace<S>(span: Span, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Fix {
+            edits: vec![Edit {
+                span,
+                text: text.into(),
+            }],
+        }
+    }
+
+    fn lo(&self) -> BytePos {
+        self.edits.iter().map(|e| e.span.lo()).min().unwrap_or(BytePos(0))
+    }
+
+    fn hi(&self) -> BytePos {
+        self.edits.iter().map(|e| e.span.hi()).max().unwrap_or(BytePos(0))
+    }
+}
+
+#[derive(Debug)]
+pub struct Fixed {
+    pub code: String,
+    /// Problems remaining in `code`.
+    pub results: Vec<LintResult>,
+}
+
+/// Lints `fm` and applies fixes until no fix is applicable.
+///
+/// `fm` is parsed as a module if `is_module` is `Some(true)`, and as a script
+/// if it's `Some(false)`. If it's `None`, `fm` is parsed as a module only if
+/// it's not a valid script.
+///
+/// Returns `Err` if `fm` has a syntax error, which is emitted using `handler`.
+pub fn fix(
+    cm: &SourceMap,
+    handler: &Handler,
+    syntax: Syntax,
+    is_module: Option<bool>,
+    registry: &Registry,
+    config: &Config,
+    fm: Arc<SourceFile>,
+) -> Result<Fixed, ()> {
+    let mut fm = fm;
+    let mut pass = 0;
+
+    loop {
+        let comments = Comments::default();
+        let mut parser = Parser::new(
+            Session { handler },
+            syntax,
+            SourceFileInput::from(&*fm),
+            Some(&comments),
+        );
+        let program = match is_module {
+            Some(true) => parser.parse_module().map(Program::Module),
+            Some(false) => parser.parse_script().map(Program::Script),
+            None => parser.parse_program(),
+        }
+        .map_err(|mut e| e.emit())?;
+
+        let results = lint_program(cm, &comments, registry, config, &program);
+        let code = if pass == MAX_PASSES {
+            None
+        } else {
+            apply_fixes(&fm.src, fm.start_pos, &results)
+        };
+
+        match code {
+            Some(code) => fm = cm.new_source_file(fm.name.clone(), code),
+            None => {
+                return Ok(Fixed {
+                    code: fm.src.to_string(),
+                    results,
+                })
+            }
+        }
+        pass += 1;
+    }
+}
+
+/// Applies fixes of `results` to `src`, which starts at `start_pos`.
+///
+/// Fixes overlapping with a previous fix are ignored, and they are expected to
+/// be applied by the next pass. Returns `None` if there's no fix.
+pub fn apply_fixes(src: &str, start_pos: BytePos, results: &[LintResult]) -> Option<String> {
+    let mut fixes: Vec<_> = results.iter().filter_map(|r| r.fix.as_ref()).collect();
+    fixes.sort_by_key(|f| f.lo());
+
+    let mut edits = vec![];
+    let mut last_hi = start_pos;
+    for fix in fixes {
+        if fix.lo() < last_hi {
+            continue;
+        }
+        last_hi = fix.hi();
+        edits.extend(fix.edits.iter());
+    }
+    if edits.is_empty() {
+        return None;
+    }
+    edits.sort_by_key(|e| e.span.lo());
+
+    let mut buf = String::with_capacity(src.len());
+    let mut pos = 0;
+    for e in edits {
+        let lo = (e.span.lo() - start_pos).0 as usize;
+        let hi = (e.span.hi() - start_pos).0 as usize;
+        buf.push_str(&src[pos..lo]);
+        buf.push_str(&e.text);
+        pos = hi;
+    }
+    buf.push_str(&src[pos..]);
+
+    Some(buf)
+}
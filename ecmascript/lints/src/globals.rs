@@ -0,0 +1,175 @@
+//! Global variables provided by environments.
+use serde::{Deserialize, Serialize};
+
+/// Environment which defines global variables.
+///
+/// `es*` environments include globals of previous versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Env {
+    Browser,
+    Node,
+    Es2015,
+    Es2016,
+    Es2017,
+    Es2018,
+    Es2019,
+    Es2020,
+}
+
+/// Always available.
+const ES5: &[&str] = &[
+    "Array",
+    "Boolean",
+    "Date",
+    "Error",
+    "EvalError",
+    "Function",
+    "Infinity",
+    "JSON",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "RangeError",
+    "ReferenceError",
+    "RegExp",
+    "String",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+    "arguments",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "escape",
+    "eval",
+    "isFinite",
+    "isNaN",
+    "parseFloat",
+    "parseInt",
+    "undefined",
+    "unescape",
+];
+
+const ES2015: &[&str] = &[
+    "ArrayBuffer",
+    "DataView",
+    "Float32Array",
+    "Float64Array",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Map",
+    "Promise",
+    "Proxy",
+    "Reflect",
+    "Set",
+    "Symbol",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakSet",
+];
+
+const ES2017: &[&str] = &["Atomics", "SharedArrayBuffer"];
+
+const ES2020: &[&str] = &["BigInt", "BigInt64Array", "BigUint64Array", "globalThis"];
+
+const BROWSER: &[&str] = &[
+    "Blob",
+    "CustomEvent",
+    "Element",
+    "Event",
+    "File",
+    "FileReader",
+    "FormData",
+    "HTMLElement",
+    "Headers",
+    "Image",
+    "IntersectionObserver",
+    "MutationObserver",
+    "Node",
+    "Request",
+    "Response",
+    "URL",
+    "URLSearchParams",
+    "WebSocket",
+    "Worker",
+    "XMLHttpRequest",
+    "alert",
+    "atob",
+    "btoa",
+    "cancelAnimationFrame",
+    "clearInterval",
+    "clearTimeout",
+    "confirm",
+    "console",
+    "crypto",
+    "customElements",
+    "document",
+    "fetch",
+    "getComputedStyle",
+    "history",
+    "localStorage",
+    "location",
+    "matchMedia",
+    "navigator",
+    "performance",
+    "prompt",
+    "queueMicrotask",
+    "requestAnimationFrame",
+    "screen",
+    "self",
+    "sessionStorage",
+    "setInterval",
+    "setTimeout",
+    "window",
+];
+
+const NODE: &[&str] = &[
+    "Buffer",
+    "TextDecoder",
+    "TextEncoder",
+    "URL",
+    "URLSearchParams",
+    "__dirname",
+    "__filename",
+    "clearImmediate",
+    "clearInterval",
+    "clearTimeout",
+    "console",
+    "exports",
+    "global",
+    "module",
+    "process",
+    "queueMicrotask",
+    "require",
+    "setImmediate",
+    "setInterval",
+    "setTimeout",
+];
+
+impl Env {
+    fn globals(self) -> Vec<&'static [&'static str]> {
+        match self {
+            Env::Browser => vec![BROWSER],
+            Env::Node => vec![NODE],
+            Env::Es2015 | Env::Es2016 => vec![ES2015],
+            Env::Es2017 | Env::Es2018 | Env::Es2019 => vec![ES2015, ES2017],
+            Env::Es2020 => vec![ES2015, ES2017, ES2020],
+        }
+    }
+}
+
+/// Returns true if `name` is a global variable of any of `envs`.
+pub(crate) fn is_global(envs: &[Env], name: &str) -> bool {
+    ES5.contains(&name)
+        || envs
+            .iter()
+            .flat_map(|env| env.globals())
+            .any(|globals| globals.contains(&name))
+}
@@ -1,13 +1,128 @@
+#![feature(box_syntax)]
+#![feature(box_patterns)]
 #![feature(specialization)]
 
+extern crate serde;
+extern crate swc_atoms;
 extern crate swc_common;
+#[macro_use]
 extern crate swc_ecma_ast;
+extern crate swc_ecma_parser;
 
-mod with;
-
-use swc_common::{errors::Handler, VisitWith};
+pub use self::{
+    config::Config,
+    fix::{apply_fixes, fix, Edit, Fix, Fixed},
+    globals::Env,
+    registry::Registry,
+    rule::{Category, Context, LintResult, Rule, RuleMeta, Severity},
+};
+use swc_common::{
+    comments::Comments,
+    errors::{Applicability, Handler},
+    SourceMap,
+};
 use swc_ecma_ast::*;
 
+mod config;
+mod fix;
+mod globals;
+mod registry;
+mod rule;
+pub mod rules;
+mod scope;
+mod suppress;
+
+/// Runs all rules in `registry` which are not turned off by `config`.
+///
+/// Problems disabled by `// swc-disable-next-line` comments are not reported.
+pub fn lint(
+    cm: &SourceMap,
+    comments: &Comments,
+    registry: &Registry,
+    config: &Config,
+    module: &Module,
+) -> Vec<LintResult> {
+    let mut results = run(Some(cm), registry, config, module);
+    results.retain(|r| !suppress::is_disabled(cm, comments, r.rule, r.span));
+    results
+}
+
+/// Same as [lint], but `program` can be a script.
+pub fn lint_program(
+    cm: &SourceMap,
+    comments: &Comments,
+    registry: &Registry,
+    config: &Config,
+    program: &Program,
+) -> Vec<LintResult> {
+    match *program {
+        Program::Module(ref module) => lint(cm, comments, registry, config, module),
+        Program::Script(ref script) => {
+            // Rules are applied to statements, so a script is linted as a module
+            // without imports and exports.
+            let module = Module {
+                span: script.span,
+                body: script.body.iter().cloned().map(ModuleItem::Stmt).collect(),
+                shebang: script.shebang.clone(),
+            };
+            lint(cm, comments, registry, config, &module)
+        }
+    }
+}
+
+fn run(
+    cm: Option<&SourceMap>,
+    registry: &Registry,
+    config: &Config,
+    module: &Module,
+) -> Vec<LintResult> {
+    let mut results = vec![];
+
+    for rule in registry.rules() {
+        let meta = rule.meta();
+        let severity = config.severity(meta.name).unwrap_or(meta.default_severity);
+        if severity == Severity::Off {
+            continue;
+        }
+
+        rule.check(
+            &mut Context {
+                cm,
+                config,
+                rule: meta.name,
+                severity,
+                results: &mut results,
+            },
+            module,
+        );
+    }
+
+    results.sort_by_key(|r| r.span.lo());
+    results
+}
+
+/// Reports `results` using `handler`. Fixes are reported as suggestions.
+pub fn emit(handler: &Handler, results: &[LintResult]) {
+    for r in results {
+        let msg = format!("{} ({})", r.message, r.rule);
+        let mut diagnostic = match r.severity {
+            Severity::Error => handler.struct_err(&msg),
+            _ => handler.struct_warn(&msg),
+        };
+        diagnostic.set_span(r.span);
+        if let Some(ref fix) = r.fix {
+            diagnostic.multipart_suggestion_with_applicability(
+                "replace with",
+                fix.edits.iter().map(|e| (e.span, e.text.clone())).collect(),
+                Applicability::MachineApplicable,
+            );
+        }
+        diagnostic.emit();
+    }
+}
+
+/// Runs built-in rules with default severity.
 pub fn lint_all(handler: &Handler, module: &Module) {
-    module.visit_with(&mut with::With { handler });
+    let results = run(None, &Registry::default(), &Config::default(), module);
+    emit(handler, &results)
 }
@@ -0,0 +1,43 @@
+use crate::{rule::Rule, rules};
+
+/// List of rules known to the linter.
+pub struct Registry {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Registry {
+    /// Creates a registry without any rule.
+    pub fn empty() -> Self {
+        Registry { rules: vec![] }
+    }
+
+    /// Replaces a rule with same name, if any.
+    pub fn register<R>(&mut self, rule: R)
+    where
+        R: Rule + 'static,
+    {
+        let name = rule.meta().name;
+        self.rules.retain(|r| r.meta().name != name);
+        self.rules.push(box rule);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Rule> {
+        self.rules
+            .iter()
+            .find(|r| r.meta().name == name)
+            .map(|r| &**r)
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|r| &**r)
+    }
+}
+
+/// Creates a registry with all built-in rules.
+impl Default for Registry {
+    fn default() -> Self {
+        Registry {
+            rules: rules::all(),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{config::Config, fix::Fix};
+use serde::{Deserialize, Serialize};
+use swc_common::{SourceMap, Span};
+use swc_ecma_ast::Module;
+
+/// A lint rule.
+///
+/// Rules are stateless. State required while checking a module should be
+/// stored in a visitor created by [Rule::check].
+pub trait Rule: Send + Sync {
+    fn meta(&self) -> &'static RuleMeta;
+
+    fn check(&self, cx: &mut Context, module: &Module);
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RuleMeta {
+    /// Name used in config files and suppression comments. e.g. `no-debugger`
+    pub name: &'static str,
+    pub category: Category,
+    /// Used if the rule is not configured by user.
+    pub default_severity: Severity,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Category {
+    /// Code which is (almost always) a bug.
+    PossibleErrors,
+    BestPractices,
+    Variables,
+    Style,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Off,
+    Warn,
+    Error,
+}
+
+/// A problem reported by a rule.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintResult {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Fix>,
+}
+
+/// Passed to [Rule::check].
+pub struct Context<'a> {
+    pub(crate) cm: Option<&'a SourceMap>,
+    pub(crate) config: &'a Config,
+    pub(crate) rule: &'static str,
+    pub(crate) severity: Severity,
+    pub(crate) results: &'a mut Vec<LintResult>,
+}
+
+impl Context<'_> {
+    pub fn config(&self) -> &Config {
+        self.config
+    }
+
+    /// Returns the source code of `span`.
+    ///
+    /// Rules should not provide a fix if this returns `None`.
+    pub fn snippet(&self, span: Span) -> Option<String> {
+        self.cm?.span_to_snippet(span).ok()
+    }
+
+    pub fn report<S>(&mut self, span: Span, message: S)
+    where
+        S: Into<String>,
+    {
+        self.push(span, message.into(), None)
+    }
+
+    pub fn report_with_fix<S>(&mut self, span: Span, message: S, fix: Fix)
+    where
+        S: Into<String>,
+    {
+        self.push(span, message.into(), Some(fix))
+    }
+
+    fn push(&mut self, span: Span, message: String, fix: Option<Fix>) {
+        self.results.push(LintResult {
+            rule: self.rule,
+            severity: self.severity,
+            message,
+            span,
+            fix,
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    fix::Fix,
+    rule::{Category, Context, Rule, RuleMeta, Severity},
+};
+use swc_common::{BytePos, Spanned, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+pub struct Eqeqeq;
+
+static META: RuleMeta = RuleMeta {
+    name: "eqeqeq",
+    category: Category::BestPractices,
+    default_severity: Severity::Off,
+    description: "require `===` and `!==`",
+};
+
+impl Rule for Eqeqeq {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        module.visit_with(&mut Visitor { cx });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    cx: &'a mut Context<'b>,
+}
+
+impl Visit<BinExpr> for Visitor<'_, '_> {
+    fn visit(&mut self, e: &BinExpr) {
+        e.visit_children(self);
+
+        let (op, expected) = match e.op {
+            op!("==") => ("==", "==="),
+            op!("!=") => ("!=", "!=="),
+            _ => return,
+        };
+        let msg = format!("expected '{}' and instead saw '{}'", expected, op);
+
+        // Point the operator if there's nothing but whitespaces around it.
+        let between = e
+            .span
+            .with_lo(e.left.span().hi())
+            .with_hi(e.right.span().lo());
+        let op_span = match self.cx.snippet(between) {
+            Some(ref s) if s.trim() == op => {
+                let lo = between.lo() + BytePos((s.len() - s.trim_start().len()) as u32);
+                Some(between.with_lo(lo).with_hi(lo + BytePos(2)))
+            }
+            _ => None,
+        };
+
+        match op_span {
+            Some(span) if is_safe(e) => {
+                self.cx.report_with_fix(span, msg, Fix::replace(span, expected))
+            }
+            Some(span) => self.cx.report(span, msg),
+            None => self.cx.report(e.span, msg),
+        }
+    }
+}
+
+/// Returns true if `==` and `===` are equivalent for `e`.
+fn is_safe(e: &BinExpr) -> bool {
+    fn is_typeof(e: &Expr) -> bool {
+        match *e {
+            Expr::Unary(UnaryExpr {
+                op: op!("typeof"), ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    if is_typeof(&e.left) || is_typeof(&e.right) {
+        return true;
+    }
+
+    match (&*e.left, &*e.right) {
+        (Expr::Lit(Lit::Str(..)), Expr::Lit(Lit::Str(..)))
+        | (Expr::Lit(Lit::Num(..)), Expr::Lit(Lit::Num(..)))
+        | (Expr::Lit(Lit::Bool(..)), Expr::Lit(Lit::Bool(..)))
+        | (Expr::Lit(Lit::Null(..)), Expr::Lit(Lit::Null(..))) => true,
+        _ => false,
+    }
+}
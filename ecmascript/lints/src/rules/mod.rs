@@ -0,0 +1,24 @@
+use crate::rule::Rule;
+
+pub mod eqeqeq;
+pub mod no_debugger;
+pub mod no_redeclare;
+pub mod no_undef;
+pub mod no_unused_vars;
+pub mod no_var;
+pub mod no_with;
+pub mod prefer_const;
+
+/// Built-in rules.
+pub(crate) fn all() -> Vec<Box<dyn Rule>> {
+    vec![
+        box eqeqeq::Eqeqeq,
+        box no_debugger::NoDebugger,
+        box no_redeclare::NoRedeclare,
+        box no_undef::NoUndef,
+        box no_unused_vars::NoUnusedVars,
+        box no_var::NoVar,
+        box no_with::NoWith,
+        box prefer_const::PreferConst,
+    ]
+}
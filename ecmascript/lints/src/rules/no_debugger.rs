@@ -0,0 +1,32 @@
+use crate::rule::{Category, Context, Rule, RuleMeta, Severity};
+use swc_common::{Visit, VisitWith};
+use swc_ecma_ast::*;
+
+pub struct NoDebugger;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-debugger",
+    category: Category::PossibleErrors,
+    default_severity: Severity::Warn,
+    description: "disallow `debugger` statements",
+};
+
+impl Rule for NoDebugger {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        module.visit_with(&mut Visitor { cx });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    cx: &'a mut Context<'b>,
+}
+
+impl Visit<DebuggerStmt> for Visitor<'_, '_> {
+    fn visit(&mut self, stmt: &DebuggerStmt) {
+        self.cx.report(stmt.span, "unexpected debugger statement");
+    }
+}
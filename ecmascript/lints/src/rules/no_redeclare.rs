@@ -0,0 +1,28 @@
+use crate::{
+    rule::{Category, Context, Rule, RuleMeta, Severity},
+    scope,
+};
+use swc_ecma_ast::*;
+
+pub struct NoRedeclare;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-redeclare",
+    category: Category::Variables,
+    default_severity: Severity::Error,
+    description: "disallow redeclaration of `let`, `const`, `class` and imports",
+};
+
+impl Rule for NoRedeclare {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        let analysis = scope::analyze(module);
+
+        for (sym, span) in analysis.redeclarations {
+            cx.report(span, format!("'{}' has already been declared", sym));
+        }
+    }
+}
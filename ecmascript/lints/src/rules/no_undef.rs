@@ -0,0 +1,31 @@
+use crate::{
+    rule::{Category, Context, Rule, RuleMeta, Severity},
+    scope,
+};
+use swc_ecma_ast::*;
+
+pub struct NoUndef;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-undef",
+    category: Category::Variables,
+    default_severity: Severity::Error,
+    description: "disallow use of undeclared variables unless listed in `globals` or `env`",
+};
+
+impl Rule for NoUndef {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        let analysis = scope::analyze(module);
+
+        for (sym, span) in analysis.unresolved {
+            if cx.config().is_global(&sym) {
+                continue;
+            }
+            cx.report(span, format!("'{}' is not defined", sym));
+        }
+    }
+}
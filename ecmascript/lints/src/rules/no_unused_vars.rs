@@ -0,0 +1,46 @@
+use crate::{
+    rule::{Category, Context, Rule, RuleMeta, Severity},
+    scope::{self, BindingKind},
+};
+use swc_ecma_ast::*;
+
+pub struct NoUnusedVars;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-unused-vars",
+    category: Category::Variables,
+    default_severity: Severity::Warn,
+    description: "disallow unused variables, parameters and imports",
+};
+
+impl Rule for NoUnusedVars {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        let analysis = scope::analyze(module);
+
+        for (idx, b) in analysis.bindings.iter().enumerate() {
+            if b.used || b.exported || b.sym.starts_with('_') {
+                continue;
+            }
+
+            let what = match b.kind {
+                BindingKind::Var | BindingKind::Let | BindingKind::Const => {
+                    "is assigned a value but never used"
+                }
+                BindingKind::Class | BindingKind::Function => "is defined but never used",
+                BindingKind::Import => "is imported but never used",
+                BindingKind::Param if analysis.unused_params.contains(&idx) => {
+                    "is defined but never used"
+                }
+                BindingKind::Param
+                | BindingKind::CatchParam
+                | BindingKind::ExprName
+                | BindingKind::Ts => continue,
+            };
+            cx.report(b.span, format!("'{}' {}", b.sym, what));
+        }
+    }
+}
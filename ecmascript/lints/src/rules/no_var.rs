@@ -0,0 +1,117 @@
+use crate::{
+    fix::Fix,
+    rule::{Category, Context, Rule, RuleMeta, Severity},
+    scope::{self, find_ids, Analysis},
+};
+use std::collections::HashMap;
+use swc_common::{BytePos, Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+pub struct NoVar;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-var",
+    category: Category::BestPractices,
+    default_severity: Severity::Off,
+    description: "require `let` or `const` instead of `var`",
+};
+
+impl Rule for NoVar {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        let analysis = scope::analyze(module);
+        let bindings = analysis
+            .bindings
+            .iter()
+            .enumerate()
+            .map(|(idx, b)| (b.span, idx))
+            .collect();
+
+        module.visit_with(&mut Visitor {
+            cx,
+            analysis: &analysis,
+            bindings,
+        });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    cx: &'a mut Context<'b>,
+    analysis: &'a Analysis,
+    /// Span of binding identifier to index of binding.
+    bindings: HashMap<Span, usize>,
+}
+
+impl Visitor<'_, '_> {
+    /// `in_stmts` is false if `v` is not in a statement list, like
+    /// `if (a) var b = 1`, which becomes a syntax error if `var` is replaced.
+    fn check_var(&mut self, v: &VarDecl, in_stmts: bool) {
+        v.decls.visit_with(self);
+
+        if v.kind != VarDeclKind::Var || v.declare {
+            return;
+        }
+
+        let span = v.span.with_hi(v.span.lo() + BytePos(3));
+        let msg = "unexpected var, use let or const instead";
+        if in_stmts && self.can_fix(v) {
+            self.cx.report_with_fix(span, msg, Fix::replace(span, "let"))
+        } else {
+            self.cx.report(span, msg)
+        }
+    }
+
+    /// Returns true if replacing `var` with `let` does not change semantics.
+    fn can_fix(&self, v: &VarDecl) -> bool {
+        v.decls
+            .iter()
+            .flat_map(|d| find_ids(&d.name))
+            .all(|i| match self.bindings.get(&i.span) {
+                Some(&idx) => {
+                    let b = &self.analysis.bindings[idx];
+                    // `var a = a || {}` or usage of `a` outside of the block
+                    // is valid only for `var`.
+                    !b.redeclared
+                        && !b.hoisted
+                        && b.first_ref.map_or(true, |pos| pos >= v.span.hi())
+                }
+                // Redeclaration of a parameter, a function, etc.
+                None => false,
+            })
+    }
+}
+
+impl Visit<Vec<Stmt>> for Visitor<'_, '_> {
+    fn visit(&mut self, stmts: &Vec<Stmt>) {
+        for stmt in stmts {
+            match *stmt {
+                Stmt::Decl(Decl::Var(ref v)) => self.check_var(v, true),
+                _ => stmt.visit_with(self),
+            }
+        }
+    }
+}
+
+impl Visit<Vec<ModuleItem>> for Visitor<'_, '_> {
+    fn visit(&mut self, items: &Vec<ModuleItem>) {
+        for item in items {
+            match *item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(ref v)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(ref v),
+                    ..
+                })) => self.check_var(v, true),
+                _ => item.visit_with(self),
+            }
+        }
+    }
+}
+
+impl Visit<VarDecl> for Visitor<'_, '_> {
+    fn visit(&mut self, v: &VarDecl) {
+        self.check_var(v, false)
+    }
+}
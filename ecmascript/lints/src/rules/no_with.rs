@@ -0,0 +1,36 @@
+use crate::rule::{Category, Context, Rule, RuleMeta, Severity};
+use swc_common::{BytePos, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+pub struct NoWith;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-with",
+    category: Category::BestPractices,
+    default_severity: Severity::Warn,
+    description: "disallow `with` statements",
+};
+
+impl Rule for NoWith {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        module.visit_with(&mut Visitor { cx });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    cx: &'a mut Context<'b>,
+}
+
+impl Visit<WithStmt> for Visitor<'_, '_> {
+    fn visit(&mut self, stmt: &WithStmt) {
+        stmt.visit_children(self);
+
+        // Point the `with` keyword.
+        let span = stmt.span.with_hi(stmt.span.lo() + BytePos(4));
+        self.cx.report(span, "with statement");
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    fix::Fix,
+    rule::{Category, Context, Rule, RuleMeta, Severity},
+    scope::{self, find_ids, Analysis},
+};
+use std::collections::HashMap;
+use swc_common::{BytePos, Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+pub struct PreferConst;
+
+static META: RuleMeta = RuleMeta {
+    name: "prefer-const",
+    category: Category::BestPractices,
+    default_severity: Severity::Off,
+    description: "require `const` for variables which are never reassigned",
+};
+
+impl Rule for PreferConst {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check(&self, cx: &mut Context, module: &Module) {
+        let analysis = scope::analyze(module);
+        let bindings = analysis
+            .bindings
+            .iter()
+            .enumerate()
+            .map(|(idx, b)| (b.span, idx))
+            .collect();
+
+        module.visit_with(&mut Visitor {
+            cx,
+            analysis: &analysis,
+            bindings,
+        });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    cx: &'a mut Context<'b>,
+    analysis: &'a Analysis,
+    /// Span of binding identifier to index of binding.
+    bindings: HashMap<Span, usize>,
+}
+
+impl Visitor<'_, '_> {
+    fn is_const(&self, i: &Ident) -> bool {
+        match self.bindings.get(&i.span) {
+            Some(&idx) => {
+                let b = &self.analysis.bindings[idx];
+                !b.reassigned && !b.redeclared
+            }
+            None => false,
+        }
+    }
+
+    /// `v` does not have initializers if it's the left hand side of a for-in
+    /// or a for-of statement.
+    fn check_let(&mut self, v: &VarDecl, in_for_in_of: bool) {
+        v.visit_children(self);
+
+        if v.kind != VarDeclKind::Let || v.declare {
+            return;
+        }
+
+        // `let a;` cannot be `const a;`
+        let ids: Vec<_> = v
+            .decls
+            .iter()
+            .filter(|d| in_for_in_of || d.init.is_some())
+            .flat_map(|d| find_ids(&d.name))
+            .collect();
+        let all_const = v.decls.iter().all(|d| in_for_in_of || d.init.is_some())
+            && ids.iter().all(|i| self.is_const(i));
+
+        // A declaration is fixed at once.
+        let mut fix = if all_const {
+            let span = v.span.with_hi(v.span.lo() + BytePos(3));
+            Some(Fix::replace(span, "const"))
+        } else {
+            None
+        };
+
+        for i in ids {
+            if !self.is_const(i) {
+                continue;
+            }
+
+            let msg = format!("'{}' is never reassigned, use const instead", i.sym);
+            match fix.take() {
+                Some(fix) => self.cx.report_with_fix(i.span, msg, fix),
+                None => self.cx.report(i.span, msg),
+            }
+        }
+    }
+}
+
+impl Visit<VarDecl> for Visitor<'_, '_> {
+    fn visit(&mut self, v: &VarDecl) {
+        self.check_let(v, false)
+    }
+}
+
+impl Visit<ForInStmt> for Visitor<'_, '_> {
+    fn visit(&mut self, s: &ForInStmt) {
+        match s.left {
+            VarDeclOrPat::VarDecl(ref v) => self.check_let(v, true),
+            VarDeclOrPat::Pat(ref p) => p.visit_with(self),
+        }
+        s.right.visit_with(self);
+        s.body.visit_with(self);
+    }
+}
+
+impl Visit<ForOfStmt> for Visitor<'_, '_> {
+    fn visit(&mut self, s: &ForOfStmt) {
+        match s.left {
+            VarDeclOrPat::VarDecl(ref v) => self.check_let(v, true),
+            VarDeclOrPat::Pat(ref p) => p.visit_with(self),
+        }
+        s.right.visit_with(self);
+        s.body.visit_with(self);
+    }
+}
@@ -0,0 +1,713 @@
+//! Scope analysis used by rules which need to know declarations and references
+//! of variables.
+//!
+//! Note that we can't use `resolver` from `swc_ecma_transforms` because it
+//! binds unresolved references to the current scope to support hoisting, so
+//! undeclared globals are indistinguishable from declared variables.
+use std::collections::HashMap;
+use swc_atoms::JsWord;
+use swc_common::{BytePos, Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingKind {
+    Var,
+    Let,
+    Const,
+    Class,
+    Function,
+    Param,
+    Import,
+    CatchParam,
+    /// Name of a function expression or a class expression.
+    ExprName,
+    /// Typescript types, enums and namespaces.
+    Ts,
+}
+
+impl BindingKind {
+    /// Returns true if redeclaration of the binding is a syntax error.
+    fn is_lexical(self) -> bool {
+        match self {
+            BindingKind::Let | BindingKind::Const | BindingKind::Class | BindingKind::Import => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Binding {
+    pub sym: JsWord,
+    pub span: Span,
+    pub kind: BindingKind,
+    /// True if the binding is read.
+    pub used: bool,
+    /// Exported or ambient bindings are never reported as unused.
+    pub exported: bool,
+    /// True if the binding is assigned after its declaration.
+    pub reassigned: bool,
+    /// True if the binding is declared more than once.
+    pub redeclared: bool,
+    /// True for `var` declared in a block scope.
+    pub hoisted: bool,
+    /// Start of the first reference to the binding, in source order.
+    pub first_ref: Option<BytePos>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScopeKind {
+    Fn,
+    Block,
+}
+
+#[derive(Debug)]
+struct Scope {
+    parent: Option<usize>,
+    kind: ScopeKind,
+    bindings: HashMap<JsWord, usize>,
+    /// Index of bindings declared by parameters, in order.
+    params: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RefKind {
+    Read,
+    Write,
+    /// `a += 1` or `a++`, which reads and writes `a`.
+    Update,
+    /// Operand of `typeof`, which does not throw even if the variable is not
+    /// declared.
+    TypeOf,
+    /// Typescript type reference. This may refer to a global type.
+    Type,
+}
+
+#[derive(Debug)]
+struct Ref {
+    scope: usize,
+    sym: JsWord,
+    span: Span,
+    kind: RefKind,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Analysis {
+    pub bindings: Vec<Binding>,
+    /// References to undeclared variables.
+    pub unresolved: Vec<(JsWord, Span)>,
+    /// Bindings which conflicts with a binding declared previously.
+    pub redeclarations: Vec<(JsWord, Span)>,
+    /// Parameters which are not used, excluding ones followed by a used
+    /// parameter.
+    pub unused_params: Vec<usize>,
+}
+
+pub(crate) fn analyze(module: &Module) -> Analysis {
+    let mut a = Analyzer {
+        scopes: vec![Scope {
+            parent: None,
+            kind: ScopeKind::Fn,
+            bindings: Default::default(),
+            params: vec![],
+        }],
+        cur: 0,
+        decl: None,
+        bindings: vec![],
+        refs: vec![],
+        redeclarations: vec![],
+    };
+    module.visit_with(&mut a);
+
+    let mut unresolved = vec![];
+    for r in a.refs {
+        match a.lookup(r.scope, &r.sym) {
+            Some(idx) => {
+                let b = &mut a.bindings[idx];
+                match r.kind {
+                    RefKind::Write => b.reassigned = true,
+                    RefKind::Update => {
+                        b.used = true;
+                        b.reassigned = true;
+                    }
+                    RefKind::Read | RefKind::TypeOf | RefKind::Type => b.used = true,
+                }
+                if b.first_ref.map_or(true, |pos| r.span.lo() < pos) {
+                    b.first_ref = Some(r.span.lo());
+                }
+            }
+            None => match r.kind {
+                RefKind::Read | RefKind::Write | RefKind::Update => {
+                    unresolved.push((r.sym, r.span))
+                }
+                RefKind::TypeOf | RefKind::Type => {}
+            },
+        }
+    }
+
+    let mut unused_params = vec![];
+    for scope in &a.scopes {
+        // `after-used`: `(a, b) => b` does not report `a`
+        for &idx in scope.params.iter().rev() {
+            if a.bindings[idx].used {
+                break;
+            }
+            unused_params.push(idx);
+        }
+    }
+
+    Analysis {
+        bindings: a.bindings,
+        unresolved,
+        redeclarations: a.redeclarations,
+        unused_params,
+    }
+}
+
+struct Analyzer {
+    scopes: Vec<Scope>,
+    cur: usize,
+    /// `Some` while visiting a binding pattern.
+    decl: Option<BindingKind>,
+    bindings: Vec<Binding>,
+    refs: Vec<Ref>,
+    redeclarations: Vec<(JsWord, Span)>,
+}
+
+impl Analyzer {
+    fn with_scope<F>(&mut self, kind: ScopeKind, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.scopes.push(Scope {
+            parent: Some(self.cur),
+            kind,
+            bindings: Default::default(),
+            params: vec![],
+        });
+        let old = self.cur;
+        self.cur = self.scopes.len() - 1;
+        op(self);
+        self.cur = old;
+    }
+
+    /// Visits `n` as a binding pattern.
+    fn with_decl<N>(&mut self, kind: BindingKind, n: &N)
+    where
+        N: VisitWith<Self>,
+    {
+        let old = self.decl.replace(kind);
+        n.visit_with(self);
+        self.decl = old;
+    }
+
+    /// Visits `n` as an expression.
+    fn with_ref<N>(&mut self, n: &N)
+    where
+        N: VisitWith<Self>,
+    {
+        let old = self.decl.take();
+        n.visit_with(self);
+        self.decl = old;
+    }
+
+    fn lookup(&self, mut scope: usize, sym: &JsWord) -> Option<usize> {
+        loop {
+            let s = &self.scopes[scope];
+            if let Some(&idx) = s.bindings.get(sym) {
+                return Some(idx);
+            }
+            scope = s.parent?;
+        }
+    }
+
+    fn declare(&mut self, i: &Ident, kind: BindingKind) -> usize {
+        // `var` is hoisted to the function scope.
+        let mut scope = self.cur;
+        if kind == BindingKind::Var {
+            while self.scopes[scope].kind != ScopeKind::Fn {
+                scope = self.scopes[scope].parent.unwrap();
+            }
+        }
+
+        if let Some(&idx) = self.scopes[scope].bindings.get(&i.sym) {
+            if kind.is_lexical() || self.bindings[idx].kind.is_lexical() {
+                self.redeclarations.push((i.sym.clone(), i.span));
+            }
+            self.bindings[idx].redeclared = true;
+            return idx;
+        }
+
+        self.bindings.push(Binding {
+            sym: i.sym.clone(),
+            span: i.span,
+            kind,
+            used: false,
+            exported: false,
+            reassigned: false,
+            redeclared: false,
+            hoisted: scope != self.cur,
+            first_ref: None,
+        });
+        let idx = self.bindings.len() - 1;
+        self.scopes[scope].bindings.insert(i.sym.clone(), idx);
+        if kind == BindingKind::Param {
+            self.scopes[scope].params.push(idx);
+        }
+        idx
+    }
+
+    fn reference(&mut self, i: &Ident, kind: RefKind) {
+        self.refs.push(Ref {
+            scope: self.cur,
+            sym: i.sym.clone(),
+            span: i.span,
+            kind,
+        })
+    }
+
+    fn mark_exported(&mut self, i: &Ident) {
+        if let Some(idx) = self.lookup(self.cur, &i.sym) {
+            self.bindings[idx].exported = true;
+        }
+    }
+
+    fn visit_fn_body(&mut self, stmts: &[Stmt]) {
+        let old = self.decl.take();
+        // Function body shares the scope with parameters.
+        for stmt in stmts {
+            stmt.visit_with(self);
+        }
+        self.decl = old;
+    }
+}
+
+impl Visit<Pat> for Analyzer {
+    fn visit(&mut self, p: &Pat) {
+        match *p {
+            Pat::Ident(ref i) => {
+                match self.decl {
+                    Some(kind) => {
+                        self.declare(i, kind);
+                    }
+                    None => self.reference(i, RefKind::Write),
+                }
+                i.type_ann.visit_with(self);
+            }
+            Pat::Assign(ref p) => {
+                p.left.visit_with(self);
+                self.with_ref(&p.right);
+            }
+            Pat::Expr(ref e) => self.with_ref(e),
+            _ => p.visit_children(self),
+        }
+    }
+}
+
+impl Visit<ObjectPatProp> for Analyzer {
+    fn visit(&mut self, p: &ObjectPatProp) {
+        match *p {
+            ObjectPatProp::KeyValue(ref p) => {
+                self.with_ref(&p.key);
+                p.value.visit_with(self);
+            }
+            ObjectPatProp::Assign(ref p) => {
+                match self.decl {
+                    Some(kind) => {
+                        self.declare(&p.key, kind);
+                    }
+                    None => self.reference(&p.key, RefKind::Write),
+                }
+                self.with_ref(&p.value);
+            }
+            ObjectPatProp::Rest(ref p) => p.arg.visit_with(self),
+        }
+    }
+}
+
+impl Visit<Expr> for Analyzer {
+    fn visit(&mut self, e: &Expr) {
+        match *e {
+            Expr::Ident(ref i) => self.reference(i, RefKind::Read),
+            Expr::Unary(UnaryExpr {
+                op: op!("typeof"),
+                arg: box Expr::Ident(ref i),
+                ..
+            }) => self.reference(i, RefKind::TypeOf),
+            _ => e.visit_children(self),
+        }
+    }
+}
+
+impl Visit<AssignExpr> for Analyzer {
+    fn visit(&mut self, e: &AssignExpr) {
+        match e.left {
+            // `a = 1` does not use `a`, but `a += 1` does.
+            PatOrExpr::Expr(box Expr::Ident(ref i)) if e.op == op!("=") => {
+                self.reference(i, RefKind::Write)
+            }
+            PatOrExpr::Expr(box Expr::Ident(ref i)) | PatOrExpr::Pat(box Pat::Ident(ref i))
+                if e.op != op!("=") =>
+            {
+                self.reference(i, RefKind::Update)
+            }
+            _ => e.left.visit_with(self),
+        }
+        e.right.visit_with(self);
+    }
+}
+
+impl Visit<UpdateExpr> for Analyzer {
+    fn visit(&mut self, e: &UpdateExpr) {
+        match *e.arg {
+            Expr::Ident(ref i) => self.reference(i, RefKind::Update),
+            _ => e.arg.visit_with(self),
+        }
+    }
+}
+
+impl Visit<MemberExpr> for Analyzer {
+    fn visit(&mut self, e: &MemberExpr) {
+        e.obj.visit_with(self);
+        if e.computed {
+            e.prop.visit_with(self);
+        }
+    }
+}
+
+impl Visit<Prop> for Analyzer {
+    fn visit(&mut self, p: &Prop) {
+        match *p {
+            Prop::Shorthand(ref i) => self.reference(i, RefKind::Read),
+            Prop::Getter(ref p) => {
+                p.key.visit_with(self);
+                self.with_scope(ScopeKind::Fn, |a| {
+                    if let Some(ref body) = p.body {
+                        a.visit_fn_body(&body.stmts);
+                    }
+                })
+            }
+            Prop::Setter(ref p) => {
+                p.key.visit_with(self);
+                self.with_scope(ScopeKind::Fn, |a| {
+                    a.with_decl(BindingKind::Param, &p.param);
+                    if let Some(ref body) = p.body {
+                        a.visit_fn_body(&body.stmts);
+                    }
+                })
+            }
+            _ => p.visit_children(self),
+        }
+    }
+}
+
+impl Visit<ClassProp> for Analyzer {
+    fn visit(&mut self, p: &ClassProp) {
+        p.decorators.visit_with(self);
+        if p.computed {
+            p.key.visit_with(self);
+        }
+        p.value.visit_with(self);
+        p.type_ann.visit_with(self);
+    }
+}
+
+impl Visit<VarDecl> for Analyzer {
+    fn visit(&mut self, v: &VarDecl) {
+        let kind = match v.kind {
+            VarDeclKind::Var => BindingKind::Var,
+            VarDeclKind::Let => BindingKind::Let,
+            VarDeclKind::Const => BindingKind::Const,
+        };
+
+        for decl in &v.decls {
+            self.with_decl(kind, &decl.name);
+            self.with_ref(&decl.init);
+            if v.declare {
+                for i in find_ids(&decl.name) {
+                    self.mark_exported(i);
+                }
+            }
+        }
+    }
+}
+
+impl Visit<FnDecl> for Analyzer {
+    fn visit(&mut self, f: &FnDecl) {
+        let idx = self.declare(&f.ident, BindingKind::Function);
+        if f.declare {
+            self.bindings[idx].exported = true;
+        }
+        f.function.visit_with(self);
+    }
+}
+
+impl Visit<FnExpr> for Analyzer {
+    fn visit(&mut self, f: &FnExpr) {
+        self.with_scope(ScopeKind::Block, |a| {
+            if let Some(ref i) = f.ident {
+                a.declare(i, BindingKind::ExprName);
+            }
+            f.function.visit_with(a);
+        })
+    }
+}
+
+impl Visit<Function> for Analyzer {
+    fn visit(&mut self, f: &Function) {
+        self.with_ref(&f.decorators);
+
+        self.with_scope(ScopeKind::Fn, |a| {
+            a.with_decl(BindingKind::Param, &f.params);
+            if let Some(ref body) = f.body {
+                a.visit_fn_body(&body.stmts);
+            }
+        })
+    }
+}
+
+impl Visit<ArrowExpr> for Analyzer {
+    fn visit(&mut self, f: &ArrowExpr) {
+        self.with_scope(ScopeKind::Fn, |a| {
+            a.with_decl(BindingKind::Param, &f.params);
+            match f.body {
+                BlockStmtOrExpr::BlockStmt(ref body) => a.visit_fn_body(&body.stmts),
+                BlockStmtOrExpr::Expr(ref body) => body.visit_with(a),
+            }
+        })
+    }
+}
+
+impl Visit<Constructor> for Analyzer {
+    fn visit(&mut self, c: &Constructor) {
+        self.with_ref(&c.key);
+
+        self.with_scope(ScopeKind::Fn, |a| {
+            for param in &c.params {
+                match *param {
+                    PatOrTsParamProp::Pat(ref p) => a.with_decl(BindingKind::Param, p),
+                    PatOrTsParamProp::TsParamProp(ref p) => {
+                        let i = match p.param {
+                            TsParamPropParam::Ident(ref i) => i,
+                            TsParamPropParam::Assign(ref p) => {
+                                a.with_ref(&p.right);
+                                match *p.left {
+                                    Pat::Ident(ref i) => i,
+                                    _ => continue,
+                                }
+                            }
+                        };
+                        // Assigned to `this`.
+                        let idx = a.declare(i, BindingKind::Param);
+                        a.bindings[idx].used = true;
+                    }
+                }
+            }
+            if let Some(ref body) = c.body {
+                a.visit_fn_body(&body.stmts);
+            }
+        })
+    }
+}
+
+impl Visit<ClassDecl> for Analyzer {
+    fn visit(&mut self, c: &ClassDecl) {
+        let idx = self.declare(&c.ident, BindingKind::Class);
+        if c.declare {
+            self.bindings[idx].exported = true;
+        }
+        c.class.visit_with(self);
+    }
+}
+
+impl Visit<ClassExpr> for Analyzer {
+    fn visit(&mut self, c: &ClassExpr) {
+        self.with_scope(ScopeKind::Block, |a| {
+            if let Some(ref i) = c.ident {
+                a.declare(i, BindingKind::ExprName);
+            }
+            c.class.visit_with(a);
+        })
+    }
+}
+
+impl Visit<BlockStmt> for Analyzer {
+    fn visit(&mut self, b: &BlockStmt) {
+        self.with_scope(ScopeKind::Block, |a| b.visit_children(a))
+    }
+}
+
+impl Visit<ForStmt> for Analyzer {
+    fn visit(&mut self, s: &ForStmt) {
+        self.with_scope(ScopeKind::Block, |a| s.visit_children(a))
+    }
+}
+
+impl Visit<ForInStmt> for Analyzer {
+    fn visit(&mut self, s: &ForInStmt) {
+        self.with_scope(ScopeKind::Block, |a| s.visit_children(a))
+    }
+}
+
+impl Visit<ForOfStmt> for Analyzer {
+    fn visit(&mut self, s: &ForOfStmt) {
+        self.with_scope(ScopeKind::Block, |a| s.visit_children(a))
+    }
+}
+
+impl Visit<SwitchStmt> for Analyzer {
+    fn visit(&mut self, s: &SwitchStmt) {
+        s.discriminant.visit_with(self);
+        self.with_scope(ScopeKind::Block, |a| s.cases.visit_with(a))
+    }
+}
+
+impl Visit<CatchClause> for Analyzer {
+    fn visit(&mut self, c: &CatchClause) {
+        self.with_scope(ScopeKind::Block, |a| {
+            a.with_decl(BindingKind::CatchParam, &c.param);
+            // `catch (e) { let e; }` is an error.
+            for stmt in &c.body.stmts {
+                stmt.visit_with(a);
+            }
+        })
+    }
+}
+
+impl Visit<ImportSpecifier> for Analyzer {
+    fn visit(&mut self, s: &ImportSpecifier) {
+        let local = match *s {
+            ImportSpecifier::Specific(ref s) => &s.local,
+            ImportSpecifier::Default(ref s) => &s.local,
+            ImportSpecifier::Namespace(ref s) => &s.local,
+        };
+        self.declare(local, BindingKind::Import);
+    }
+}
+
+impl Visit<ExportDecl> for Analyzer {
+    fn visit(&mut self, e: &ExportDecl) {
+        e.decl.visit_with(self);
+
+        let ids = match e.decl {
+            Decl::Class(ref c) => vec![&c.ident],
+            Decl::Fn(ref f) => vec![&f.ident],
+            Decl::Var(ref v) => v.decls.iter().flat_map(|d| find_ids(&d.name)).collect(),
+            Decl::TsEnum(ref e) => vec![&e.id],
+            Decl::TsModule(TsModuleDecl {
+                id: TsModuleName::Ident(ref i),
+                ..
+            }) => vec![i],
+            _ => vec![],
+        };
+        for i in ids {
+            self.mark_exported(i);
+        }
+    }
+}
+
+impl Visit<NamedExport> for Analyzer {
+    fn visit(&mut self, e: &NamedExport) {
+        // `export { foo } from 'bar'` does not refer local variables.
+        if e.src.is_some() {
+            return;
+        }
+
+        for s in &e.specifiers {
+            match *s {
+                ExportSpecifier::Named(ref s) => self.reference(&s.orig, RefKind::Read),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Visit<JSXElementName> for Analyzer {
+    fn visit(&mut self, n: &JSXElementName) {
+        match *n {
+            // `<div />` is not a reference.
+            JSXElementName::Ident(ref i) if i.sym.starts_with(|c: char| c.is_uppercase()) => {
+                self.reference(i, RefKind::Read)
+            }
+            JSXElementName::JSXMemberExpr(ref e) => e.visit_with(self),
+            _ => {}
+        }
+    }
+}
+
+impl Visit<JSXObject> for Analyzer {
+    fn visit(&mut self, o: &JSXObject) {
+        match *o {
+            JSXObject::Ident(ref i) => self.reference(i, RefKind::Read),
+            JSXObject::JSXMemberExpr(ref e) => e.obj.visit_with(self),
+        }
+    }
+}
+
+impl Visit<JSXMemberExpr> for Analyzer {
+    fn visit(&mut self, e: &JSXMemberExpr) {
+        e.obj.visit_with(self);
+    }
+}
+
+impl Visit<TsEntityName> for Analyzer {
+    fn visit(&mut self, n: &TsEntityName) {
+        match *n {
+            TsEntityName::Ident(ref i) => self.reference(i, RefKind::Type),
+            TsEntityName::TsQualifiedName(ref q) => q.left.visit_with(self),
+        }
+    }
+}
+
+macro_rules! ts_decl {
+    ($T:ty, |$n:ident| $id:expr) => {
+        impl Visit<$T> for Analyzer {
+            fn visit(&mut self, $n: &$T) {
+                if let Some(i) = $id {
+                    let idx = self.declare(i, BindingKind::Ts);
+                    if $n.declare {
+                        self.bindings[idx].exported = true;
+                    }
+                }
+                $n.visit_children(self);
+            }
+        }
+    };
+}
+
+ts_decl!(TsInterfaceDecl, |n| Some(&n.id));
+ts_decl!(TsTypeAliasDecl, |n| Some(&n.id));
+ts_decl!(TsEnumDecl, |n| Some(&n.id));
+ts_decl!(TsModuleDecl, |n| match n.id {
+    TsModuleName::Ident(ref i) => Some(i),
+    TsModuleName::Str(..) => None,
+});
+
+/// Returns binding identifiers of `pat`.
+pub(crate) fn find_ids(pat: &Pat) -> Vec<&Ident> {
+    fn add<'a>(pat: &'a Pat, buf: &mut Vec<&'a Ident>) {
+        match *pat {
+            Pat::Ident(ref i) => buf.push(i),
+            Pat::Array(ref a) => {
+                for elem in a.elems.iter().filter_map(|e| e.as_ref()) {
+                    add(elem, buf)
+                }
+            }
+            Pat::Object(ref o) => {
+                for prop in &o.props {
+                    match *prop {
+                        ObjectPatProp::KeyValue(ref p) => add(&p.value, buf),
+                        ObjectPatProp::Assign(ref p) => buf.push(&p.key),
+                        ObjectPatProp::Rest(ref p) => add(&p.arg, buf),
+                    }
+                }
+            }
+            Pat::Assign(ref p) => add(&p.left, buf),
+            Pat::Rest(ref p) => add(&p.arg, buf),
+            Pat::Expr(..) => {}
+        }
+    }
+
+    let mut buf = vec![];
+    add(pat, &mut buf);
+    buf
+}
@@ -0,0 +1,64 @@
+use swc_common::{
+    comments::{Comment, Comments},
+    BytePos, SourceMap, Span,
+};
+
+const DISABLE_NEXT_LINE: &str = "swc-disable-next-line";
+
+/// Returns true if `rule` is disabled for `span` by a comment like
+///
+/// ```js
+/// // swc-disable-next-line no-debugger, no-with
+/// ```
+///
+/// on the line right above `span`. If no rule is specified, all rules are
+/// disabled.
+pub(crate) fn is_disabled(cm: &SourceMap, comments: &Comments, rule: &str, span: Span) -> bool {
+    let line = match cm.lookup_line(span.lo()) {
+        Ok(line) => line,
+        Err(..) => return false,
+    };
+    if line.line == 0 {
+        return false;
+    }
+
+    // Comments are attached to the first token of the line.
+    let first_token = {
+        let src = match line.sf.get_line(line.line) {
+            Some(src) => src,
+            None => return false,
+        };
+        let indent = src.len() - src.trim_start().len();
+        line.sf.lines[line.line] + BytePos(indent as u32)
+    };
+
+    let cmts = match comments.leading_comments(first_token) {
+        Some(cmts) => cmts,
+        None => return false,
+    };
+
+    cmts.iter()
+        .filter(|c| match cm.lookup_line(c.span.lo()) {
+            Ok(l) => l.line + 1 == line.line,
+            Err(..) => false,
+        })
+        .any(|c| disables(c, rule))
+}
+
+fn disables(c: &Comment, rule: &str) -> bool {
+    let text = c.text.trim();
+    if !text.starts_with(DISABLE_NEXT_LINE) {
+        return false;
+    }
+
+    let rules = &text[DISABLE_NEXT_LINE.len()..];
+    if rules.trim().is_empty() {
+        return true;
+    }
+    // `swc-disable-next-linefoo`
+    if !rules.starts_with(char::is_whitespace) {
+        return false;
+    }
+
+    rules.split(',').any(|r| r.trim() == rule)
+}
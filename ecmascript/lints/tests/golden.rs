@@ -2,6 +2,7 @@
 #![feature(test)]
 
 extern crate swc_common;
+extern crate swc_ecma_ast as ast;
 extern crate swc_ecma_lints as lints;
 extern crate swc_ecma_parser as parser;
 extern crate test;
@@ -9,6 +10,7 @@ extern crate testing;
 extern crate walkdir;
 
 use self::{
+    ast::{Module, ModuleItem},
     parser::{SourceFileInput, Syntax},
     test::{
         test_main, DynTestFn, Options, ShouldPanic::No, TestDesc, TestDescAndFn, TestName, TestType,
@@ -20,7 +22,8 @@ use std::{
     io::{self, Read},
     path::{Path, PathBuf},
 };
-use swc_common::FileName;
+use swc_common::{comments::Comments, FileName};
+use testing::NormalizedOutput;
 use walkdir::{DirEntry, WalkDir};
 
 fn add_test<F: FnOnce() + Send + 'static>(
@@ -83,6 +86,8 @@ fn add_golden_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
             buf
         };
 
+        let dir = file_name.split('/').next().unwrap().to_string();
+
         let name = format!("lints::golden::{}", file_name);
         add_test(tests, name, false, move || {
             eprintln!(
@@ -90,8 +95,27 @@ fn add_golden_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                 file_name, input
             );
 
+            // Only the rule is enabled for `tests/references/<rule>/*.js`.
+            let registry = lints::Registry::default();
+            let mut config = lints::Config::default();
+            if let Some(rule) = registry.get(&dir) {
+                for other in registry.rules() {
+                    if other.meta().name != dir {
+                        config
+                            .rules
+                            .insert(other.meta().name.into(), lints::Severity::Off);
+                    }
+                }
+                // Rules which are not enabled by default.
+                if rule.meta().default_severity == lints::Severity::Off {
+                    config.rules.insert(dir.clone(), lints::Severity::Warn);
+                }
+            }
+
+            let mut syntax_error = false;
             let stderr = ::testing::run_test(false, |cm, handler| {
-                let fm = cm.new_source_file(FileName::Real(path.clone()), input);
+                let fm = cm.new_source_file(FileName::Real(path.clone()), input.clone());
+                let comments = Comments::default();
 
                 let module = {
                     let session = parser::Session { handler: &handler };
@@ -99,23 +123,37 @@ fn add_golden_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                         session,
                         Syntax::default(),
                         SourceFileInput::from(&*fm),
-                        None,
+                        Some(&comments),
                     )
-                    .parse_module()
-                    .map_err(|mut e| {
-                        e.emit();
-                        ()
-                    })?
+                    .parse_module_recovering()
                 };
+                syntax_error = handler.has_errors();
 
-                lints::lint_all(&handler, &module);
+                let results = lints::lint(&cm, &comments, &registry, &config, &module);
+                lints::emit(&handler, &results);
 
-                Ok(())
+                Err(())
             })
             .expect_err("expected to be linted");
             stderr
                 .compare_to_file(format!("{}.stderr", path.display()))
                 .unwrap();
+
+            // Fixing requires a module without syntax errors.
+            if syntax_error {
+                return;
+            }
+
+            let fixed = ::testing::run_test(false, |cm, handler| {
+                let fm = cm.new_source_file(FileName::Real(path.clone()), input.clone());
+                lints::fix(&cm, handler, Syntax::default(), Some(true), &registry, &config, fm)
+            })
+            .expect("failed to fix");
+            if fixed.code != input {
+                NormalizedOutput::from(fixed.code)
+                    .compare_to_file(format!("{}.fixed", path.display()))
+                    .unwrap();
+            }
         });
     }
 
@@ -129,3 +167,66 @@ fn golden() {
     add_golden_tests(&mut tests).unwrap();
     test_main(&args, tests, Some(Options::new()));
 }
+
+/// Lints `src` as a script with only `no-with` enabled.
+///
+/// `with` statements are syntax errors in modules, so `no-with` is tested with
+/// scripts.
+fn lint_script_no_with(src: &str) -> String {
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Real("no-with.js".into()), src.into());
+        let comments = Comments::default();
+
+        let script = parser::Parser::new(
+            parser::Session { handler: &handler },
+            Syntax::default(),
+            SourceFileInput::from(&*fm),
+            Some(&comments),
+        )
+        .parse_script()
+        .map_err(|mut e| {
+            e.emit();
+            ()
+        })?;
+        let module = Module {
+            span: script.span,
+            body: script.body.into_iter().map(ModuleItem::Stmt).collect(),
+            shebang: script.shebang,
+        };
+
+        let registry = lints::Registry::default();
+        let mut config = lints::Config::default();
+        for rule in registry.rules() {
+            if rule.meta().name != "no-with" {
+                config
+                    .rules
+                    .insert(rule.meta().name.into(), lints::Severity::Off);
+            }
+        }
+        config.rules.insert("no-with".into(), lints::Severity::Warn);
+
+        let results = lints::lint(&cm, &comments, &registry, &config, &module);
+        lints::emit(&handler, &results);
+
+        Err(())
+    })
+    .expect_err("expected to be linted")
+    .to_string()
+}
+
+#[test]
+fn no_with() {
+    let stderr = lint_script_no_with("with (obj) {}");
+
+    assert!(stderr.contains("warning: with statement (no-with)"), "{}", stderr);
+    assert!(!stderr.contains("error"), "{}", stderr);
+}
+
+#[test]
+fn no_with_disable_next_line() {
+    let stderr = lint_script_no_with("// swc-disable-next-line no-with\nwith (obj) {}");
+    assert_eq!(stderr, "");
+
+    let stderr = lint_script_no_with("// swc-disable-next-line\nwith (obj) {}");
+    assert_eq!(stderr, "");
+}
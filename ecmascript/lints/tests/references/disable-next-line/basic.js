@@ -0,0 +1,6 @@
+// swc-disable-next-line no-debugger
+debugger;
+// swc-disable-next-line no-with
+debugger;
+// swc-disable-next-line
+debugger;
@@ -0,0 +1,4 @@
+if (typeof a == 'string') {}
+if (a != null) {}
+if ((a)==(b)) {}
+if (a ==/* comment */b) {}
@@ -0,0 +1,8 @@
+let a = 1;
+var a = 2;
+var b = 1;
+var b = 2;
+function c(d) {
+    let d;
+}
+a, b, c;
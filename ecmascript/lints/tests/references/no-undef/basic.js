@@ -0,0 +1,5 @@
+foo();
+typeof bar;
+const baz = 1;
+baz;
+window;
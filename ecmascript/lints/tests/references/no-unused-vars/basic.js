@@ -0,0 +1,9 @@
+import a from 'a';
+import { b } from 'b';
+const c = 1;
+let _d = 2;
+function e(f, g, h) {
+    return g;
+}
+export function i() {}
+b;
@@ -0,0 +1,9 @@
+var a = 1;
+var b = b || {};
+if (a) { var c = 2; }
+function foo(d) {
+    var d;
+    var e = 3;
+    return e;
+}
+a, c, foo;
@@ -0,0 +1,7 @@
+let a = 1;
+let b = 2;
+b += 1;
+let c;
+for (let d of [a, b, c]) {}
+let { e, f } = {};
+f = 3;
@@ -137,6 +137,7 @@ pub enum SyntaxError {
     JSXExpectedClosingTag {
         tag: JsWord,
     },
+    JSXNamespacedNameAsObject,
     InvalidLeadingDecorator,
     DecoratorOnExport,
 
@@ -151,14 +152,41 @@ pub enum SyntaxError {
     ClassProperty,
     ReadOnlyMethod,
     TsBindingPatCannotBeOptional,
+    TsDeclareMethod,
+    TsDeclareFieldInitializer,
 
     TrailingCommaInsideImport,
     DynamicImport,
+    ImportMeta,
+    ImportMetaInScript,
+    InvalidImportMetaProp,
 
     ExportDefaultWithOutFrom,
     ExportNamespaceFrom,
 
     DotsWithoutIdentifier,
+
+    DuplicateBinding {
+        name: JsWord,
+        /// Span of the previous declaration
+        previous: Span,
+    },
+    DuplicateParam(JsWord),
+    DuplicateExport {
+        name: JsWord,
+        /// Span of the previous export
+        previous: Span,
+    },
+    DuplicateConstructor {
+        /// Span of the previous constructor
+        previous: Span,
+    },
+    DuplicateProto,
+    ConstWithoutInit,
+    LetInLexicalBinding,
+    UndeclaredPrivateName {
+        name: JsWord,
+    },
 }
 
 impl<'a> From<ErrorToDiag<'a>> for Error {
@@ -268,6 +296,9 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             JSXExpectedClosingTag { ref tag } => {
                 format!("Expected corresponding JSX closing tag for <{}>", tag).into()
             }
+            JSXNamespacedNameAsObject => {
+                "Namespaced JSX names can't be used in member expressions".into()
+            }
             InvalidLeadingDecorator => {
                 "Leading decorators must be attached to a class declaration".into()
             }
@@ -291,6 +322,10 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             TsBindingPatCannotBeOptional => "A binding pattern parameter cannot be optional in an \
                                              implementation signature."
                 .into(),
+            TsDeclareMethod => {
+                "'declare' modifier cannot appear on class elements of this kind.".into()
+            }
+            TsDeclareFieldInitializer => "Initializers are not allowed in ambient contexts.".into(),
 
             TrailingCommaInsideImport => {
                 "Trailing comma is disallowed inside import(...) arguments".into()
@@ -298,6 +333,9 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             DynamicImport => {
                 "import(...) expressions requires `jsc.parser.dynamicImport` to be true".into()
             }
+            ImportMeta => "import.meta requires `jsc.parser.importMeta` to be true".into(),
+            ImportMetaInScript => "import.meta cannot be used outside of module code".into(),
+            InvalidImportMetaProp => "The only valid meta property for import is import.meta".into(),
             ExportDefaultWithOutFrom => "export default statements required from '...';".into(),
             ExportNamespaceFrom => "export * as Foo from 'foo'; requires \
                                     `jsc.parser.exportNamespaceFrom` to be true"
@@ -306,6 +344,23 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             DotsWithoutIdentifier => {
                 "`...` must be followed by an identifier in declaration contexts".into()
             }
+
+            DuplicateBinding { ref name, .. } => {
+                format!("Identifier '{}' has already been declared", name).into()
+            }
+            DuplicateParam(ref name) => {
+                format!("Duplicate parameter name '{}' is not allowed here", name).into()
+            }
+            DuplicateExport { ref name, .. } => format!("Duplicate export of '{}'", name).into(),
+            DuplicateConstructor { .. } => "A class may only have one constructor".into(),
+            DuplicateProto => {
+                "Duplicate __proto__ fields are not allowed in object literals".into()
+            }
+            ConstWithoutInit => "Missing initializer in const declaration".into(),
+            LetInLexicalBinding => "'let' cannot be used as a lexically bound name".into(),
+            UndeclaredPrivateName { ref name } => {
+                format!("Private field '#{}' must be declared in an enclosing class", name).into()
+            }
         };
 
         let mut db = e.handler.struct_err(&msg);
@@ -321,6 +376,15 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             MultipleDefault { previous } => {
                 db.span_note(previous, "previous default case is declared at here");
             }
+            DuplicateBinding { previous, .. } => {
+                db.span_note(previous, "previous declaration is here");
+            }
+            DuplicateExport { previous, .. } => {
+                db.span_note(previous, "previous export is here");
+            }
+            DuplicateConstructor { previous } => {
+                db.span_note(previous, "previous constructor is here");
+            }
             _ => {}
         }
 
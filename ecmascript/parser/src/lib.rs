@@ -126,6 +126,8 @@ pub enum Syntax {
     Es(EsConfig),
     #[serde(rename = "typescript")]
     Typescript(TsConfig),
+    #[serde(rename = "flow")]
+    Flow(FlowConfig),
 }
 
 impl Default for Syntax {
@@ -139,7 +141,8 @@ impl Syntax {
     pub fn jsx(self) -> bool {
         match self {
             Syntax::Es(EsConfig { jsx: true, .. })
-            | Syntax::Typescript(TsConfig { tsx: true, .. }) => true,
+            | Syntax::Typescript(TsConfig { tsx: true, .. })
+            | Syntax::Flow(FlowConfig { jsx: true, .. }) => true,
             _ => false,
         }
     }
@@ -153,6 +156,42 @@ impl Syntax {
             | Syntax::Typescript(TsConfig {
                 dynamic_import: true,
                 ..
+            })
+            | Syntax::Flow(FlowConfig {
+                dynamic_import: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn import_meta(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                import_meta: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                import_meta: true,
+                ..
+            })
+            | Syntax::Flow(FlowConfig {
+                import_meta: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn top_level_await(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                top_level_await: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                top_level_await: true,
+                ..
             }) => true,
             _ => false,
         }
@@ -179,6 +218,9 @@ impl Syntax {
             })
             | Syntax::Typescript(TsConfig {
                 decorators: true, ..
+            })
+            | Syntax::Flow(FlowConfig {
+                decorators: true, ..
             }) => true,
             _ => false,
         }
@@ -228,9 +270,20 @@ impl Syntax {
     }
 
     /// Should we pare typescript?
+    ///
+    /// This is also true for flow, as flow type annotations are parsed using
+    /// the typescript type grammar.
     pub fn typescript(self) -> bool {
         match self {
-            Syntax::Typescript(..) => true,
+            Syntax::Typescript(..) | Syntax::Flow(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Should we parse flow?
+    pub fn flow(self) -> bool {
+        match self {
+            Syntax::Flow(..) => true,
             _ => false,
         }
     }
@@ -267,6 +320,28 @@ pub struct TsConfig {
 
     #[serde(default)]
     pub dynamic_import: bool,
+
+    #[serde(default)]
+    pub import_meta: bool,
+
+    #[serde(default)]
+    pub top_level_await: bool,
+}
+
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FlowConfig {
+    #[serde(default)]
+    pub jsx: bool,
+
+    #[serde(default)]
+    pub decorators: bool,
+
+    #[serde(default)]
+    pub dynamic_import: bool,
+
+    #[serde(default)]
+    pub import_meta: bool,
 }
 
 #[derive(Clone, Copy, Default, Serialize, Deserialize)]
@@ -315,6 +390,14 @@ pub struct EsConfig {
 
     #[serde(default)]
     pub dynamic_import: bool,
+
+    /// Support `import.meta`.
+    #[serde(default)]
+    pub import_meta: bool,
+
+    /// Support `await` at the top level of a module.
+    #[serde(default)]
+    pub top_level_await: bool,
 }
 
 /// Syntactic context.
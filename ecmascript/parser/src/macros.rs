@@ -29,6 +29,9 @@ macro_rules! tok {
     ('|') => {
         crate::token::Token::BinOp(crate::token::BinOpToken::BitOr)
     };
+    ("||") => {
+        crate::token::Token::BinOp(crate::token::BinOpToken::LogicalOr)
+    };
 
     (',') => {
         crate::token::Token::Comma
@@ -335,6 +338,12 @@ macro_rules! tok {
     ("undefined") => {
         crate::token::Token::Word(crate::token::Word::Ident(js_word!("undefined")))
     };
+    ("type") => {
+        crate::token::Token::Word(crate::token::Word::Ident(js_word!("type")))
+    };
+    ("opaque") => {
+        crate::token::Token::Word(crate::token::Word::Ident(js_word!("opaque")))
+    };
 }
 
 macro_rules! token_including_semi {
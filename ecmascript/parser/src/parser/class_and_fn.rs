@@ -203,14 +203,74 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     fn parse_class_body(&mut self) -> PResult<'a, Vec<ClassMember>> {
+        // Popped even if there's an error, as the parser may be reused.
+        self.state.private_names_in.push(vec![]);
+        let elems = self.parse_class_members();
+        let used = self.state.private_names_in.pop().unwrap_or_default();
+        let elems = elems?;
+
+        for name in used {
+            let declared = elems.iter().any(|m| match *m {
+                ClassMember::PrivateMethod(PrivateMethod { ref key, .. })
+                | ClassMember::PrivateProp(PrivateProp { ref key, .. }) => {
+                    key.id.sym == name.id.sym
+                }
+                _ => false,
+            });
+            if declared {
+                continue;
+            }
+
+            // Resolved by an outer class.
+            match self.state.private_names_in.last_mut() {
+                Some(names) => names.push(name),
+                None => self.early_error(
+                    name.span,
+                    SyntaxError::UndeclaredPrivateName { name: name.id.sym },
+                )?,
+            }
+        }
+
+        Ok(elems)
+    }
+
+    fn parse_class_members(&mut self) -> PResult<'a, Vec<ClassMember>> {
         let mut elems = vec![];
         while !eof!() && !is!('}') {
             if eat_exact!(';') {
                 continue;
             }
 
+            if self.state.recover {
+                let start = cur_pos!();
+                let ctx = self.ctx();
+                match self.parse_class_member() {
+                    Ok(member) => elems.push(member),
+                    Err(err) => {
+                        self.emit_recovered(err);
+                        self.set_ctx(ctx);
+                        self.skip_to_boundary(false, false, start);
+                    }
+                }
+                continue;
+            }
+
             elems.push(self.parse_class_member()?);
         }
+
+        // Constructors without body are overloads of typescript.
+        let mut constructors = elems.iter().filter_map(|m| match *m {
+            ClassMember::Constructor(Constructor {
+                span,
+                body: Some(..),
+                ..
+            }) => Some(span),
+            _ => None,
+        });
+        if let (Some(previous), Some(span)) = (constructors.next(), constructors.next()) {
+            self.early_error(span, SyntaxError::DuplicateConstructor { previous })?;
+        }
+
         Ok(elems)
     }
 
@@ -285,6 +345,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     is_optional,
                     false,
                     false,
+                    false,
                 );
             } else {
                 // TODO: error if static contains escape
@@ -303,6 +364,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let is_static = static_token.is_some();
         let start = static_token.map(|s| s.lo()).unwrap_or(cur_pos!());
 
+        let declare = self.parse_ts_modifier(&["declare"])?.is_some();
         let modifier = self.parse_ts_modifier(&["abstract", "readonly"])?;
         let (is_abstract, readonly) = match modifier {
             Some("abstract") => (true, self.parse_ts_modifier(&["readonly"])?.is_some()),
@@ -324,6 +386,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
             if readonly {
                 syntax_error!(span!(start), SyntaxError::ReadOnlyMethod);
             }
+            if declare {
+                syntax_error!(span!(start), SyntaxError::TsDeclareMethod);
+            }
             if is_constructor(&key) {
                 unexpected!();
             }
@@ -362,15 +427,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
             if readonly {
                 syntax_error!(span!(start), SyntaxError::ReadOnlyMethod);
             }
-            let is_constructor = is_constructor(&key);
+            if declare {
+                syntax_error!(span!(start), SyntaxError::TsDeclareMethod);
+            }
+            // `static constructor(){}` is a static method.
+            let is_constructor = !is_static && is_constructor(&key);
 
             if is_constructor {
                 expect!('(');
                 let params = self.parse_constructor_params()?;
                 expect!(')');
                 let body = self.parse_fn_body(false, false)?;
+                self.check_constructor_names(&params, body.as_ref())?;
 
-                // TODO: check for duplicate constructors
                 return Ok(ClassMember::Constructor(Constructor {
                     span: span!(start),
                     accessibility,
@@ -384,7 +453,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }));
             } else {
                 return self.make_method(
-                    |p| p.parse_formal_params(),
+                    |p| p.parse_unique_formal_params(),
                     MakeMethodArgs {
                         start,
                         is_optional,
@@ -411,6 +480,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 is_optional,
                 false,
                 is_abstract,
+                declare,
             );
         }
 
@@ -429,6 +499,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
             if readonly {
                 syntax_error!(span!(start), SyntaxError::ReadOnlyMethod);
             }
+            if declare {
+                syntax_error!(span!(start), SyntaxError::TsDeclareMethod);
+            }
 
             // handle async foo(){}
             return self.make_method(
@@ -461,6 +534,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 if readonly {
                     unexpected!()
                 }
+                if declare {
+                    syntax_error!(span!(start), SyntaxError::TsDeclareMethod);
+                }
 
                 return match i.sym {
                     js_word!("get") => self.make_method(
@@ -512,6 +588,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         is_optional: bool,
         readonly: bool,
         is_abstract: bool,
+        declare: bool,
     ) -> PResult<'a, ClassMember> {
         if !self.input.syntax().class_props() {
             syntax_error!(span!(start), SyntaxError::ClassProperty)
@@ -534,6 +611,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 if !p.input.syntax().class_props() {
                     syntax_error!(span!(start), SyntaxError::ClassProperty);
                 }
+                if declare {
+                    syntax_error!(span!(start), SyntaxError::TsDeclareFieldInitializer);
+                }
                 assert_and_bump!('=');
                 Some(p.parse_assignment_expr()?)
             } else {
@@ -554,6 +634,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     is_optional,
                     readonly,
                     definite,
+                    declare,
                     type_ann,
                     computed: false,
                 }
@@ -578,6 +659,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     is_optional,
                     readonly,
                     definite,
+                    declare,
                     type_ann,
                 }
                 .into(),
@@ -703,7 +785,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             let body = p.parse_fn_body(is_async, is_generator)?;
 
-            Ok(Function {
+            let function = Function {
                 span: span!(start),
                 decorators,
                 type_params,
@@ -712,7 +794,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 is_async,
                 is_generator,
                 return_type,
-            })
+            };
+            p.check_function_names(&function)?;
+
+            Ok(function)
         })
     }
 
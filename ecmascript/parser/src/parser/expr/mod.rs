@@ -107,7 +107,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
         match *cond {
             // if cond is conditional expression but not left-hand-side expression,
             // just return it.
-            Expr::Cond(..) | Expr::Bin(..) | Expr::Unary(..) | Expr::Update(..) => return Ok(cond),
+            Expr::Cond(..) | Expr::Bin(..) | Expr::Unary(..) | Expr::Update(..) => {
+                // `a + b = c` or `a++ = b`
+                if let Ok(&Token::AssignOp(..)) = cur!(false) {
+                    syntax_error!(cond.span(), SyntaxError::NotSimpleAssign)
+                }
+                return Ok(cond);
+            }
             _ => {}
         }
 
@@ -198,6 +204,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         if eat!("import") {
+            if is!('.') {
+                return self.parse_import_meta_prop(start);
+            }
             return self.parse_dynamic_import(start);
         }
 
@@ -292,6 +301,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 let params = vec![arg];
                 expect!("=>");
                 let body = self.parse_fn_body(true, false)?;
+                self.check_arrow_names(&params, &body)?;
                 return Ok(Box::new(Expr::Arrow(ArrowExpr {
                     span: span!(start),
                     body,
@@ -302,8 +312,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     type_params: None,
                 })));
             } else if can_be_arrow && !self.input.had_line_break_before_cur() && eat!("=>") {
-                let params = vec![id.into()];
+                let params: Vec<Pat> = vec![id.into()];
                 let body = self.parse_fn_body(false, false)?;
+                self.check_arrow_names(&params, &body)?;
 
                 return Ok(Box::new(Expr::Arrow(ArrowExpr {
                     span: span!(start),
@@ -336,14 +347,18 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 elems.push(None);
                 continue;
             }
-            elems.push(
-                self.include_in_expr(true)
-                    .parse_expr_or_spread()
-                    .map(Some)?,
-            );
+            let elem = self.include_in_expr(true).parse_expr_or_spread()?;
             if is!(',') {
                 expect!(',');
+                match elem.spread {
+                    // `[...a,]` is a syntax error if it's reparsed as a pattern.
+                    Some(dot3_token) if is!(']') => {
+                        self.state.trailing_comma_after_rest.push(dot3_token.lo())
+                    }
+                    _ => {}
+                }
             }
+            elems.push(Some(elem));
         }
 
         expect!(']');
@@ -501,8 +516,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let return_type =
             if !self.ctx().in_cond_expr && self.input.syntax().typescript() && is!(':') {
-                let start = cur_pos!();
-                Some(self.parse_ts_type_or_type_predicate_ann(&tok!(':'))?)
+                if self.input.syntax().flow() {
+                    // `((a, b): T)` is a type cast of a parenthesized expression.
+                    self.try_parse_ts(|p| {
+                        let return_type = p.parse_ts_type_or_type_predicate_ann(&tok!(':'))?;
+                        if !is!("=>") {
+                            return Ok(None);
+                        }
+                        Ok(Some(return_type))
+                    })
+                } else {
+                    let start = cur_pos!();
+                    Some(self.parse_ts_type_or_type_predicate_ann(&tok!(':'))?)
+                }
             } else {
                 None
             };
@@ -517,12 +543,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
             expect!("=>");
 
-            let params = self
+            let params: Vec<Pat> = self
                 .parse_paren_items_as_params(paren_items)?
                 .into_iter()
                 .collect();
 
             let body: BlockStmtOrExpr = self.parse_fn_body(async_span.is_some(), false)?;
+            self.check_arrow_names(&params, &body)?;
             return Ok(Box::new(Expr::Arrow(ArrowExpr {
                 span: span!(start),
                 is_async: async_span.is_some(),
@@ -565,7 +592,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
             );
         }
 
-        // TODO: Verify that invalid expression like {a = 1} does not exists.
+        // Invalid expressions like `{a = 1}` are reported at the end of the
+        // statement, as they are valid in arrow parameters.
 
         // ParenthesizedExpression cannot contain spread.
         if expr_or_spreads.len() == 1 {
@@ -1124,7 +1152,30 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 false
             };
 
-            if optional || (self.input.syntax().typescript() && is!(':')) {
+            if self.input.syntax().flow() && !optional && arg.spread.is_none() && is!(':') {
+                // `(x: T)` is a type cast unless it turns out to be a parameter of an arrow
+                // function.
+                let expr = self
+                    .parse_flow_type_cast(start, arg.expr)
+                    .map(Expr::from)
+                    .map(Box::new)?;
+
+                if eat!('=') {
+                    let left = self.reparse_expr_as_pat(PatType::BindingPat, expr)?;
+                    let right = self.parse_assignment_expr()?;
+                    items.push(PatOrExprOrSpread::Pat(Pat::Assign(AssignPat {
+                        span: span!(start),
+                        left: Box::new(left),
+                        right,
+                        type_ann: None,
+                    })));
+                } else {
+                    items.push(PatOrExprOrSpread::ExprOrSpread(ExprOrSpread {
+                        spread: None,
+                        expr,
+                    }));
+                }
+            } else if optional || (self.input.syntax().typescript() && is!(':')) {
                 let start = cur_pos!();
                 let mut pat = self.reparse_expr_as_pat(PatType::BindingPat, arg.expr)?;
                 if optional {
@@ -1192,12 +1243,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     _ => false,
                 }
             } {
-                let params = self
+                let params: Vec<Pat> = self
                     .parse_paren_items_as_params(items)?
                     .into_iter()
                     .collect();
 
                 let body: BlockStmtOrExpr = self.parse_fn_body(false, false)?;
+                self.check_arrow_names(&params, &body)?;
                 expect!(')');
                 let span = span!(start);
                 return Ok(vec![PatOrExprOrSpread::ExprOrSpread(ExprOrSpread {
@@ -1337,6 +1389,26 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         self.parse_subscripts(ExprOrSuper::Expr(import), true)
     }
+
+    /// Parses `.meta` of `import.meta`. `import` should be eaten by caller.
+    pub(super) fn parse_import_meta_prop(&mut self, start: BytePos) -> PResult<'a, Box<Expr>> {
+        if !self.input.syntax().import_meta() {
+            syntax_error!(span!(start), SyntaxError::ImportMeta);
+        }
+        if !self.ctx().module {
+            syntax_error!(span!(start), SyntaxError::ImportMetaInScript);
+        }
+
+        let meta = Ident::new(js_word!("import"), span!(start));
+        expect!('.');
+
+        let prop = self.parse_ident_name()?;
+        if prop.sym != js_word!("meta") {
+            syntax_error!(prop.span, SyntaxError::InvalidImportMetaProp);
+        }
+
+        Ok(Box::new(Expr::MetaProp(MetaPropExpr { meta, prop })))
+    }
 }
 
 fn is_import(obj: &ExprOrSuper) -> bool {
@@ -7,12 +7,41 @@ use swc_common::Spanned;
 impl<'a, I: Tokens> Parser<'a, I> {
     /// Name from spec: 'LogicalORExpression'
     pub(super) fn parse_bin_expr(&mut self) -> PResult<'a, (Box<Expr>)> {
-        let left = self.parse_unary_expr()?;
+        let left = self.parse_bin_operand(0)?;
 
         return_if_arrow!(left);
         self.parse_bin_op_recursively(left, 0)
     }
 
+    /// Parses an unary expression, or `#x` of `#x in obj`.
+    ///
+    /// `min_prec` is the precedence of the operator on the left.
+    fn parse_bin_operand(&mut self, min_prec: u8) -> PResult<'a, Box<Expr>> {
+        if is!('#')
+            && (self.input.syntax().class_private_props()
+                || self.input.syntax().class_private_methods())
+        {
+            let name = self.parse_private_name()?;
+            if !is!("in") || !self.ctx().include_in_expr || op!("in").precedence() <= min_prec {
+                unexpected!()
+            }
+
+            match self.state.private_names_in.last_mut() {
+                Some(names) => names.push(name.clone()),
+                None => self.early_error(
+                    name.span,
+                    SyntaxError::UndeclaredPrivateName {
+                        name: name.id.sym.clone(),
+                    },
+                )?,
+            }
+
+            return Ok(box Expr::PrivateName(name));
+        }
+
+        self.parse_unary_expr()
+    }
+
     /// Parse binary operators with the operator precedence parsing
     /// algorithm. `left` is the left-hand side of the operator.
     /// `minPrec` provides context that allows the function to stop and
@@ -101,7 +130,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         let right = {
-            let left_of_right = self.parse_unary_expr()?;
+            let left_of_right = self.parse_bin_operand(op.precedence())?;
             self.parse_bin_op_recursively(
                 left_of_right,
                 if op == op!("**") {
@@ -130,7 +159,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     pub(in crate::parser) fn parse_unary_expr(&mut self) -> PResult<'a, (Box<Expr>)> {
         let start = cur_pos!();
 
-        if !self.input.syntax().jsx() && self.input.syntax().typescript() && eat!('<') {
+        if !self.input.syntax().jsx()
+            && !self.input.syntax().flow()
+            && self.input.syntax().typescript()
+            && eat!('<')
+        {
             if eat!("const") {
                 expect!('>');
                 let expr = self.parse_unary_expr()?;
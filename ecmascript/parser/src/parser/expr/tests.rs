@@ -5,6 +5,7 @@ use swc_common::DUMMY_SP as span;
 fn syntax() -> Syntax {
     Syntax::Es(EsConfig {
         dynamic_import: true,
+        import_meta: true,
         ..Default::default()
     })
 }
@@ -415,3 +416,147 @@ fn issue_380() {
 }",
     );
 }
+
+#[test]
+fn import_meta() {
+    assert_eq_ignore_span!(
+        test_parser("import.meta.url", syntax(), |p| {
+            p.parse_module()
+                .map(|mut module| match module.body.pop() {
+                    Some(ModuleItem::Stmt(Stmt::Expr(expr))) => expr,
+                    _ => unreachable!(),
+                })
+                .map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+        }),
+        box Expr::Member(MemberExpr {
+            span,
+            obj: ExprOrSuper::Expr(box Expr::MetaProp(MetaPropExpr {
+                meta: Ident::new(js_word!("import"), span),
+                prop: Ident::new(js_word!("meta"), span),
+            })),
+            prop: box Expr::Ident(Ident::new("url".into(), span)),
+            computed: false,
+        })
+    );
+}
+
+/// Parses `s` as an expression in a class body.
+fn private_in(s: &'static str) -> Box<Expr> {
+    test_parser(s, private_syntax(), |p| {
+        p.state.private_names_in.push(vec![]);
+        p.parse_expr().map_err(|mut e| {
+            e.emit();
+            ()
+        })
+    })
+}
+
+fn private_syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        class_private_props: true,
+        class_private_methods: true,
+        ..Default::default()
+    })
+}
+
+fn private_in_error(s: &'static str) -> Option<String> {
+    crate::with_test_sess(s, |sess, input| {
+        Parser::new(sess, private_syntax(), input, None)
+            .parse_script()
+            .map(drop)
+            .map_err(|mut e| e.emit())
+    })
+    .err()
+    .map(|stderr| stderr.to_string())
+}
+
+fn private_x_in_obj() -> Box<Expr> {
+    box Expr::Bin(BinExpr {
+        span,
+        op: op!("in"),
+        left: box Expr::PrivateName(PrivateName {
+            span,
+            id: Ident::new("x".into(), span),
+        }),
+        right: box Expr::Ident(Ident::new("obj".into(), span)),
+    })
+}
+
+#[test]
+fn private_name_in() {
+    assert_eq_ignore_span!(private_in("#x in obj"), private_x_in_obj());
+}
+
+#[test]
+fn private_name_in_right_operand() {
+    assert_eq_ignore_span!(
+        private_in("a && #x in obj"),
+        box Expr::Bin(BinExpr {
+            span,
+            op: op!("&&"),
+            left: box Expr::Ident(Ident::new("a".into(), span)),
+            right: private_x_in_obj(),
+        })
+    );
+}
+
+#[test]
+fn private_name_in_seq() {
+    assert_eq_ignore_span!(
+        private_in("a, #x in obj"),
+        box Expr::Seq(SeqExpr {
+            span,
+            exprs: vec![box Expr::Ident(Ident::new("a".into(), span)), private_x_in_obj()],
+        })
+    );
+}
+
+#[test]
+fn private_name_in_stack_on_error() {
+    crate::with_test_sess("class A { #x; m() { #x in obj; ( } }", |sess, input| {
+        let mut p = Parser::new(sess, private_syntax(), input, None);
+        p.parse_script()
+            .map(drop)
+            .map_err(|mut e| e.cancel())
+            .expect_err("should fail to parse");
+        assert!(p.state.private_names_in.is_empty());
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn private_name_in_errors() {
+    let errors = &[
+        ("a + #x in obj;", "Unexpected token"),
+        ("a in #x in obj;", "Unexpected token"),
+        ("#x in obj;", "Private field '#x' must be declared in an enclosing class"),
+        (
+            "class A { m() { #x in obj; } }",
+            "Private field '#x' must be declared in an enclosing class",
+        ),
+        (
+            "class A { #x; m() { class B { n() { #y in obj; } } } }",
+            "Private field '#y' must be declared in an enclosing class",
+        ),
+    ];
+    for &(src, msg) in errors {
+        let stderr =
+            private_in_error(src).unwrap_or_else(|| panic!("`{}` should be an error", src));
+        assert!(stderr.contains(msg), "{}\n{}", src, stderr);
+    }
+
+    let valid = &[
+        "class A { #x; m() { #x in obj; } }",
+        "class A { m() { #x in obj; } #x() {} }",
+        "class A { #x; m() { class B { n() { #x in obj; } } } }",
+    ];
+    for src in valid {
+        if let Some(stderr) = private_in_error(src) {
+            panic!("`{}` should be valid\n{}", src, stderr)
+        }
+    }
+}
@@ -20,7 +20,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     #[cfg(not(feature = "verify"))]
     pub(in crate::parser) fn verify_expr(&self, expr: Box<Expr>) -> PResult<'a, Box<Expr>> {
-        // TODO(kdy1): Somehow verify it
+        // Invalid properties like `{a = 1}` are reported by `check_object_lits`.
         Ok(expr)
     }
 }
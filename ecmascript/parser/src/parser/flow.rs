@@ -0,0 +1,216 @@
+//! Flow specific parts of the parser.
+//!
+//! Flow type annotations are parsed using the typescript type grammar, so this
+//! module only contains syntax which does not exist in typescript.
+use super::*;
+
+#[parser]
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// `flowParsePrefixType`
+    ///
+    /// `?T` is represented as `T | null | undefined`.
+    pub(super) fn parse_flow_maybe_type(&mut self) -> PResult<'a, Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!();
+        assert_and_bump!('?');
+        let question_span = self.input.prev_span();
+
+        let ty = self.parse_ts_type_operator_or_higher()?;
+
+        Ok(Box::new(
+            TsUnionType {
+                span: span!(start),
+                types: vec![
+                    ty,
+                    Box::new(TsType::TsKeywordType(TsKeywordType {
+                        span: question_span,
+                        kind: TsKeywordTypeKind::TsNullKeyword,
+                    })),
+                    Box::new(TsType::TsKeywordType(TsKeywordType {
+                        span: question_span,
+                        kind: TsKeywordTypeKind::TsUndefinedKeyword,
+                    })),
+                ],
+            }
+            .into(),
+        ))
+    }
+
+    /// `flowParseOpaqueType`
+    ///
+    /// Parses `T: Super = U;` in `opaque type T: Super = U;`. The supertype is
+    /// only used by the type checker, so it's dropped.
+    pub(super) fn parse_flow_opaque_type(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<'a, TsTypeAliasDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_ts_type_params()?;
+        let _supertype = self.eat_then_parse_ts_type(&tok!(':'))?;
+        let type_ann = self.expect_then_parse_ts_type(&tok!('='))?;
+        expect!(';');
+        Ok(TsTypeAliasDecl {
+            declare: self.ctx().in_declare,
+            span: span!(start),
+            id,
+            type_params,
+            type_ann,
+        })
+    }
+
+    /// Eats `type` or `typeof` of `import type { A } from 'a'`.
+    ///
+    /// Returns true if the import declaration only imports types.
+    pub(super) fn eat_flow_import_kind(&mut self) -> PResult<'a, bool> {
+        debug_assert!(self.input.syntax().flow());
+
+        if !is_one_of!("type", "typeof") {
+            return Ok(false);
+        }
+
+        // `import type from 'a'` and `import type, { a } from 'a'` import a value
+        // named `type`.
+        if peeked_is!('{') || peeked_is!('*') || (peeked_is!(BindingIdent) && !peeked_is!("from"))
+        {
+            bump!();
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Eats `type` or `typeof` of `import { type A } from 'a'`.
+    ///
+    /// It's not stored in the ast, as the strip pass removes specifiers which
+    /// are only used as a type.
+    pub(super) fn eat_flow_import_specifier_kind(&mut self) -> PResult<'a, ()> {
+        debug_assert!(self.input.syntax().flow());
+
+        if is_one_of!("type", "typeof") && peeked_is!(IdentName) && !peeked_is!("as") {
+            bump!();
+        }
+
+        Ok(())
+    }
+
+    /// Parses `: T` of a type cast expression `(expr: T)`.
+    pub(super) fn parse_flow_type_cast(
+        &mut self,
+        start: BytePos,
+        expr: Box<Expr>,
+    ) -> PResult<'a, TsTypeCastExpr> {
+        debug_assert!(self.input.syntax().flow());
+
+        let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, cur_pos!())?;
+
+        Ok(TsTypeCastExpr {
+            span: span!(start),
+            expr,
+            type_ann,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::DUMMY_SP as span;
+
+    fn module(s: &'static str) -> Module {
+        test_parser(s, Syntax::Flow(Default::default()), |p| {
+            p.parse_module().map_err(|mut e| {
+                e.emit();
+                ()
+            })
+        })
+    }
+
+    fn expr(s: &'static str) -> Box<Expr> {
+        test_parser(s, Syntax::Flow(Default::default()), |p| {
+            p.parse_expr().map_err(|mut e| {
+                e.emit();
+                ()
+            })
+        })
+    }
+
+    fn parse_import(s: &'static str) -> ImportDecl {
+        match module(s).body.into_iter().next() {
+            Some(ModuleItem::ModuleDecl(ModuleDecl::Import(import))) => import,
+            item => panic!("expected an import declaration, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn type_cast() {
+        assert_eq_ignore_span!(
+            expr("(a: T)"),
+            Box::new(Expr::Paren(ParenExpr {
+                span,
+                expr: Box::new(Expr::TsTypeCast(TsTypeCastExpr {
+                    span,
+                    expr: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+                    type_ann: TsTypeAnn {
+                        span,
+                        type_ann: Box::new(TsType::TsTypeRef(TsTypeRef {
+                            span,
+                            type_name: TsEntityName::Ident(Ident::new("T".into(), span)),
+                            type_params: None,
+                        })),
+                    },
+                })),
+            }))
+        );
+    }
+
+    #[test]
+    fn arrow_params_are_not_type_casts() {
+        match *expr("(a: T, b?: U): V => a") {
+            Expr::Arrow(ArrowExpr {
+                ref params,
+                return_type: Some(..),
+                ..
+            }) => {
+                assert_eq!(params.len(), 2);
+                for param in params {
+                    match *param {
+                        Pat::Ident(Ident {
+                            type_ann: Some(..), ..
+                        }) => {}
+                        _ => panic!("expected a typed parameter, got {:?}", param),
+                    }
+                }
+            }
+            ref e => panic!("expected an arrow function, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn maybe_and_exact_object_types() {
+        module("var a: ?{| b: ?string, c: {||} |} = null;");
+    }
+
+    #[test]
+    fn opaque_type() {
+        module("opaque type A: string = string; export opaque type B = number;");
+    }
+
+    #[test]
+    fn import_type() {
+        let import = parse_import("import type { A } from 'a';");
+        assert!(import.type_only);
+        assert_eq!(import.specifiers.len(), 1);
+
+        assert!(parse_import("import typeof A from 'a';").type_only);
+    }
+
+    #[test]
+    fn import_default_named_type() {
+        let import = parse_import("import type from 'a';");
+        assert!(!import.type_only);
+        assert_eq!(import.specifiers.len(), 1);
+    }
+}
@@ -52,15 +52,16 @@ impl<'a, I: Tokens> Parser<'a, I> {
             JSXAttrName::JSXNamespacedName(i) => JSXElementName::JSXNamespacedName(i),
         };
         while eat!('.') {
+            let obj = match node {
+                JSXElementName::Ident(i) => JSXObject::Ident(i),
+                JSXElementName::JSXMemberExpr(i) => JSXObject::JSXMemberExpr(Box::new(i)),
+                // <a:b.c />
+                JSXElementName::JSXNamespacedName(ref n) => {
+                    syntax_error!(n.span(), SyntaxError::JSXNamespacedNameAsObject)
+                }
+            };
             let prop = self.parse_jsx_ident()?;
-            let new_node = JSXElementName::JSXMemberExpr(JSXMemberExpr {
-                obj: match node {
-                    JSXElementName::Ident(i) => JSXObject::Ident(i),
-                    JSXElementName::JSXMemberExpr(i) => JSXObject::JSXMemberExpr(Box::new(i)),
-                    _ => unimplemented!("JSXNamespacedName -> JSXObject"),
-                },
-                prop,
-            });
+            let new_node = JSXElementName::JSXMemberExpr(JSXMemberExpr { obj, prop });
             node = new_node;
         }
         return Ok(node);
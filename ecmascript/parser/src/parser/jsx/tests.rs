@@ -89,3 +89,36 @@ fn escape_in_attr() {
         })
     );
 }
+
+#[test]
+fn namespaced_name() {
+    assert_eq_ignore_span!(
+        jsx(r#"<svg:rect xlink:href="a" />"#),
+        box Expr::JSXElement(JSXElement {
+            span,
+            opening: JSXOpeningElement {
+                span,
+                attrs: vec![JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    span,
+                    name: JSXAttrName::JSXNamespacedName(JSXNamespacedName {
+                        ns: Ident::new("xlink".into(), span),
+                        name: Ident::new("href".into(), span),
+                    }),
+                    value: Some(box Expr::Lit(Lit::Str(Str {
+                        span,
+                        value: "a".into(),
+                        has_escape: false,
+                    }))),
+                })],
+                name: JSXElementName::JSXNamespacedName(JSXNamespacedName {
+                    ns: Ident::new("svg".into(), span),
+                    name: Ident::new("rect".into(), span),
+                }),
+                self_closing: true,
+                type_args: None,
+            },
+            children: vec![],
+            closing: None
+        })
+    );
+}
@@ -3,9 +3,9 @@
 pub use self::input::{Capturing, Tokens, TokensInput};
 use self::{input::Buffer, util::ParseObject};
 use crate::{
-    error::SyntaxError,
+    error::{ErrorToDiag, SyntaxError},
     parser_macros::parser,
-    token::{Token, Word},
+    token::{Keyword, Token, Word},
     Context, Session, Syntax,
 };
 use ast::*;
@@ -18,11 +18,13 @@ use swc_common::{comments::Comments, errors::DiagnosticBuilder, input::Input, By
 mod macros;
 mod class_and_fn;
 mod expr;
+mod flow;
 mod ident;
 pub mod input;
 mod jsx;
 mod object;
 mod pat;
+mod scope;
 mod stmt;
 mod typescript;
 mod util;
@@ -43,6 +45,26 @@ struct State {
     labels: Vec<JsWord>,
     /// Start position of an assignment expression.
     potential_arrow_start: Option<BytePos>,
+    /// If true, errors in statements and class members are emitted instead of
+    /// being returned.
+    recover: bool,
+    /// Start of the last error emitted while recovering, used to avoid
+    /// reporting an error multiple times.
+    last_error: Option<BytePos>,
+    /// Duplicate `__proto__` properties of object literals, which are valid if
+    /// the object literal is reparsed as a pattern.
+    duplicate_proto: Vec<Span>,
+    /// Properties like `a = 1` in object literals, which are valid only if the
+    /// object literal is reparsed as a pattern.
+    cover_initialized_names: Vec<Span>,
+    /// Spread elements of array literals followed by a comma, which are
+    /// invalid if the array literal is reparsed as a pattern.
+    trailing_comma_after_rest: Vec<BytePos>,
+    /// Private names used by `#x in obj` in each class body being parsed.
+    ///
+    /// They are checked at the end of the class body, because a private name
+    /// can be used before its declaration.
+    private_names_in: Vec<Vec<PrivateName>>,
 }
 
 impl<'a, I: Input> Parser<'a, Lexer<'a, I>> {
@@ -77,18 +99,48 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let shebang = self.parse_shebang()?;
 
-        self.parse_block_body(true, true, None).map(|body| Script {
+        let body = self.parse_block_body(true, true, None)?;
+        self.check_top_level_names(&body, false)?;
+
+        Ok(Script {
             span: span!(start),
             body,
             shebang,
         })
     }
 
+    /// Parses a script, or a module if the source code is not a valid script.
+    ///
+    /// If both fail, the error of the script is returned.
+    pub fn parse_program(&mut self) -> PResult<'a, Program> {
+        let module = self.clone();
+
+        match self.parse_script() {
+            Ok(script) => Ok(Program::Script(script)),
+            Err(mut script_err) => {
+                // Module code is in strict mode, so it should be parsed again.
+                //
+                // Comments are lexed again, but `Comments` ignores duplicates.
+                *self = module;
+                match self.parse_module() {
+                    Ok(module) => {
+                        script_err.cancel();
+                        Ok(Program::Module(module))
+                    }
+                    Err(mut module_err) => {
+                        module_err.cancel();
+                        Err(script_err)
+                    }
+                }
+            }
+        }
+    }
+
     pub fn parse_module(&mut self) -> PResult<'a, Module> {
-        //TODO: parse() -> PResult<'a, Program>
         let ctx = Context {
             module: true,
             strict: true,
+            in_async: self.input.syntax().top_level_await(),
             ..self.ctx()
         };
         // Module code is always in strict mode
@@ -97,13 +149,165 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let start = cur_pos!();
         let shebang = self.parse_shebang()?;
 
-        self.parse_block_body(true, true, None).map(|body| Module {
+        let body = self.parse_block_body(true, true, None)?;
+        self.check_top_level_names(&body, true)?;
+        self.check_exports(&body)?;
+
+        Ok(Module {
             span: span!(start),
             body,
             shebang,
         })
     }
 
+    /// Parses a module, recovering from syntax errors.
+    ///
+    /// Unlike [Parser::parse_module], this emits all syntax errors using the
+    /// handler of the session and returns a module even if there's an error.
+    /// Statements which failed to parse are replaced with [Expr::Invalid],
+    /// and class members which failed to parse are removed.
+    ///
+    /// Use `Handler::has_errors` to check if the module has a syntax error.
+    pub fn parse_module_recovering(&mut self) -> Module {
+        let ctx = Context {
+            module: true,
+            strict: true,
+            in_async: self.input.syntax().top_level_await(),
+            ..self.ctx()
+        };
+        self.set_ctx(ctx);
+        self.state.recover = true;
+
+        let start = cur_pos!();
+        let shebang = self.parse_shebang().unwrap_or_else(|err| {
+            self.emit_recovered(err);
+            None
+        });
+
+        let body = self.parse_block_body(true, true, None).unwrap_or_else(|err| {
+            self.emit_recovered(err);
+            vec![]
+        });
+        // Early errors are emitted because we are recovering.
+        let _ = self.check_top_level_names(&body, true);
+        let _ = self.check_exports(&body);
+
+        Module {
+            span: span!(start),
+            body,
+            shebang,
+        }
+    }
+
+    /// Emits `err` unless an error was emitted at the same position.
+    fn emit_recovered(&mut self, mut err: DiagnosticBuilder) {
+        let pos = err.span.primary_span().map(|span| span.lo());
+        if pos.is_some() && pos == self.state.last_error {
+            err.cancel();
+            return;
+        }
+        self.state.last_error = pos;
+        err.emit();
+    }
+
+    /// Reports an early error, which does not affect parsing of the remaining
+    /// source code.
+    ///
+    /// The error is emitted if we are recovering, and returned otherwise.
+    fn early_error(&mut self, span: Span, error: SyntaxError) -> PResult<'a, ()> {
+        let err = DiagnosticBuilder::from(ErrorToDiag {
+            handler: self.session.handler,
+            span,
+            error,
+        });
+        if self.state.recover {
+            self.emit_recovered(err);
+            return Ok(());
+        }
+        Err(err)
+    }
+
+    /// Skips tokens until the end of current statement or class member, which
+    /// started at `start`.
+    ///
+    /// Stops at `}` which closes the enclosing block, and if `stmt` is true,
+    /// at a keyword which starts a statement on a new line.
+    fn skip_to_boundary(&mut self, stmt: bool, top_level: bool, start: BytePos) {
+        let mut depth = 0usize;
+
+        loop {
+            let is_boundary = match self.input.cur() {
+                None => return,
+                Some(&Token::Error(..)) => false,
+                Some(&Token::Semi) => depth == 0,
+                Some(&Token::LBrace) => {
+                    depth += 1;
+                    false
+                }
+                Some(&Token::RBrace) => {
+                    if depth == 0 {
+                        // `}` without `{` at the top level is skipped.
+                        if !top_level {
+                            return;
+                        }
+                        false
+                    } else {
+                        depth -= 1;
+                        if depth == 0 {
+                            bump!();
+                            return;
+                        }
+                        false
+                    }
+                }
+                Some(&Token::Word(Word::Keyword(k))) if stmt && depth == 0 => {
+                    let starts_stmt = match k {
+                        Keyword::Var
+                        | Keyword::Let
+                        | Keyword::Const
+                        | Keyword::Function
+                        | Keyword::Class
+                        | Keyword::If
+                        | Keyword::For
+                        | Keyword::While
+                        | Keyword::Do
+                        | Keyword::Return
+                        | Keyword::Throw
+                        | Keyword::Try
+                        | Keyword::Switch
+                        | Keyword::Import
+                        | Keyword::Export => true,
+                        _ => false,
+                    };
+                    if starts_stmt
+                        && self.input.cur_pos() != start
+                        && self.input.had_line_break_before_cur()
+                    {
+                        return;
+                    }
+                    false
+                }
+                _ => false,
+            };
+
+            match bump!() {
+                Token::Error(e) => {
+                    let err = DiagnosticBuilder::from(ErrorToDiag {
+                        handler: self.session.handler,
+                        span: e.span,
+                        error: e.error,
+                    });
+                    self.emit_recovered(err);
+                }
+                _ => {}
+            }
+
+            if is_boundary {
+                return;
+            }
+        }
+    }
+
     fn parse_shebang(&mut self) -> PResult<'a, Option<JsWord>> {
         match cur!(false) {
             Ok(&Token::Shebang(..)) => match bump!() {
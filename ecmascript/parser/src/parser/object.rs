@@ -34,6 +34,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
         self.make_object(span!(start), props)
     }
 
+    /// Reports duplicate `__proto__` and properties like `a = 1` in object
+    /// literals of the statement which started at `start`.
+    pub(super) fn check_object_lits(&mut self, start: BytePos) -> PResult<'a, ()> {
+        if let Some(span) = take_first(&mut self.state.cover_initialized_names, start) {
+            self.early_error(span, SyntaxError::AssignProperty)?;
+        }
+        if let Some(span) = take_first(&mut self.state.duplicate_proto, start) {
+            self.early_error(span, SyntaxError::DuplicateProto)?;
+        }
+
+        Ok(())
+    }
+
     /// spec: 'PropertyName'
     pub(super) fn parse_prop_name(&mut self) -> PResult<'a, PropName> {
         let ctx = self.ctx();
@@ -86,6 +99,31 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
     type Prop = PropOrSpread;
 
     fn make_object(&mut self, span: Span, props: Vec<Self::Prop>) -> PResult<'a, Box<Expr>> {
+        let mut protos = props.iter().filter_map(|prop| match *prop {
+            PropOrSpread::Prop(ref prop) => match **prop {
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(Ident { span, ref sym, .. }),
+                    ..
+                })
+                | Prop::KeyValue(KeyValueProp {
+                    key:
+                        PropName::Str(Str {
+                            span,
+                            value: ref sym,
+                            ..
+                        }),
+                    ..
+                }) if &**sym == "__proto__" => Some(span),
+                _ => None,
+            },
+            _ => None,
+        });
+        // It's reported later because it's valid if the object literal is reparsed as
+        // a pattern.
+        if let Some(span) = protos.nth(1) {
+            self.state.duplicate_proto.push(span);
+        }
+
         Ok(Box::new(Expr::Object(ObjectLit { span, props })))
     }
 
@@ -168,6 +206,11 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
 
             if eat!('=') {
                 let value = self.include_in_expr(true).parse_assignment_expr()?;
+                // It's reported later because it's valid if the object literal is reparsed
+                // as a pattern.
+                self.state
+                    .cover_initialized_names
+                    .push(Span::new(ident.span.lo(), value.span().hi(), Default::default()));
                 return Ok(PropOrSpread::Prop(Box::new(Prop::Assign(AssignProp {
                     key: ident,
                     value,
@@ -317,3 +360,15 @@ impl<'a, I: Tokens> ParseObject<'a, Pat> for Parser<'a, I> {
         }))
     }
 }
+
+/// Removes spans starting at or after `start` from `spans`, and returns the
+/// first one.
+fn take_first(spans: &mut Vec<Span>, start: BytePos) -> Option<Span> {
+    let first = spans
+        .iter()
+        .filter(|span| span.lo() >= start)
+        .min_by_key(|span| span.lo())
+        .cloned();
+    spans.retain(|span| span.lo() < start);
+    first
+}
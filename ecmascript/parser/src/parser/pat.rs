@@ -1,5 +1,9 @@
 //! 13.3.3 Destructuring Binding Patterns
-use super::{util::ExprExt, *};
+use super::{
+    scope::{collect_pat, NameKind},
+    util::ExprExt,
+    *,
+};
 use crate::{parser::expr::PatOrExprOrSpread, token::AssignOpToken};
 use std::iter;
 use swc_common::Spanned;
@@ -288,8 +292,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     pub(super) fn parse_unique_formal_params(&mut self) -> PResult<'a, Vec<Pat>> {
-        // FIXME: This is wrong
-        self.parse_formal_params()
+        let params = self.parse_formal_params()?;
+
+        let mut names = vec![];
+        for p in &params {
+            collect_pat(p, NameKind::Param, &mut names);
+        }
+        self.check_names(names, true)?;
+
+        Ok(params)
     }
 }
 ///
@@ -412,6 +423,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }));
             }
             Expr::Object(ObjectLit { span, props }) => {
+                // Duplicate `__proto__` and `a = 1` are allowed in patterns.
+                self.state
+                    .duplicate_proto
+                    .retain(|proto| !span.contains(*proto));
+                self.state
+                    .cover_initialized_names
+                    .retain(|prop| !span.contains(*prop));
+
                 // {}
                 return Ok(Pat::Object(ObjectPat {
                     span,
@@ -464,6 +483,34 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }));
             }
             Expr::Ident(ident) => return Ok(ident.into()),
+            // Flow parses `(x: T)` as a type cast.
+            Expr::TsTypeCast(TsTypeCastExpr { expr, type_ann, .. }) => {
+                let mut pat = self.reparse_expr_as_pat(pat_ty, expr)?;
+                match pat {
+                    Pat::Ident(Ident {
+                        type_ann: ref mut ann,
+                        ..
+                    })
+                    | Pat::Array(ArrayPat {
+                        type_ann: ref mut ann,
+                        ..
+                    })
+                    | Pat::Object(ObjectPat {
+                        type_ann: ref mut ann,
+                        ..
+                    })
+                    | Pat::Rest(RestPat {
+                        type_ann: ref mut ann,
+                        ..
+                    })
+                    | Pat::Assign(AssignPat {
+                        type_ann: ref mut ann,
+                        ..
+                    }) => *ann = Some(type_ann),
+                    Pat::Expr(..) => syntax_error!(span, SyntaxError::InvalidPat),
+                }
+                return Ok(pat);
+            }
             Expr::Array(ArrayLit {
                 elems: mut exprs, ..
             }) => {
@@ -512,6 +559,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
                             spread: Some(dot3_token),
                             expr,
                         }) => {
+                            // `[...a,] = b`
+                            if self
+                                .state
+                                .trailing_comma_after_rest
+                                .contains(&dot3_token.lo())
+                            {
+                                syntax_error!(dot3_token, SyntaxError::CommaAfterRestElement)
+                            }
+
                             // TODO: is BindingPat correct?
                             self.reparse_expr_as_pat(pat_ty.element(), expr)
                                 .map(|pat| {
@@ -527,8 +583,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
                             // TODO: is BindingPat correct?
                             self.reparse_expr_as_pat(pat_ty.element(), expr).map(Some)?
                         }
-                        // TODO: sytax error if last element is ellison and ...rest exists.
-                        None => None,
+                        // Elisions at the end are counted as trailing commas.
+                        None => unreachable!(),
                     };
                     params.push(last);
                 }
@@ -0,0 +1,415 @@
+//! Early errors for redeclarations.
+//!
+//! e.g. `let a; var a;`, `function f(a, a) { 'use strict' }`
+use super::{stmt::IsDirective, *};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum NameKind {
+    /// `let`, `const`, `class`, imports and functions declared at the top
+    /// level of a module.
+    Lexical,
+    /// Functions declared in a block, which can be redeclared in sloppy mode
+    /// (Annex B).
+    BlockFn,
+    /// `var`, and functions declared at the top level of a script or a
+    /// function.
+    Var,
+    /// Parameters of a function, or an identifier parameter of a catch clause.
+    Param,
+}
+
+type Names<'b> = Vec<(&'b Ident, NameKind)>;
+
+pub(super) trait DeclaredNames {
+    /// Pushes names declared by `self`, excluding ones declared in nested
+    /// functions.
+    ///
+    /// `fn_kind` is the kind of function declarations.
+    fn collect_names<'b>(&'b self, fn_kind: NameKind, names: &mut Names<'b>);
+}
+
+impl DeclaredNames for Stmt {
+    fn collect_names<'b>(&'b self, fn_kind: NameKind, names: &mut Names<'b>) {
+        match *self {
+            Stmt::Decl(ref decl) => collect_decl(decl, fn_kind, names),
+            _ => collect_var_names(self, names),
+        }
+    }
+}
+
+impl DeclaredNames for ModuleItem {
+    fn collect_names<'b>(&'b self, fn_kind: NameKind, names: &mut Names<'b>) {
+        match *self {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { ref specifiers, .. })) => {
+                for s in specifiers {
+                    let local = match *s {
+                        ImportSpecifier::Specific(ImportSpecific { ref local, .. })
+                        | ImportSpecifier::Default(ImportDefault { ref local, .. })
+                        | ImportSpecifier::Namespace(ImportStarAs { ref local, .. }) => local,
+                    };
+                    names.push((local, NameKind::Lexical));
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. })) => {
+                collect_decl(decl, fn_kind, names)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                ref decl,
+                ..
+            })) => match *decl {
+                DefaultDecl::Class(ClassExpr {
+                    ident: Some(ref ident),
+                    ..
+                })
+                | DefaultDecl::Fn(FnExpr {
+                    ident: Some(ref ident),
+                    ..
+                }) => names.push((ident, NameKind::Lexical)),
+                _ => {}
+            },
+            ModuleItem::ModuleDecl(..) => {}
+            ModuleItem::Stmt(ref s) => s.collect_names(fn_kind, names),
+        }
+    }
+}
+
+fn collect_decl<'b>(decl: &'b Decl, fn_kind: NameKind, names: &mut Names<'b>) {
+    match *decl {
+        Decl::Var(VarDecl {
+            kind, ref decls, ..
+        }) => {
+            let kind = match kind {
+                VarDeclKind::Var => NameKind::Var,
+                _ => NameKind::Lexical,
+            };
+            for d in decls {
+                collect_pat(&d.name, kind, names);
+            }
+        }
+        Decl::Fn(FnDecl {
+            ref ident,
+            ref function,
+            ..
+        }) => {
+            // Annex B only applies to plain functions.
+            let kind = if fn_kind == NameKind::BlockFn
+                && (function.is_async || function.is_generator)
+            {
+                NameKind::Lexical
+            } else {
+                fn_kind
+            };
+            names.push((ident, kind));
+        }
+        Decl::Class(ClassDecl { ref ident, .. }) => names.push((ident, NameKind::Lexical)),
+        // Typescript declarations can be merged.
+        _ => {}
+    }
+}
+
+/// Collects `var` declared in `stmt`, including ones in nested blocks.
+fn collect_var_names<'b>(stmt: &'b Stmt, names: &mut Names<'b>) {
+    match *stmt {
+        Stmt::Decl(Decl::Var(ref v)) => collect_var_decl(v, names),
+        Stmt::Block(BlockStmt { ref stmts, .. }) => {
+            for s in stmts {
+                collect_var_names(s, names);
+            }
+        }
+        Stmt::If(IfStmt {
+            ref cons, ref alt, ..
+        }) => {
+            collect_var_names(cons, names);
+            if let Some(ref alt) = *alt {
+                collect_var_names(alt, names);
+            }
+        }
+        Stmt::For(ForStmt {
+            ref init, ref body, ..
+        }) => {
+            if let Some(VarDeclOrExpr::VarDecl(ref v)) = *init {
+                collect_var_decl(v, names);
+            }
+            collect_var_names(body, names);
+        }
+        Stmt::ForIn(ForInStmt {
+            ref left, ref body, ..
+        })
+        | Stmt::ForOf(ForOfStmt {
+            ref left, ref body, ..
+        }) => {
+            if let VarDeclOrPat::VarDecl(ref v) = *left {
+                collect_var_decl(v, names);
+            }
+            collect_var_names(body, names);
+        }
+        Stmt::While(WhileStmt { ref body, .. })
+        | Stmt::DoWhile(DoWhileStmt { ref body, .. })
+        | Stmt::With(WithStmt { ref body, .. })
+        | Stmt::Labeled(LabeledStmt { ref body, .. }) => collect_var_names(body, names),
+        Stmt::Try(TryStmt {
+            ref block,
+            ref handler,
+            ref finalizer,
+            ..
+        }) => {
+            for s in &block.stmts {
+                collect_var_names(s, names);
+            }
+            if let Some(ref handler) = *handler {
+                for s in &handler.body.stmts {
+                    collect_var_names(s, names);
+                }
+            }
+            if let Some(ref finalizer) = *finalizer {
+                for s in &finalizer.stmts {
+                    collect_var_names(s, names);
+                }
+            }
+        }
+        Stmt::Switch(SwitchStmt { ref cases, .. }) => {
+            for s in cases.iter().flat_map(|case| case.cons.iter()) {
+                collect_var_names(s, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_var_decl<'b>(v: &'b VarDecl, names: &mut Names<'b>) {
+    if v.kind == VarDeclKind::Var {
+        for d in &v.decls {
+            collect_pat(&d.name, NameKind::Var, names);
+        }
+    }
+}
+
+pub(super) fn collect_pat<'b>(pat: &'b Pat, kind: NameKind, names: &mut Names<'b>) {
+    match *pat {
+        Pat::Ident(ref i) => names.push((i, kind)),
+        Pat::Array(ArrayPat { ref elems, .. }) => {
+            for elem in elems.iter().filter_map(Option::as_ref) {
+                collect_pat(elem, kind, names);
+            }
+        }
+        Pat::Rest(RestPat { ref arg, .. }) => collect_pat(arg, kind, names),
+        Pat::Object(ObjectPat { ref props, .. }) => {
+            for prop in props {
+                match *prop {
+                    ObjectPatProp::KeyValue(KeyValuePatProp { ref value, .. }) => {
+                        collect_pat(value, kind, names)
+                    }
+                    ObjectPatProp::Assign(AssignPatProp { ref key, .. }) => {
+                        names.push((key, kind))
+                    }
+                    ObjectPatProp::Rest(RestPat { ref arg, .. }) => collect_pat(arg, kind, names),
+                }
+            }
+        }
+        Pat::Assign(AssignPat { ref left, .. }) => collect_pat(left, kind, names),
+        Pat::Expr(..) => {}
+    }
+}
+
+/// "IsSimpleParameterList" from spec.
+fn is_simple_params(params: &[Pat]) -> bool {
+    params.iter().all(|p| match *p {
+        Pat::Ident(..) => true,
+        _ => false,
+    })
+}
+
+fn has_use_strict(body: &BlockStmt) -> bool {
+    body.stmts
+        .iter()
+        .take_while(|s| match **s {
+            Stmt::Expr(ref e) => match **e {
+                Expr::Lit(Lit::Str(..)) => true,
+                _ => false,
+            },
+            _ => false,
+        })
+        .any(|s| s.is_use_strict())
+}
+
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// Checks names declared at the top level of a script or a module.
+    pub(super) fn check_top_level_names<T>(&mut self, body: &[T], module: bool) -> PResult<'a, ()>
+    where
+        T: DeclaredNames,
+    {
+        let fn_kind = if module {
+            NameKind::Lexical
+        } else {
+            NameKind::Var
+        };
+        let mut names = vec![];
+        for s in body {
+            s.collect_names(fn_kind, &mut names);
+        }
+        self.check_names(names, false)
+    }
+
+    /// Checks names declared in a block statement or a switch statement.
+    pub(super) fn check_block_names<'b, S>(&mut self, stmts: S) -> PResult<'a, ()>
+    where
+        S: IntoIterator<Item = &'b Stmt>,
+    {
+        let mut names = vec![];
+        for s in stmts {
+            s.collect_names(NameKind::BlockFn, &mut names);
+        }
+        self.check_names(names, false)
+    }
+
+    /// Checks the parameter and the body of a catch clause.
+    pub(super) fn check_catch_names(
+        &mut self,
+        param: Option<&Pat>,
+        body: &BlockStmt,
+    ) -> PResult<'a, ()> {
+        let mut names = vec![];
+        match param {
+            // Annex B allows `var e` in `catch (e) {}`.
+            Some(&Pat::Ident(ref i)) => names.push((i, NameKind::Param)),
+            Some(pat) => collect_pat(pat, NameKind::Lexical, &mut names),
+            None => {}
+        }
+        for s in &body.stmts {
+            s.collect_names(NameKind::BlockFn, &mut names);
+        }
+        self.check_names(names, false)
+    }
+
+    /// Checks a lexical declaration in the head of a for statement against
+    /// `var` in the body.
+    pub(super) fn check_for_names(&mut self, decl: &VarDecl, body: &Stmt) -> PResult<'a, ()> {
+        if decl.kind == VarDeclKind::Var {
+            return Ok(());
+        }
+
+        let mut names = vec![];
+        for d in &decl.decls {
+            collect_pat(&d.name, NameKind::Lexical, &mut names);
+        }
+        collect_var_names(body, &mut names);
+        self.check_names(names, false)
+    }
+
+    /// Checks parameters of a function and names declared in the body.
+    ///
+    /// Parameters may have the same name only if `unique_params` is false,
+    /// the function is not in strict mode, and the parameter list is simple.
+    fn check_fn_names(
+        &mut self,
+        params: Names,
+        simple: bool,
+        body: Option<&BlockStmt>,
+        unique_params: bool,
+    ) -> PResult<'a, ()> {
+        let strict = self.ctx().strict || body.map(has_use_strict).unwrap_or(false);
+
+        let mut names = params;
+        if let Some(body) = body {
+            for s in &body.stmts {
+                s.collect_names(NameKind::Var, &mut names);
+            }
+        }
+        self.check_names(names, unique_params || strict || !simple)
+    }
+
+    /// Checks parameters and the body of a function or a method.
+    ///
+    /// `UniqueFormalParameters` of methods are checked while parsing them.
+    pub(super) fn check_function_names(&mut self, f: &Function) -> PResult<'a, ()> {
+        let mut names = vec![];
+        for p in &f.params {
+            collect_pat(p, NameKind::Param, &mut names);
+        }
+        self.check_fn_names(names, is_simple_params(&f.params), f.body.as_ref(), false)
+    }
+
+    /// Checks parameters and the body of a constructor.
+    pub(super) fn check_constructor_names(
+        &mut self,
+        params: &[PatOrTsParamProp],
+        body: Option<&BlockStmt>,
+    ) -> PResult<'a, ()> {
+        let mut names = vec![];
+        for p in params {
+            match *p {
+                PatOrTsParamProp::Pat(ref pat) => collect_pat(pat, NameKind::Param, &mut names),
+                PatOrTsParamProp::TsParamProp(TsParamProp { ref param, .. }) => match *param {
+                    TsParamPropParam::Ident(ref i) => names.push((i, NameKind::Param)),
+                    TsParamPropParam::Assign(AssignPat { ref left, .. }) => {
+                        collect_pat(left, NameKind::Param, &mut names)
+                    }
+                },
+            }
+        }
+        self.check_fn_names(names, true, body, true)
+    }
+
+    /// Checks parameters and the body of an arrow function.
+    pub(super) fn check_arrow_names(
+        &mut self,
+        params: &[Pat],
+        body: &BlockStmtOrExpr,
+    ) -> PResult<'a, ()> {
+        let mut names = vec![];
+        for p in params {
+            collect_pat(p, NameKind::Param, &mut names);
+        }
+        let body = match *body {
+            BlockStmtOrExpr::BlockStmt(ref body) => Some(body),
+            BlockStmtOrExpr::Expr(..) => None,
+        };
+        self.check_fn_names(names, true, body, true)
+    }
+
+    /// Reports the first name which conflicts with a previous one.
+    ///
+    /// `names` should be sorted by position.
+    pub(super) fn check_names(&mut self, names: Names, unique_params: bool) -> PResult<'a, ()> {
+        // Typescript allows merging declarations and overloading functions, so
+        // only parameters are checked.
+        let typescript = self.input.syntax().typescript();
+        // Annex B allows redeclaring functions in a block in sloppy mode.
+        let annex_b = !self.ctx().strict;
+
+        // First declaration of each kind.
+        let mut declared: HashMap<&JsWord, Vec<(Span, NameKind)>> = HashMap::new();
+
+        for (ident, kind) in names {
+            let prev = declared.entry(&ident.sym).or_insert_with(Vec::new);
+
+            for &(span, prev_kind) in prev.iter() {
+                let error = match (prev_kind, kind) {
+                    (NameKind::Param, NameKind::Param) => {
+                        if !unique_params {
+                            continue;
+                        }
+                        SyntaxError::DuplicateParam(ident.sym.clone())
+                    }
+                    _ if typescript => continue,
+                    (NameKind::Var, NameKind::Var)
+                    | (NameKind::Param, NameKind::Var)
+                    | (NameKind::Var, NameKind::Param) => continue,
+                    (NameKind::BlockFn, NameKind::BlockFn) if annex_b => continue,
+                    _ => SyntaxError::DuplicateBinding {
+                        name: ident.sym.clone(),
+                        previous: span,
+                    },
+                };
+                return self.early_error(ident.span, error);
+            }
+
+            if prev.iter().all(|&(_, k)| k != kind) {
+                prev.push((ident.span, kind));
+            }
+        }
+
+        Ok(())
+    }
+}
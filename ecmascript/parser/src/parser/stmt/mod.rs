@@ -1,4 +1,8 @@
-use super::{pat::PatType, *};
+use super::{
+    pat::PatType,
+    scope::{collect_pat, NameKind},
+    *,
+};
 use swc_common::Spanned;
 
 mod module_item;
@@ -19,11 +23,43 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let mut stmts = vec![];
         while {
-            let c = cur!(false).ok();
+            // Errors from lexer are handled below if we are recovering.
+            let c = if self.state.recover {
+                self.input.cur()
+            } else {
+                cur!(false).ok()
+            };
             let b = c != end;
             b
         } {
-            let stmt = self.parse_stmt_like(true, top_level)?;
+            let start = cur_pos!();
+            let stmt = if self.state.recover {
+                let ctx = self.ctx();
+                let labels = self.state.labels.len();
+
+                match self.parse_stmt_like(true, top_level) {
+                    Ok(stmt) => stmt,
+                    Err(err) => {
+                        self.emit_recovered(err);
+                        self.set_ctx(ctx);
+                        self.state.labels.truncate(labels);
+
+                        self.skip_to_boundary(true, end.is_none(), start);
+                        let hi = last_pos!().max(start);
+                        stmts.push(Type::from(Stmt::Expr(Box::new(Expr::Invalid(Invalid {
+                            span: Span::new(start, hi, Default::default()),
+                        })))));
+                        allow_directives = false;
+                        if self.input.cur().is_none() {
+                            break;
+                        }
+                        continue;
+                    }
+                }
+            } else {
+                self.parse_stmt_like(true, top_level)?
+            };
+            self.check_object_lits(start)?;
             if allow_directives {
                 allow_directives = false;
                 if stmt.is_use_strict() {
@@ -46,7 +82,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
             stmts.push(stmt);
         }
 
-        if end.is_some() {
+        // Eof is reported while parsing the last statement.
+        if end.is_some() && !(self.state.recover && self.input.cur().is_none()) {
             bump!();
         }
 
@@ -71,6 +108,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
     {
         let decorators = self.parse_decorators(true)?;
 
+        // `import.meta` is an expression statement.
+        if self.input.syntax().import_meta() && is!("import") && peeked_is!('.') {
+            return self
+                .parse_stmt_internal(include_decl, top_level, decorators)
+                .map(From::from);
+        }
+
         if is_one_of!("import", "export") {
             return self.handle_import_export(top_level, decorators);
         }
@@ -194,7 +238,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         if is!('{') {
-            return self.parse_block(false).map(Stmt::Block);
+            let block = self.parse_block(false)?;
+            self.check_block_names(&block.stmts)?;
+            return Ok(Stmt::Block(block));
         }
 
         if eat_exact!(';') {
@@ -356,6 +402,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
         expect!('}');
         cases.extend(cur);
 
+        self.check_block_names(cases.iter().flat_map(|case| case.cons.iter()))?;
+
         Ok(Stmt::Switch(SwitchStmt {
             span: span!(switch_start),
             discriminant,
@@ -386,23 +434,26 @@ impl<'a, I: Tokens> Parser<'a, I> {
         assert_and_bump!("try");
 
         let block = self.parse_block(false)?;
+        self.check_block_names(&block.stmts)?;
 
         let catch_start = cur_pos!();
         let handler = if eat!("catch") {
             let param = self.parse_catch_param()?;
-            self.parse_block(false)
-                .map(|body| CatchClause {
-                    span: span!(catch_start),
-                    param,
-                    body,
-                })
-                .map(Some)?
+            let body = self.parse_block(false)?;
+            self.check_catch_names(param.as_ref(), &body)?;
+            Some(CatchClause {
+                span: span!(catch_start),
+                param,
+                body,
+            })
         } else {
             None
         };
 
         let finalizer = if eat!("finally") {
-            self.parse_block(false).map(Some)?
+            let finalizer = self.parse_block(false)?;
+            self.check_block_names(&finalizer.stmts)?;
+            Some(finalizer)
         } else {
             if handler.is_none() {
                 unexpected!();
@@ -455,12 +506,23 @@ impl<'a, I: Tokens> Parser<'a, I> {
             } else {
                 self.ctx()
             };
-            decls.push(self.with_ctx(ctx).parse_var_declarator(for_loop)?);
+            decls.push(self.with_ctx(ctx).parse_var_declarator(kind, for_loop)?);
         }
         if !for_loop {
             expect!(';');
         }
 
+        if kind != VarDeclKind::Var {
+            // It is a Syntax Error if the BoundNames of BindingList contains "let".
+            let mut names = vec![];
+            for d in &decls {
+                collect_pat(&d.name, NameKind::Lexical, &mut names);
+            }
+            if let Some(&(ident, _)) = names.iter().find(|&&(i, _)| i.sym == js_word!("let")) {
+                self.early_error(ident.span, SyntaxError::LetInLexicalBinding)?;
+            }
+        }
+
         Ok(VarDecl {
             span: span!(start),
             declare: self.ctx().in_declare,
@@ -469,7 +531,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
-    fn parse_var_declarator(&mut self, for_loop: bool) -> PResult<'a, VarDeclarator> {
+    fn parse_var_declarator(
+        &mut self,
+        kind: VarDeclKind,
+        for_loop: bool,
+    ) -> PResult<'a, VarDeclarator> {
         let start = cur_pos!();
 
         let mut name = self.parse_binding_pat_or_ident()?;
@@ -522,6 +588,12 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     None
                 } else {
                     match name {
+                        // Typescript reports it while type checking.
+                        Pat::Ident(..)
+                            if kind == VarDeclKind::Const && !self.input.syntax().typescript() =>
+                        {
+                            syntax_error!(span!(start), SyntaxError::ConstWithoutInit)
+                        }
                         Pat::Ident(..) => None,
                         _ => syntax_error!(span!(start), SyntaxError::PatVarWithoutInit),
                     }
@@ -652,6 +724,22 @@ impl<'a, I: Tokens> Parser<'a, I> {
         expect!(')');
         let body = self.parse_stmt(false).map(Box::new)?;
 
+        match head {
+            ForHead::For {
+                init: Some(VarDeclOrExpr::VarDecl(ref decl)),
+                ..
+            }
+            | ForHead::ForIn {
+                left: VarDeclOrPat::VarDecl(ref decl),
+                ..
+            }
+            | ForHead::ForOf {
+                left: VarDeclOrPat::VarDecl(ref decl),
+                ..
+            } => self.check_for_names(decl, &body)?,
+            _ => {}
+        }
+
         let span = span!(start);
         Ok(match head {
             ForHead::For { init, test, update } => {
@@ -809,7 +897,7 @@ pub(super) trait StmtLikeParser<'a, Type: IsDirective> {
 #[parser]
 impl<'a, I: Tokens> StmtLikeParser<'a, Stmt> for Parser<'a, I> {
     fn handle_import_export(&mut self, top_level: bool, _: Vec<Decorator>) -> PResult<'a, Stmt> {
-        if self.input.syntax().dynamic_import() && is!("import") {
+        if self.input.syntax().dynamic_import() && is!("import") && peeked_is!('(') {
             return self.parse_primary_expr().map(Stmt::Expr);
         }
         syntax_error!(SyntaxError::ImportExportInScript);
@@ -820,7 +908,7 @@ impl<'a, I: Tokens> StmtLikeParser<'a, Stmt> for Parser<'a, I> {
 mod tests {
     use super::*;
     use crate::EsConfig;
-    use swc_common::DUMMY_SP as span;
+    use swc_common::{comments::Comments, DUMMY_SP as span};
 
     fn stmt(s: &'static str) -> Stmt {
         test_parser(s, Syntax::default(), |p| {
@@ -1347,6 +1435,47 @@ export default function waitUntil(callback, options = {}) {
         );
     }
 
+    #[test]
+    fn import_meta_stmt() {
+        test_parser(
+            "import.meta.url;
+            function foo() {
+                import.meta.url = 'foo';
+            }",
+            Syntax::Es(EsConfig {
+                import_meta: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn top_level_await() {
+        test_parser(
+            "const foo = await bar();
+            for await (const a of b) {}
+            async function baz() {
+                await foo;
+            }",
+            Syntax::Es(EsConfig {
+                top_level_await: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+            },
+        );
+    }
+
     #[test]
     fn issue_411() {
         test_parser(
@@ -1363,4 +1492,231 @@ export default function waitUntil(callback, options = {}) {
             },
         );
     }
+
+    #[test]
+    fn recover_stmts() {
+        let stderr = crate::with_test_sess(
+            "var = 1;
+foo();
+class A { ( ; bar() {} }
+baz(",
+            |sess, input| {
+                let module =
+                    Parser::new(sess, Syntax::default(), input, None).parse_module_recovering();
+
+                let is_invalid = |item: &ModuleItem| match *item {
+                    ModuleItem::Stmt(Stmt::Expr(ref e)) => match **e {
+                        Expr::Invalid(..) => true,
+                        _ => false,
+                    },
+                    _ => false,
+                };
+                assert_eq!(module.body.len(), 4);
+                assert!(is_invalid(&module.body[0]));
+                assert!(!is_invalid(&module.body[1]));
+                match module.body[2] {
+                    ModuleItem::Stmt(Stmt::Decl(Decl::Class(ref c))) => {
+                        assert_eq!(c.class.body.len(), 1)
+                    }
+                    _ => panic!("expected a class declaration"),
+                }
+                assert!(is_invalid(&module.body[3]));
+
+                Err(())
+            },
+        )
+        .expect_err("syntax errors should be emitted");
+
+        assert_eq!(stderr.matches("error:").count(), 3, "{}", stderr);
+    }
+
+    fn early_error(src: &'static str, module: bool) -> Option<String> {
+        crate::with_test_sess(src, |sess, input| {
+            let mut p = Parser::new(sess, Syntax::default(), input, None);
+            let res = if module {
+                p.parse_module().map(drop)
+            } else {
+                p.parse_script().map(drop)
+            };
+            res.map_err(|mut e| e.emit())
+        })
+        .err()
+        .map(|stderr| stderr.to_string())
+    }
+
+    #[test]
+    fn early_errors() {
+        let errors = &[
+            ("let a; let a;", "Identifier 'a' has already been declared"),
+            ("let a; var a;", "Identifier 'a' has already been declared"),
+            ("var a; const a = 1;", "Identifier 'a' has already been declared"),
+            ("{ let a; { var a; } }", "Identifier 'a' has already been declared"),
+            ("let [a, a] = b;", "Identifier 'a' has already been declared"),
+            ("class A {} function A() {}", "Identifier 'A' has already been declared"),
+            (
+                "switch (a) { case 1: let b; default: let b; }",
+                "Identifier 'b' has already been declared",
+            ),
+            ("for (let a;;) { var a; }", "Identifier 'a' has already been declared"),
+            ("try {} catch (e) { let e; }", "Identifier 'e' has already been declared"),
+            ("try {} catch ([e]) { var e; }", "Identifier 'e' has already been declared"),
+            ("function f(a) { let a; }", "Identifier 'a' has already been declared"),
+            ("(a) => { const a = 1; }", "Identifier 'a' has already been declared"),
+            ("function f(a, a) { 'use strict'; }", "Duplicate parameter name 'a'"),
+            ("function f(a, [a]) {}", "Duplicate parameter name 'a'"),
+            ("(a, a) => 1", "Duplicate parameter name 'a'"),
+            ("({ m(a, a) {} })", "Duplicate parameter name 'a'"),
+            (
+                "class A { constructor() {} constructor() {} }",
+                "A class may only have one constructor",
+            ),
+            ("({ __proto__: 1, '__proto__': 2 })", "Duplicate __proto__ fields"),
+            ("const a;", "Missing initializer in const declaration"),
+            ("for (const a;;) {}", "Missing initializer in const declaration"),
+            ("[...a,] = b;", "Trailing comma isn't permitted after a rest element"),
+            ("a + b = c;", "Cannot assign to this"),
+            ("a++ = b;", "Cannot assign to this"),
+            ("-a += b;", "Cannot assign to this"),
+            ("f() = a;", "Cannot assign to this"),
+            ("[a + b] = c;", "Not a pattern"),
+            ("({ a = 1 });", "assignment property is invalid syntax"),
+            ("f({ a = 1 });", "assignment property is invalid syntax"),
+            ("function f() { return { a = 1 }; }", "assignment property is invalid syntax"),
+            ("x = [{ a = 1 }];", "assignment property is invalid syntax"),
+        ];
+        for &(src, msg) in errors {
+            let stderr = early_error(src, false)
+                .unwrap_or_else(|| panic!("`{}` should be an early error", src));
+            assert!(stderr.contains(msg), "{}\n{}", src, stderr);
+        }
+
+        let errors = &[
+            ("export { a, b as a };", "Duplicate export of 'a'"),
+            ("export default 1; export default 2;", "Duplicate export of 'default'"),
+            ("export function a() {} export { a };", "Duplicate export of 'a'"),
+            ("function a() {} function a() {}", "Identifier 'a' has already been declared"),
+            ("import a from 'a'; let a;", "Identifier 'a' has already been declared"),
+        ];
+        for &(src, msg) in errors {
+            let stderr = early_error(src, true)
+                .unwrap_or_else(|| panic!("`{}` should be an early error", src));
+            assert!(stderr.contains(msg), "{}\n{}", src, stderr);
+        }
+    }
+
+    #[test]
+    fn no_early_errors() {
+        let valid = &[
+            "var a; var a; function a() {}",
+            "function f(a, a) {}",
+            "function f(a) { var a; function a() {} }",
+            "{ function a() {} function a() {} }",
+            "try {} catch (e) { var e; }",
+            "for (let a of b) { let a; }",
+            "({ __proto__: a, __proto__: b } = c);",
+            "({ __proto__: a, __proto__: b }) => 1;",
+            "({ __proto__: 1, __proto__() {}, ['__proto__']: 2 });",
+            "[{ __proto__: a, __proto__: b }] = c;",
+            "for (const a in b) {}",
+            "class A { constructor() {} static constructor() {} }",
+            "({ a = 1 } = b);",
+            "({ a = 1 }) => a;",
+            "[{ a = 1 }] = b;",
+            "for ({ a = 1 } of b);",
+            "x = ({ a = 1 }) => a;",
+        ];
+        for src in valid {
+            if let Some(stderr) = early_error(src, false) {
+                panic!("`{}` should be valid\n{}", src, stderr)
+            }
+        }
+    }
+
+    fn program(src: &'static str) -> Program {
+        test_parser(src, Syntax::default(), |p| {
+            p.parse_program().map_err(|mut e| {
+                e.emit();
+                ()
+            })
+        })
+    }
+
+    #[test]
+    fn program_goal() {
+        let scripts = &["with (a) {}", "var a = 010;", "function f(a, a) {}", "a = { import: 1 };"];
+        for src in scripts {
+            match program(src) {
+                Program::Script(..) => {}
+                Program::Module(..) => panic!("`{}` should be parsed as a script", src),
+            }
+        }
+
+        let modules = &[
+            "import a from 'a';",
+            "var a; export { a };",
+            "'use strict'; export default 1;",
+        ];
+        for src in modules {
+            match program(src) {
+                Program::Module(..) => {}
+                Program::Script(..) => panic!("`{}` should be parsed as a module", src),
+            }
+        }
+    }
+
+    #[test]
+    fn program_retries_as_module() {
+        let syntax = Syntax::Es(EsConfig {
+            top_level_await: true,
+            ..Default::default()
+        });
+        let program = test_parser("await a;", syntax, |p| {
+            p.parse_program().map_err(|mut e| {
+                e.emit();
+                ()
+            })
+        });
+        match program {
+            Program::Module(..) => {}
+            Program::Script(..) => panic!("`await a;` should be parsed as a module"),
+        }
+    }
+
+    #[test]
+    fn program_reports_script_error() {
+        // Module code is in strict mode, so this is invalid as a module, too.
+        let stderr = crate::with_test_sess("with (a) {} export {};", |sess, input| {
+            Parser::new(sess, Syntax::default(), input, None)
+                .parse_program()
+                .map(drop)
+                .map_err(|mut e| e.emit())
+        })
+        .expect_err("should fail to parse")
+        .to_string();
+        assert!(
+            stderr.contains("'import', and 'export' cannot be used outside of module code"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn program_module_comments() {
+        let comments = Comments::default();
+        let program = crate::with_test_sess("a; // a\nexport {}; // b\n", |sess, input| {
+            Parser::new(sess, Syntax::default(), input, Some(&comments))
+                .parse_program()
+                .map_err(|mut e| e.emit())
+        })
+        .unwrap();
+
+        let module = match program {
+            Program::Module(module) => module,
+            Program::Script(..) => panic!("should be parsed as a module"),
+        };
+        for item in &module.body {
+            let cmts = comments.trailing_comments(item.span().hi());
+            assert_eq!(cmts.map(|cmts| cmts.len()), Some(1));
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::HashMap;
 
 #[parser]
 impl<'a, I: Tokens> Parser<'a, I> {
@@ -38,6 +39,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                             has_escape,
                         },
                         specifiers: vec![],
+                        type_only: false,
                     }))
                     .map(ModuleItem::from);
                 }
@@ -46,6 +48,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
             _ => {}
         }
 
+        let type_only = self.input.syntax().flow() && self.eat_flow_import_kind()?;
+
         let mut specifiers = vec![];
 
         if is!(BindingIdent) {
@@ -94,6 +98,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             span: span!(start),
             specifiers,
             src,
+            type_only,
         }))
         .map(ModuleItem::from)
     }
@@ -101,6 +106,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// Parse `foo`, `foo2 as bar` in `import { foo, foo2 as bar }`
     fn parse_import_specifier(&mut self) -> PResult<'a, ImportSpecifier> {
         let start = cur_pos!();
+        if self.input.syntax().flow() {
+            self.eat_flow_import_specifier_kind()?;
+        }
         match cur!(false) {
             Ok(&Word(..)) => {
                 let orig_name = self.parse_ident_name()?;
@@ -466,6 +474,83 @@ impl<'a, I: Tokens> Parser<'a, I> {
             _ => unexpected!(),
         }
     }
+
+    /// Reports a name which is exported more than once.
+    pub(in crate::parser) fn check_exports(&mut self, body: &[ModuleItem]) -> PResult<'a, ()> {
+        let mut exported_names = vec![];
+        for item in body {
+            match *item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. })) => {
+                    match *decl {
+                        Decl::Var(VarDecl { ref decls, .. }) => {
+                            let mut names = vec![];
+                            for d in decls {
+                                collect_pat(&d.name, NameKind::Lexical, &mut names);
+                            }
+                            exported_names
+                                .extend(names.into_iter().map(|(i, _)| (i.sym.clone(), i.span)));
+                        }
+                        // Overloads of typescript don't have body.
+                        Decl::Fn(FnDecl {
+                            ref ident,
+                            function: Function { body: Some(..), .. },
+                            ..
+                        })
+                        | Decl::Class(ClassDecl { ref ident, .. }) => {
+                            exported_names.push((ident.sym.clone(), ident.span))
+                        }
+                        // Typescript declarations can be merged.
+                        _ => {}
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    ref specifiers,
+                    ..
+                })) => {
+                    for s in specifiers {
+                        let name = match *s {
+                            ExportSpecifier::Namespace(NamespaceExportSpecifier {
+                                ref name,
+                                ..
+                            }) => name,
+                            ExportSpecifier::Default(DefaultExportSpecifier { ref exported }) => {
+                                exported
+                            }
+                            ExportSpecifier::Named(NamedExportSpecifier {
+                                ref orig,
+                                ref exported,
+                                ..
+                            }) => exported.as_ref().unwrap_or(orig),
+                        };
+                        exported_names.push((name.sym.clone(), name.span));
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Class(..),
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Fn(..),
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    span,
+                    ..
+                })) => exported_names.push((js_word!("default"), span)),
+                _ => {}
+            }
+        }
+
+        let mut exported: HashMap<JsWord, Span> = HashMap::new();
+        for (name, span) in exported_names {
+            if let Some(&previous) = exported.get(&name) {
+                return self.early_error(span, SyntaxError::DuplicateExport { name, previous });
+            }
+            exported.insert(name, span);
+        }
+
+        Ok(())
+    }
 }
 
 impl IsDirective for ModuleItem {
@@ -58,7 +58,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
         debug_assert!(self.input.syntax().typescript());
 
         Ok(match kind {
-            ParsingContext::EnumMembers | ParsingContext::TypeMembers => is!('}'),
+            ParsingContext::EnumMembers => is!('}'),
+            // `|}` of an exact object type
+            ParsingContext::TypeMembers => {
+                is!('}') || (self.input.syntax().flow() && is!('|') && peeked_is!('}'))
+            }
             ParsingContext::HeritageClauseElement => is!('{'),
             ParsingContext::TupleElementTypes => is!(']'),
             ParsingContext::TypeParametersOrArguments => is!('>'),
@@ -243,7 +247,12 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let start = cur_pos!();
 
         let name = self.parse_ident_name()?;
-        let constraint = self.eat_then_parse_ts_type(&tok!("extends"))?;
+        let constraint = if self.input.syntax().flow() {
+            // `<T: Bound>`
+            self.eat_then_parse_ts_type(&tok!(':'))?
+        } else {
+            self.eat_then_parse_ts_type(&tok!("extends"))?
+        };
         let default = self.eat_then_parse_ts_type(&tok!('='))?;
 
         Ok(TsTypeParam {
@@ -409,7 +418,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsEatThenParseType`
-    fn eat_then_parse_ts_type(
+    pub(super) fn eat_then_parse_ts_type(
         &mut self,
         token_to_eat: &'static Token,
     ) -> PResult<'a, Option<Box<TsType>>> {
@@ -423,7 +432,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsExpectThenParseType`
-    fn expect_then_parse_ts_type(&mut self, token: &'static Token) -> PResult<'a, Box<TsType>> {
+    pub(super) fn expect_then_parse_ts_type(
+        &mut self,
+        token: &'static Token,
+    ) -> PResult<'a, Box<TsType>> {
         debug_assert!(self.input.syntax().typescript());
 
         self.in_type().parse_with(|p| {
@@ -885,7 +897,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     fn parse_ts_type_member_semicolon(&mut self) -> PResult<'a, ()> {
         debug_assert!(self.input.syntax().typescript());
 
-        if !eat!(',') {
+        if !eat!(',') && !self.is_ts_list_terminator(ParsingContext::TypeMembers)? {
             expect!(';');
         }
 
@@ -1124,8 +1136,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
         debug_assert!(self.input.syntax().typescript());
 
         expect!('{');
+        if self.input.syntax().flow() {
+            // Exact object types, `{| a: T |}` and `{||}`.
+            if eat!("||") {
+                expect!('}');
+                return Ok(vec![]);
+            }
+            eat!('|');
+        }
         let members =
             self.parse_ts_list(ParsingContext::TypeMembers, |p| p.parse_ts_type_member())?;
+        if self.input.syntax().flow() {
+            eat!('|');
+        }
         expect!('}');
         Ok(members)
     }
@@ -1596,7 +1619,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseTypeOperatorOrHigher`
-    fn parse_ts_type_operator_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
+    pub(super) fn parse_ts_type_operator_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
         debug_assert!(self.input.syntax().typescript());
 
         let operator = if is!("keyof") {
@@ -1615,7 +1638,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 .map(TsType::from)
                 .map(Box::new),
             None => {
-                if is!("infer") {
+                if self.input.syntax().flow() && is!('?') {
+                    self.parse_flow_maybe_type()
+                } else if is!("infer") {
                     self.parse_ts_infer_type().map(TsType::from).map(Box::new)
                 } else {
                     self.parse_ts_array_type_or_higher()
@@ -1855,6 +1880,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             }
 
+            js_word!("opaque") if self.input.syntax().flow() => {
+                if next || (is!("type") && peeked_is!(IdentRef)) {
+                    if next {
+                        bump!();
+                    }
+                    expect!("type");
+                    return self
+                        .parse_flow_opaque_type(start)
+                        .map(From::from)
+                        .map(Some);
+                }
+            }
+
             js_word!("type") => {
                 if next || is!(IdentRef) {
                     if next {
@@ -1905,6 +1943,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             let expr = true; // May be set again by parseFunctionBody.
             let is_async = true;
             let body = p.parse_fn_body(true, false)?;
+            p.check_arrow_names(&params, &body)?;
             Ok(Some(ArrowExpr {
                 span: span!(start),
                 body,
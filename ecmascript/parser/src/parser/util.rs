@@ -223,6 +223,8 @@ pub(super) trait ExprExt {
             }
 
             Expr::TsConstAssertion(..) => false,
+
+            Expr::Invalid(..) => false,
         }
     }
 }
@@ -0,0 +1 @@
+var x = <a:b.c />;
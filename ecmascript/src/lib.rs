@@ -1,4 +1,5 @@
 pub extern crate swc_ecma_ast as ast;
 pub extern crate swc_ecma_codegen as codegen;
+pub extern crate swc_ecma_lints as lints;
 pub extern crate swc_ecma_parser as parser;
 pub extern crate swc_ecma_transforms as transforms;
@@ -127,7 +127,7 @@ fn all(b: &mut Bencher) {
     tr!(b, || chain!(
         compat::es2017(),
         compat::es2016(),
-        compat::es2015(),
+        compat::es2015(Default::default()),
         compat::es3(),
     ));
 }
@@ -164,7 +164,7 @@ fn es2016_exponentation(b: &mut Bencher) {
 
 #[bench]
 fn es2015(b: &mut Bencher) {
-    tr!(b, || compat::es2015());
+    tr!(b, || compat::es2015(Default::default()));
 }
 
 #[bench]
@@ -184,7 +184,7 @@ fn es2015_block_scoping(b: &mut Bencher) {
 
 #[bench]
 fn es2015_classes(b: &mut Bencher) {
-    tr!(b, || compat::es2015::Classes);
+    tr!(b, || compat::es2015::Classes::default());
 }
 
 #[bench]
@@ -194,7 +194,7 @@ fn es2015_computed_props(b: &mut Bencher) {
 
 #[bench]
 fn es2015_destructuring(b: &mut Bencher) {
-    tr!(b, compat::es2015::destructuring);
+    tr!(b, || compat::es2015::destructuring(Default::default()));
 }
 
 #[bench]
@@ -214,7 +214,7 @@ fn es2015_fn_name(b: &mut Bencher) {
 
 #[bench]
 fn es2015_for_of(b: &mut Bencher) {
-    tr!(b, || compat::es2015::for_of());
+    tr!(b, || compat::es2015::for_of(Default::default()));
 }
 
 #[bench]
@@ -229,7 +229,7 @@ fn es2015_shorthand_property(b: &mut Bencher) {
 
 #[bench]
 fn es2015_spread(b: &mut Bencher) {
-    tr!(b, || compat::es2015::spread());
+    tr!(b, || compat::es2015::spread(Default::default()));
 }
 
 #[bench]
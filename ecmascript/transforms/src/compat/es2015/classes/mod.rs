@@ -1,3 +1,4 @@
+pub(crate) use self::super_field::SuperFieldAccessFolder;
 use self::{
     constructor::{
         constructor_fn, make_possible_return_value, replace_this_in_constructor, ConstructorFolder,
@@ -5,11 +6,14 @@ use self::{
     },
     native::is_native,
     prop_name::HashKey,
-    super_field::SuperFieldAccessFolder,
 };
-use crate::util::{
-    alias_ident_for, default_constructor, prepend, prop_name_to_expr, ExprFactory, ModuleItemLike,
-    StmtLike,
+use super::Config;
+use crate::{
+    pass::Pass,
+    util::{
+        alias_ident_for, default_constructor, prepend, prop_name_to_expr, ExprFactory,
+        ModuleItemLike, StmtLike,
+    },
 };
 use ast::*;
 use fxhash::FxBuildHasher;
@@ -58,8 +62,17 @@ type IndexMap<K, V> = indexmap::IndexMap<K, V, FxBuildHasher>;
 ///   return Test;
 /// }();
 /// ```
+///
+/// In loose mode, methods are assigned to the prototype instead of being
+/// defined by `_createClass`, and `_inheritsLoose` is used for subclasses.
 #[derive(Default, Clone, Copy)]
-pub struct Classes;
+pub struct Classes {
+    c: Config,
+}
+
+pub fn classes(c: Config) -> impl Pass {
+    Classes { c }
+}
 
 struct Data {
     key_prop: Box<Prop>,
@@ -357,7 +370,11 @@ impl Classes {
 
             stmts.push(Stmt::Expr(box Expr::Call(CallExpr {
                 span: DUMMY_SP,
-                callee: helper!(inherits, "inherits"),
+                callee: if self.c.loose {
+                    helper!(inherits_loose, "inheritsLoose")
+                } else {
+                    helper!(inherits, "inherits")
+                },
                 args: vec![
                     class_name.clone().as_arg(),
                     super_class_ident.clone().as_arg(),
@@ -643,7 +660,35 @@ impl Classes {
             }))
         }
 
+        /// Foo.prototype.method = function method() {}
+        fn mk_loose_method_assign(
+            class_name: &Ident,
+            is_static: bool,
+            key: Expr,
+            computed: bool,
+            value: Box<Expr>,
+        ) -> Stmt {
+            let obj = if is_static {
+                Expr::Ident(class_name.clone())
+            } else {
+                class_name.clone().member(quote_ident!("prototype"))
+            };
+
+            Stmt::Expr(box Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                left: PatOrExpr::Expr(box Expr::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: obj.as_obj(),
+                    prop: box key,
+                    computed,
+                })),
+                op: op!("="),
+                right: value,
+            }))
+        }
+
         let (mut props, mut static_props) = (IndexMap::default(), IndexMap::default());
+        let mut loose_stmts = vec![];
 
         for m in methods {
             let key = HashKey::from(&m.key);
@@ -653,6 +698,16 @@ impl Classes {
                 _ => false,
             };
             let prop_name = prop_name_to_expr(m.key);
+            // Literal keys are computed in `Foo.prototype['a-b']`.
+            let loose_key = if self.c.loose && m.kind == MethodKind::Method {
+                let computed = match prop_name {
+                    Expr::Ident(..) => computed,
+                    _ => true,
+                };
+                Some((prop_name.clone(), computed))
+            } else {
+                None
+            };
 
             let append_to: &mut IndexMap<_, _> = if m.is_static {
                 &mut static_props
@@ -717,6 +772,17 @@ impl Classes {
                 function,
             });
 
+            if let Some((key, computed)) = loose_key {
+                loose_stmts.push(mk_loose_method_assign(
+                    &class_name,
+                    m.is_static,
+                    key,
+                    computed,
+                    value,
+                ));
+                continue;
+            }
+
             let data = append_to.entry(key).or_insert_with(|| Data {
                 key_prop,
                 get: None,
@@ -731,9 +797,9 @@ impl Classes {
         }
 
         if props.is_empty() && static_props.is_empty() {
-            return vec![];
+            return loose_stmts;
         }
-        vec![mk_create_class_call(
+        loose_stmts.push(mk_create_class_call(
             class_name,
             mk_arg_obj_for_create_class(props),
             if static_props.is_empty() {
@@ -741,7 +807,8 @@ impl Classes {
             } else {
                 Some(mk_arg_obj_for_create_class(static_props))
             },
-        )]
+        ));
+        loose_stmts
     }
 }
 
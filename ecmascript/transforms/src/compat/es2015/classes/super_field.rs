@@ -17,7 +17,7 @@ use swc_common::{Fold, FoldWith, Mark, Span, Spanned, DUMMY_SP};
 ///
 /// _get(Child.prototype.__proto__ || Object.getPrototypeOf(Child.prototype),
 /// 'foo', this).call(this, a);
-pub(super) struct SuperFieldAccessFolder<'a> {
+pub(crate) struct SuperFieldAccessFolder<'a> {
     pub class_name: &'a Ident,
 
     pub vars: &'a mut Vec<VarDeclarator>,
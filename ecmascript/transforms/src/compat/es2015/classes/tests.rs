@@ -11,11 +11,16 @@ fn syntax() -> Syntax {
 }
 
 fn tr() -> impl Fold<Module> {
-    Classes
+    Classes::default()
 }
 
 fn spec_tr() -> impl Fold<Module> {
-    chain!(resolver(), Classes, spread(), block_scoping(),)
+    chain!(
+        resolver(),
+        Classes::default(),
+        spread(Default::default()),
+        block_scoping(),
+    )
 }
 
 test!(
@@ -5490,3 +5495,35 @@ expect(obj.test).toBe(2);
 
 "#
 );
+
+test!(
+    syntax(),
+    |_| classes(Config { loose: true }),
+    loose_methods,
+    r#"
+class Foo extends Bar {
+  method() {}
+  static foo() {}
+  get bar() {}
+}
+"#,
+    r#"
+var Foo = function(_Bar) {
+  'use strict';
+  _inheritsLoose(Foo, _Bar);
+
+  function Foo() {
+    _classCallCheck(this, Foo);
+    return _possibleConstructorReturn(this, _getPrototypeOf(Foo).apply(this, arguments));
+  }
+
+  Foo.prototype.method = function method() {};
+  Foo.foo = function foo() {};
+  _createClass(Foo, [{
+    key: 'bar',
+    get: function () {}
+  }]);
+  return Foo;
+}(Bar);
+"#
+);
@@ -1,3 +1,4 @@
+use super::Config;
 use crate::{
     pass::Pass,
     util::{prop_name_to_expr, ExprFactory, StmtLike},
@@ -31,11 +32,16 @@ mod tests;
 ///     b = _arr2[1],
 ///     rest = _arr2.slice(2);
 /// ```
-pub fn destructuring() -> impl Pass {
-    Destructuring
+///
+/// In loose mode, arrays are destructured by index without using the
+/// iterator protocol.
+pub fn destructuring(c: Config) -> impl Pass {
+    Destructuring { c }
 }
 
-struct Destructuring;
+struct Destructuring {
+    c: Config,
+}
 
 macro_rules! impl_for_for_stmt {
     ($T:tt) => {
@@ -139,6 +145,8 @@ impl Fold<Vec<VarDeclarator>> for AssignFolder {
                         "destructuring pattern binding requires initializer"
                     );
 
+                    let init = decl.init.map(|init| self.make_array(init, &elems));
+
                     // Make ref var if required
                     let ref_ident = make_ref_ident(
                         if self.exporting {
@@ -146,7 +154,7 @@ impl Fold<Vec<VarDeclarator>> for AssignFolder {
                         } else {
                             &mut decls
                         },
-                        decl.init,
+                        init,
                     );
 
                     for (i, elem) in elems.into_iter().enumerate() {
@@ -388,6 +396,7 @@ impl Destructuring {
 
 #[derive(Default)]
 struct AssignFolder {
+    c: Config,
     exporting: bool,
     vars: Vec<VarDeclarator>,
 }
@@ -406,7 +415,7 @@ impl Fold<Expr> for AssignFolder {
     fn fold(&mut self, expr: Expr) -> Expr {
         let expr = match expr {
             // Handle iife
-            Expr::Fn(..) | Expr::Object(..) => Destructuring.fold(expr),
+            Expr::Fn(..) | Expr::Object(..) => Destructuring { c: self.c }.fold(expr),
             _ => expr.fold_children(self),
         };
 
@@ -442,7 +451,7 @@ impl Fold<Expr> for AssignFolder {
                             span: DUMMY_SP,
                             op: op!("="),
                             left: PatOrExpr::Pat(box Pat::Ident(ref_ident.clone())),
-                            right,
+                            right: self.make_array(right, &elems),
                         }));
 
                         for (i, elem) in elems.into_iter().enumerate() {
@@ -623,6 +632,43 @@ impl Fold<Expr> for AssignFolder {
     }
 }
 
+impl AssignFolder {
+    /// Converts an iterable to an array unless loose mode is enabled.
+    ///
+    /// `_slicedToArray(obj, 2)` or `_toArray(obj)` if `elems` contains a rest
+    /// element.
+    fn make_array(&self, obj: Box<Expr>, elems: &[Option<Pat>]) -> Box<Expr> {
+        if self.c.loose {
+            return obj;
+        }
+        match *obj {
+            Expr::Array(..) => return obj,
+            _ => {}
+        }
+
+        let has_rest = elems.iter().any(|elem| match elem {
+            Some(Pat::Rest(..)) => true,
+            _ => false,
+        });
+
+        box Expr::Call(if has_rest {
+            CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(to_array, "toArray"),
+                args: vec![obj.as_arg()],
+                type_args: Default::default(),
+            }
+        } else {
+            CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(sliced_to_array, "slicedToArray"),
+                args: vec![obj.as_arg(), (elems.len() as f64).as_arg()],
+                type_args: Default::default(),
+            }
+        })
+    }
+}
+
 impl<T: StmtLike + VisitWith<DestructuringVisitor>> Fold<Vec<T>> for Destructuring
 where
     Vec<T>: FoldWith<Self>,
@@ -639,7 +685,10 @@ where
         let mut buf = Vec::with_capacity(stmts.len());
 
         for stmt in stmts {
-            let mut folder = AssignFolder::default();
+            let mut folder = AssignFolder {
+                c: self.c,
+                ..Default::default()
+            };
 
             match stmt.try_into_stmt() {
                 Err(item) => {
@@ -800,6 +849,8 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. })
         | Expr::TsConstAssertion(TsConstAssertion { ref expr, .. }) => can_be_null(expr),
         Expr::TsOptChain(ref e) => can_be_null(&e.expr),
+
+        Expr::Invalid(..) => true,
     }
 }
 
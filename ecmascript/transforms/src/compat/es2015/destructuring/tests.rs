@@ -2,7 +2,11 @@ use super::*;
 use crate::{compat::es2015, resolver};
 
 fn tr() -> impl Fold<Module> {
-    Destructuring
+    destructuring(Default::default())
+}
+
+fn loose_tr() -> impl Fold<Module> {
+    destructuring(Config { loose: true })
 }
 
 test!(
@@ -69,7 +73,7 @@ test!(
 [{ a = 1 }] = foo"#,
     r#"let a;
 var ref, ref1, ref2;
-ref = foo, ref1 = ref[0], ref2 = ref1.a, a = ref2 === void 0 ? 1 : ref2, ref;"#
+ref = _slicedToArray(foo, 1), ref1 = ref[0], ref2 = ref1.a, a = ref2 === void 0 ? 1 : ref2, ref;"#
 );
 
 test!(
@@ -77,8 +81,8 @@ test!(
     |_| tr(),
     array1,
     r#"var [a, [b], [c]] = ["hello", [", ", "junk"], ["world"]];"#,
-    r#"var ref = ['hello', [', ', 'junk'], ['world']], a = ref[0], ref1 = ref[1], 
-    b = ref1[0], ref2 = ref[2], c = ref2[0];"#
+    r#"var ref = ['hello', [', ', 'junk'], ['world']], a = ref[0],
+    ref1 = _slicedToArray(ref[1], 1), b = ref1[0], ref2 = _slicedToArray(ref[2], 1), c = ref2[0];"#
 );
 
 test!(
@@ -87,8 +91,8 @@ test!(
     array2,
     r#"[a, [b], [c]] = ["hello", [", ", "junk"], ["world"]];"#,
     r#"var ref, ref1, ref2;
-ref = ['hello', [', ', 'junk'], ['world']], a = ref[0], ref1 = ref[1],
-     b = ref1[0], ref2 = ref[2], c = ref2[0], ref;
+ref = ['hello', [', ', 'junk'], ['world']], a = ref[0], ref1 = _slicedToArray(ref[1], 1),
+     b = ref1[0], ref2 = _slicedToArray(ref[2], 1), c = ref2[0], ref;
 "#
 );
 
@@ -127,7 +131,7 @@ console.log((ref = [123], x = ref[0], ref));"#
 
 test_exec!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| Destructuring,
+    |_| tr(),
     chained,
     r#"var a, b, c, d;
 ({ a, b } = ({ c, d } = { a: 1, b: 2, c: 3, d: 4}));
@@ -139,7 +143,7 @@ expect(d).toBe(4);"#
 
 test_exec!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| Destructuring,
+    |_| tr(),
     empty_obj_pat_1,
     r#"expect(function () {
   var {} = null;
@@ -159,8 +163,9 @@ test!(
     |_| tr(),
     empty,
     r#"var [, a, [b], [c], d] = ["foo", "hello", [", ", "junk"], ["world"]];"#,
-    r#"var ref = ['foo', 'hello', [', ', 'junk'], ['world']], a = ref[1], ref1 = ref[2],
-     b = ref1[0], ref2 = ref[3], c = ref2[0], d = ref[4];
+    r#"var ref = ['foo', 'hello', [', ', 'junk'], ['world']], a = ref[1],
+     ref1 = _slicedToArray(ref[2], 1), b = ref1[0], ref2 = _slicedToArray(ref[3], 1), c = ref2[0],
+     d = ref[4];
 "#
 );
 
@@ -257,7 +262,7 @@ test!(
   print("Name: " + name + ", Value: " + value);
 }"#,
     r#"for(var ref in obj){
-    let name = ref[0], value = ref[1];
+    let ref1 = _slicedToArray(ref, 2), name = ref1[0], value = ref1[1];
     print('Name: ' + name + ', Value: ' + value);
 }
 "#
@@ -268,7 +273,7 @@ test!(
     |_| tr(),
     for_let,
     r#"for (let [ i, n ] = range; ; ) {}"#,
-    r#"for(let i = range[0], n = range[1];;){}"#
+    r#"for(let ref = _slicedToArray(range, 2), i = ref[0], n = ref[1];;){}"#
 );
 
 test!(
@@ -279,14 +284,14 @@ test!(
 
 }"#,
     r#"for(var ref of test.expectation.registers){
-    let name = ref[0], before = ref[1], after = ref[2];
+    let ref1 = _slicedToArray(ref, 3), name = ref1[0], before = ref1[1], after = ref1[2];
 }"#
 );
 
 test_exec!(
     ignore,
     ::swc_ecma_parser::Syntax::default(),
-    |_| Destructuring,
+    |_| tr(),
     fn_key_with_obj_rest_spread,
     r#"const { [(() => 1)()]: a, ...rest } = { 1: "a" };
 
@@ -312,7 +317,7 @@ for(let i = 0, ref = list ? list : _throw(new TypeError("Cannot destructure 'und
 
 test_exec!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| Destructuring,
+    |_| tr(),
     babel_issue_5090,
     r#"const assign = function([...arr], index, value) {
   arr[index] = value;
@@ -341,7 +346,8 @@ test!(
     let w;
     let e;
     var ref;
-    if (true)  ref = [1, 2, 3].map(()=>123), q = ref[0], w = ref[1], e = ref[2], ref;    
+    if (true) ref = _slicedToArray([1, 2, 3].map(()=>123), 3), q = ref[0], w = ref[1],
+        e = ref[2], ref;
 })();"#
 );
 
@@ -377,8 +383,7 @@ test!(
     r#"var z = [];
 var [x, ...y] = z;"#,
     r#"var z = [];
-var x = z[0],
-    y = z.slice(1);"#
+var ref = _toArray(z), x = ref[0], y = ref.slice(1);"#
 );
 
 test!(
@@ -398,8 +403,8 @@ test!(
 var {topLeft: [x1, y1], bottomRight: [x2, y2] } = rect;"#,
     r#"var rect = {};
 var ref = rect ? rect : _throw(new TypeError("Cannot destructure 'undefined' or 'null'")),
-    _ref$topLeft = ref.topLeft, x1 = _ref$topLeft[0], y1 = _ref$topLeft[1],
-    _ref$bottomRight = ref.bottomRight, x2 = _ref$bottomRight[0], y2 = _ref$bottomRight[1];"#
+    ref1 = _slicedToArray(ref.topLeft, 2), x1 = ref1[0], y1 = ref1[1],
+    ref2 = _slicedToArray(ref.bottomRight, 2), x2 = ref2[0], y2 = ref2[1];"#
 );
 
 test!(
@@ -417,7 +422,7 @@ var ref = coords ? coords : _throw(new TypeError("Cannot destructure 'undefined'
 test_exec!(
     ignore,
     ::swc_ecma_parser::Syntax::default(),
-    |_| Destructuring,
+    |_| tr(),
     number_key_with_object_spread,
     r#"const foo = {
   1: "a",
@@ -459,9 +464,8 @@ var ref = coords ? coords : _throw(new TypeError("Cannot destructure 'undefined'
 );
 
 test_exec!(
-    ignore,
     ::swc_ecma_parser::Syntax::default(),
-    |_| Destructuring,
+    |_| tr(),
     spread_generator,
     r#"function* f() {
   for (var i = 0; i < 3; i++) {
@@ -482,7 +486,7 @@ test!(
   return false
 }"#,
     r#"function isSorted(ref) {
-    let x = ref[0], y = ref[1], wow = ref.slice(2);
+    let ref1 = _toArray(ref), x = ref1[0], y = ref1[1], wow = ref1.slice(2);
     if (!zs.length) return true;
     if (y > x) return isSorted(zs);
     return false;
@@ -567,7 +571,7 @@ function foo(bar) {
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| chain!(resolver(), es2015(),),
+    |_| chain!(resolver(), es2015(Default::default()),),
     issue_404_2,
     "function foo(bar) {
   const { foo } = bar;
@@ -597,3 +601,102 @@ function foo(bar) {
 }
 "
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| tr(),
+    spec_array,
+    r#"var [a, b] = foo;
+var [c, ...d] = bar;
+var [e] = [1];
+[a, b] = baz;"#,
+    r#"var ref = _slicedToArray(foo, 2), a = ref[0], b = ref[1];
+var ref1 = _toArray(bar), c = ref1[0], d = ref1.slice(1);
+var ref2 = [1], e = ref2[0];
+var ref3;
+ref3 = _slicedToArray(baz, 2), a = ref3[0], b = ref3[1], ref3;"#
+);
+
+test_exec!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| tr(),
+    spec_array_iterable,
+    r#"var [a, b] = new Set([1, 2]);
+var [c, ...d] = "xyz";
+expect(a).toBe(1);
+expect(b).toBe(2);
+expect(c).toBe("x");
+expect(d).toEqual(["y", "z"]);"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| loose_tr(),
+    loose_array,
+    r#"var [a, [b], [c]] = ["hello", [", ", "junk"], ["world"]];"#,
+    r#"var ref = ['hello', [', ', 'junk'], ['world']], a = ref[0], ref1 = ref[1],
+    b = ref1[0], ref2 = ref[2], c = ref2[0];"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| loose_tr(),
+    loose_obj_assign_expr,
+    r#"let a;
+[{ a = 1 }] = foo"#,
+    r#"let a;
+var ref, ref1, ref2;
+ref = foo, ref1 = ref[0], ref2 = ref1.a, a = ref2 === void 0 ? 1 : ref2, ref;"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| loose_tr(),
+    loose_for_of,
+    r#"for (var [ name, before, after ] of test.expectation.registers) {
+
+}"#,
+    r#"for(var ref of test.expectation.registers){
+    let name = ref[0], before = ref[1], after = ref[2];
+}"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| loose_tr(),
+    loose_known_array,
+    r#"var z = [];
+var [x, ...y] = z;"#,
+    r#"var z = [];
+var x = z[0],
+    y = z.slice(1);"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| loose_tr(),
+    loose_mixed,
+    r#"var rect = {};
+var {topLeft: [x1, y1], bottomRight: [x2, y2] } = rect;"#,
+    r#"var rect = {};
+var ref = rect ? rect : _throw(new TypeError("Cannot destructure 'undefined' or 'null'")),
+    _ref$topLeft = ref.topLeft, x1 = _ref$topLeft[0], y1 = _ref$topLeft[1],
+    _ref$bottomRight = ref.bottomRight, x2 = _ref$bottomRight[0], y2 = _ref$bottomRight[1];"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| loose_tr(),
+    loose_spread,
+    r#"function isSorted([x, y, ...wow]) {
+  if (!zs.length) return true
+  if (y > x) return isSorted(zs)
+  return false
+}"#,
+    r#"function isSorted(ref) {
+    let x = ref[0], y = ref[1], wow = ref.slice(2);
+    if (!zs.length) return true;
+    if (y > x) return isSorted(zs);
+    return false;
+}"#
+);
@@ -1,9 +1,10 @@
+use super::Config;
 use crate::{
     pass::Pass,
     util::{ExprFactory, StmtLike},
 };
 use ast::*;
-use swc_common::{Fold, FoldWith, Mark, Spanned, Visit, VisitWith, DUMMY_SP};
+use swc_common::{Fold, FoldWith, Mark, Span, Spanned, Visit, VisitWith, DUMMY_SP};
 
 #[cfg(test)]
 mod tests;
@@ -42,15 +43,26 @@ mod tests;
 ///   }
 /// }
 /// ```
-pub fn for_of() -> impl Pass {
-    ForOf
+///
+/// In loose mode, the iterated object is assumed to be an array.
+///
+/// ```js
+/// for (var _i = 0, _arr = foo; _i < _arr.length; _i++) {
+///   var i = _arr[_i];
+/// }
+/// ```
+pub fn for_of(c: Config) -> impl Pass {
+    ForOf { c }
 }
 
-struct ForOf;
+struct ForOf {
+    c: Config,
+}
 
 /// Real folder.
 #[derive(Default)]
 struct Actual {
+    c: Config,
     ///```js
     /// var _iteratorNormalCompletion = true;
     /// var _didIteratorError = false;
@@ -84,31 +96,13 @@ impl Actual {
             },
         };
 
+        if self.c.loose {
+            return self.fold_for_stmt_loose(label, span, var_span, left, right, body);
+        }
+
         let step = quote_ident!(var_span, "_step");
         let step_value = box step.clone().member(quote_ident!("value"));
-        body.stmts.insert(
-            0,
-            match left {
-                VarDeclOrPat::VarDecl(mut var) => {
-                    assert!(var.decls.len() == 1);
-                    Stmt::Decl(Decl::Var(VarDecl {
-                        span: var.span,
-                        kind: var.kind,
-                        decls: vec![VarDeclarator {
-                            init: Some(step_value),
-                            ..var.decls.pop().unwrap()
-                        }],
-                        declare: false,
-                    }))
-                }
-                VarDeclOrPat::Pat(pat) => Stmt::Expr(box Expr::Assign(AssignExpr {
-                    span: DUMMY_SP,
-                    left: PatOrExpr::Pat(box pat),
-                    op: op!("="),
-                    right: step_value,
-                })),
-            },
-        );
+        body.stmts.insert(0, make_left_stmt(left, step_value));
 
         let iterator = quote_ident!(var_span, "_iterator");
         // `_iterator.return`
@@ -214,14 +208,7 @@ impl Actual {
         }
         .into();
 
-        let for_stmt = match label {
-            Some(label) => Stmt::Labeled(LabeledStmt {
-                span,
-                label,
-                body: box for_stmt,
-            }),
-            None => for_stmt,
-        };
+        let for_stmt = with_label(label, span, for_stmt);
 
         Stmt::Try(TryStmt {
             span: DUMMY_SP,
@@ -270,6 +257,105 @@ impl Actual {
     }
 }
 
+impl Actual {
+    fn fold_for_stmt_loose(
+        &mut self,
+        label: Option<Ident>,
+        span: Span,
+        var_span: Span,
+        left: VarDeclOrPat,
+        right: Box<Expr>,
+        mut body: BlockStmt,
+    ) -> Stmt {
+        let i = quote_ident!(var_span, "_i");
+        let arr = quote_ident!(var_span, "_arr");
+
+        body.stmts.insert(
+            0,
+            make_left_stmt(left, box arr.clone().computed_member(i.clone())),
+        );
+
+        let for_stmt = ForStmt {
+            span,
+            init: Some(VarDeclOrExpr::VarDecl(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![
+                    VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(i.clone()),
+                        init: Some(box Expr::Lit(Lit::Num(Number {
+                            span: DUMMY_SP,
+                            value: 0.0,
+                        }))),
+                        definite: false,
+                    },
+                    VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(arr.clone()),
+                        init: Some(right),
+                        definite: false,
+                    },
+                ],
+            })),
+            // _i < _arr.length
+            test: Some(box Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: box Expr::Ident(i.clone()),
+                op: op!("<"),
+                right: box arr.member(quote_ident!("length")),
+            })),
+            // _i++
+            update: Some(box Expr::Update(UpdateExpr {
+                span: DUMMY_SP,
+                prefix: false,
+                op: op!("++"),
+                arg: box Expr::Ident(i),
+            })),
+            body: box Stmt::Block(body),
+        }
+        .into();
+
+        with_label(label, span, for_stmt)
+    }
+}
+
+/// Creates `var i = value` or `i = value`.
+fn make_left_stmt(left: VarDeclOrPat, value: Box<Expr>) -> Stmt {
+    match left {
+        VarDeclOrPat::VarDecl(mut var) => {
+            assert!(var.decls.len() == 1);
+            Stmt::Decl(Decl::Var(VarDecl {
+                span: var.span,
+                kind: var.kind,
+                decls: vec![VarDeclarator {
+                    init: Some(value),
+                    ..var.decls.pop().unwrap()
+                }],
+                declare: false,
+            }))
+        }
+        VarDeclOrPat::Pat(pat) => Stmt::Expr(box Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            left: PatOrExpr::Pat(box pat),
+            op: op!("="),
+            right: value,
+        })),
+    }
+}
+
+fn with_label(label: Option<Ident>, span: Span, stmt: Stmt) -> Stmt {
+    match label {
+        Some(label) => Stmt::Labeled(LabeledStmt {
+            span,
+            label,
+            body: box stmt,
+        }),
+        None => stmt,
+    }
+}
+
 impl Fold<Stmt> for Actual {
     fn fold(&mut self, stmt: Stmt) -> Stmt {
         match stmt {
@@ -399,7 +485,10 @@ where
             match stmt.try_into_stmt() {
                 Err(module_item) => buf.push(module_item),
                 Ok(stmt) => {
-                    let mut folder = Actual::default();
+                    let mut folder = Actual {
+                        c: self.c,
+                        ..Default::default()
+                    };
                     let stmt = stmt.fold_with(&mut folder);
 
                     // Add variable declaration
@@ -275,3 +275,16 @@ try {
 }"#,
     ok_if_code_eq
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| for_of(Config { loose: true }),
+    loose,
+    r#"for (const i of arr) {
+  foo(i);
+}"#,
+    r#"for (var _i = 0, _arr = arr; _i < _arr.length; _i++) {
+  const i = _arr[_i];
+  foo(i);
+}"#
+);
@@ -1,10 +1,22 @@
 pub use self::{
-    arrow::arrow, block_scoped_fn::BlockScopedFns, block_scoping::block_scoping, classes::Classes,
-    computed_props::computed_properties, destructuring::destructuring,
-    duplicate_keys::duplicate_keys, for_of::for_of, function_name::function_name,
-    instanceof::InstanceOf, parameters::parameters, shorthand_property::Shorthand, spread::spread,
-    sticky_regex::StickyRegex, template_literal::TemplateLiteral, typeof_symbol::TypeOfSymbol,
+    arrow::arrow,
+    block_scoped_fn::BlockScopedFns,
+    block_scoping::block_scoping,
+    classes::{classes, Classes},
+    computed_props::computed_properties,
+    destructuring::destructuring,
+    duplicate_keys::duplicate_keys,
+    for_of::for_of,
+    function_name::function_name,
+    instanceof::InstanceOf,
+    parameters::parameters,
+    shorthand_property::Shorthand,
+    spread::spread,
+    sticky_regex::StickyRegex,
+    template_literal::{template_literal, TemplateLiteral},
+    typeof_symbol::TypeOfSymbol,
 };
+pub(crate) use self::classes::SuperFieldAccessFolder;
 use crate::pass::Pass;
 use ast::{Expr, Module};
 
@@ -37,20 +49,30 @@ fn exprs() -> impl Pass {
     )
 }
 
+/// Configuration shared by es2015 passes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Config {
+    /// Generate smaller code which does not follow the spec strictly.
+    ///
+    /// e.g. class methods are assigned to the prototype, and objects used with
+    /// spread, array destructuring and `for..of` are assumed to be arrays.
+    pub loose: bool,
+}
+
 /// Compiles es2015 to es5.
-pub fn es2015() -> impl Pass {
+pub fn es2015(c: Config) -> impl Pass {
     chain_at!(
         Module,
         BlockScopedFns,
-        TemplateLiteral::default(),
-        Classes,
-        spread(),
+        template_literal(c),
+        classes(c),
+        spread(c),
         function_name(),
         exprs(),
         parameters(),
-        for_of(),
+        for_of(c),
         computed_properties(),
-        destructuring(),
+        destructuring(c),
         block_scoping(),
     )
 }
@@ -62,7 +84,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(),
+        |_| es2015(Default::default()),
         issue_169,
         r#"
 export class Foo {
@@ -93,7 +115,7 @@ export var Foo = function() {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(),
+        |_| es2015(Default::default()),
         issue_189,
         r#"
 class HomePage extends React.Component {}
@@ -112,7 +134,7 @@ var HomePage = function(_Component) {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(),
+        |_| es2015(Default::default()),
         issue_227,
         "export default function fn1(...args) {
   fn2(...args);
@@ -179,7 +201,7 @@ function foo(scope) {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(),
+        |_| es2015(Default::default()),
         issue_413,
         r#"
 export const getBadgeBorderRadius = (text, color) => {
@@ -195,7 +217,7 @@ export var getBadgeBorderRadius = function(text, color) {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(),
+        |_| es2015(Default::default()),
         issue_400_1,
         "class A {
     constructor() {
@@ -254,7 +276,7 @@ var B = function(_A) {
 
     test_exec!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(),
+        |_| es2015(Default::default()),
         issue_400_2,
         "class A {
     constructor() {
@@ -5,7 +5,7 @@ fn tr() -> impl Fold<Module> {
     chain!(
         resolver(),
         Params,
-        crate::compat::es2015::destructuring(),
+        crate::compat::es2015::destructuring(Default::default()),
         crate::compat::es2015::block_scoping(),
     )
 }
@@ -165,7 +165,7 @@ foo(1, 2, 3);"#
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| chain!(Classes, tr()),
+    |_| chain!(Classes::default(), tr()),
     default_iife_4253,
     r#"class Ref {
   constructor(id = ++Ref.nextID) {
@@ -200,7 +200,7 @@ expect(new Ref().id).toBe(2);"#
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| chain!(Classes, tr()),
+    |_| chain!(Classes::default(), tr()),
     default_iife_self,
     r#"class Ref {
   constructor(ref = Ref) {
@@ -1195,7 +1195,11 @@ function d(thing) {
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| chain!(Classes, tr(), crate::compat::es2015::spread()),
+    |_| chain!(
+        Classes::default(),
+        tr(),
+        crate::compat::es2015::spread(Default::default())
+    ),
     rest_nested_iife,
     r#"function broken(x, ...foo) {
   if (true) {
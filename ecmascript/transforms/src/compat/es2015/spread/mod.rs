@@ -1,3 +1,4 @@
+use super::Config;
 use crate::{
     pass::Pass,
     util::{alias_ident_for, prepend, undefined, ExprFactory, StmtLike},
@@ -9,16 +10,22 @@ use swc_common::{util::move_map::MoveMap, Fold, FoldWith, Span, Spanned, DUMMY_S
 #[cfg(test)]
 mod tests;
 
-pub fn spread() -> impl Pass {
-    Spread
+/// es2015 - `SpreadElement`
+///
+/// In loose mode, spread arguments are assumed to be arrays and passed to
+/// `concat` without `_toConsumableArray`.
+pub fn spread(c: Config) -> impl Pass {
+    Spread { c }
 }
 
-/// es2015 - `SpreadElement`
 #[derive(Default, Clone)]
-struct Spread;
+struct Spread {
+    c: Config,
+}
 
 #[derive(Default)]
 struct ActualFolder {
+    c: Config,
     vars: Vec<VarDeclarator>,
 }
 
@@ -27,7 +34,10 @@ where
     T: StmtLike + FoldWith<ActualFolder> + FoldWith<Self>,
 {
     fn fold(&mut self, items: Vec<T>) -> Vec<T> {
-        let mut folder = ActualFolder::default();
+        let mut folder = ActualFolder {
+            c: self.c,
+            ..Default::default()
+        };
         let mut items = items.move_map(|item| item.fold_with(&mut folder));
         if !folder.vars.is_empty() {
             prepend(
@@ -60,7 +70,7 @@ impl Fold<Expr> for ActualFolder {
                     return Expr::Array(ArrayLit { span, elems });
                 }
 
-                let args_array = concat_args(span, elems.into_iter(), true);
+                let args_array = concat_args(self.c, span, elems.into_iter(), true);
 
                 return args_array;
             }
@@ -145,7 +155,7 @@ impl Fold<Expr> for ActualFolder {
                     ),
                 };
 
-                let args_array = concat_args(span, args.into_iter().map(Some), false);
+                let args_array = concat_args(self.c, span, args.into_iter().map(Some), false);
                 let apply = MemberExpr {
                     span: DUMMY_SP,
                     obj: callee.as_callee(),
@@ -180,7 +190,7 @@ impl Fold<Expr> for ActualFolder {
                     });
                 }
 
-                let args = concat_args(span, args.into_iter().map(Some), true);
+                let args = concat_args(self.c, span, args.into_iter().map(Some), true);
 
                 Expr::Call(CallExpr {
                     span,
@@ -195,6 +205,7 @@ impl Fold<Expr> for ActualFolder {
 }
 
 fn concat_args(
+    c: Config,
     span: Span,
     args: impl ExactSizeIterator + Iterator<Item = Option<ExprOrSpread>>,
     need_array: bool,
@@ -281,13 +292,18 @@ fn concat_args(
                                 });
                             }
 
-                            Expr::Call(CallExpr {
-                                span,
-                                callee: helper!(to_consumable_array, "toConsumableArray"),
-                                args: vec![expr.as_arg()],
-                                type_args: Default::default(),
-                            })
-                            .as_arg()
+                            if c.loose {
+                                // `concat` flattens arrays.
+                                expr.as_arg()
+                            } else {
+                                Expr::Call(CallExpr {
+                                    span,
+                                    callee: helper!(to_consumable_array, "toConsumableArray"),
+                                    args: vec![expr.as_arg()],
+                                    type_args: Default::default(),
+                                })
+                                .as_arg()
+                            }
                         }
                     });
                 }
@@ -5,7 +5,10 @@ fn syntax() -> ::swc_ecma_parser::Syntax {
 }
 
 fn tr() -> impl Fold<Module> {
-    chain!(crate::compat::es2015::parameters(), spread())
+    chain!(
+        crate::compat::es2015::parameters(),
+        spread(Default::default())
+    )
 }
 
 test!(
@@ -653,3 +656,11 @@ function foo() {
 );
 
 // regression
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| spread(Config { loose: true }),
+    loose,
+    "var foo = [a, ...b, c];",
+    "var foo = [a].concat(b, [c]);"
+);
@@ -1,12 +1,25 @@
-use crate::util::{ExprFactory, StmtLike};
+use super::Config;
+use crate::{
+    pass::Pass,
+    util::{ExprFactory, StmtLike},
+};
 use ast::*;
 use std::iter;
 use swc_common::{Fold, FoldWith, Spanned, DUMMY_SP};
 #[cfg(test)]
 mod tests;
 
+/// In loose mode, `+` is used instead of `String.prototype.concat`.
+pub fn template_literal(c: Config) -> impl Pass {
+    TemplateLiteral {
+        c,
+        ..Default::default()
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct TemplateLiteral {
+    c: Config,
     added: Vec<Stmt>,
 }
 
@@ -72,12 +85,15 @@ impl Fold<Expr> for TemplateLiteral {
                         exprs[idx].clone()
                     };
 
-                    // obj = box Expr::Bin(BinExpr {
-                    //     span: expr.span(),
-                    //     left: obj,
-                    //     op: op!(bin, "+"),
-                    //     right: expr.into(),
-                    // });
+                    if self.c.loose {
+                        obj = box Expr::Bin(BinExpr {
+                            span: expr.span(),
+                            left: obj,
+                            op: op!(bin, "+"),
+                            right: expr,
+                        });
+                        continue;
+                    }
 
                     obj = box Expr::Call(CallExpr {
                         span: expr.span(),
@@ -363,3 +363,11 @@ function a() {
   tag(_templateObject8());
 }"#
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| template_literal(Config { loose: true }),
+    loose,
+    r#"const foo = `${a}b${c}`;"#,
+    r#"const foo = '' + a + 'b' + c;"#
+);
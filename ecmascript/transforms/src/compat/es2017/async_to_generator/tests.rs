@@ -20,7 +20,7 @@ fn tr() -> impl Fold<Module> {
         ParenRemover,
         arrow(),
         parameters(),
-        destructuring(),
+        destructuring(Default::default()),
         function_name(),
         AsyncToGenerator {},
         fixer()
@@ -722,7 +722,7 @@ test!(
 
 test_exec!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| chain_at!(Module, tr(), es2015()),
+    |_| chain_at!(Module, tr(), es2015(Default::default())),
     issue_400_1,
     "class A {
     constructor() {
@@ -781,7 +781,7 @@ return (new B(20)).print().then(() => console.log('Done'));"
 
 test_exec!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| chain_at!(Module, AsyncToGenerator, es2015()),
+    |_| chain_at!(Module, AsyncToGenerator, es2015(Default::default())),
     issue_400_3,
     "class A {
     constructor() {
@@ -236,7 +236,7 @@ export var [dd, ee] = ads;
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| chain!(tr(), spread()),
+    |_| chain!(tr(), spread(Default::default())),
     rest_for_x,
     r#"
 // ForXStatement
@@ -13,6 +13,9 @@ use swc_atoms::JsWord;
 use swc_common::{util::move_map::MoveMap, FileName, Fold, FoldWith};
 use swc_ecma_parser::{Parser, SourceFileInput, Syntax};
 
+/// Inlines values of `import { flag } from 'flags'`.
+///
+/// Scripts are not modified, because they can't import modules.
 pub fn const_modules(globals: HashMap<JsWord, HashMap<JsWord, String>>) -> impl Pass {
     ConstModules {
         globals: globals
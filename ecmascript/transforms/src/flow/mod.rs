@@ -0,0 +1,58 @@
+use crate::{pass::Pass, typescript};
+use ast::*;
+use swc_common::{util::move_map::MoveMap, Fold, FoldWith};
+
+#[cfg(test)]
+mod tests;
+
+/// Strips flow type annotations out.
+///
+/// As flow type annotations are parsed using the typescript type grammar, this
+/// removes flow only syntax and then reuses [typescript::strip].
+pub fn strip() -> impl Pass {
+    chain!(StripFlow, typescript::strip())
+}
+
+struct StripFlow;
+
+impl Fold<Vec<ModuleItem>> for StripFlow {
+    fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        items.move_flat_map(|item| match item {
+            // `import type`, `import typeof`
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                type_only: true, ..
+            })) => None,
+
+            ModuleItem::Stmt(Stmt::Decl(ref decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. }))
+                if is_declare(decl) =>
+            {
+                None
+            }
+
+            _ => Some(item.fold_with(self)),
+        })
+    }
+}
+
+impl Fold<Vec<Stmt>> for StripFlow {
+    fn fold(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        stmts.move_flat_map(|stmt| match stmt {
+            Stmt::Decl(ref decl) if is_declare(decl) => None,
+            _ => Some(stmt.fold_with(self)),
+        })
+    }
+}
+
+/// `declare var`, `declare function`, `declare class`, ...
+fn is_declare(decl: &Decl) -> bool {
+    match *decl {
+        Decl::Class(ClassDecl { declare, .. })
+        | Decl::Fn(FnDecl { declare, .. })
+        | Decl::Var(VarDecl { declare, .. })
+        | Decl::TsInterface(TsInterfaceDecl { declare, .. })
+        | Decl::TsTypeAlias(TsTypeAliasDecl { declare, .. })
+        | Decl::TsEnum(TsEnumDecl { declare, .. })
+        | Decl::TsModule(TsModuleDecl { declare, .. }) => declare,
+    }
+}
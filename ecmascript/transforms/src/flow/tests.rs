@@ -0,0 +1,77 @@
+use super::strip;
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(
+            ::swc_ecma_parser::Syntax::Flow(Default::default()),
+            |_| strip(),
+            $name,
+            $from,
+            $to
+        );
+    };
+}
+
+to!(
+    annotations,
+    "// @flow
+function foo(a: number, b?: ?string, ...rest: Array<mixed>): void {}
+const bar = (x: {| a: number |}): {||} => ({});
+class Baz<T: Object = {}> extends Qux<T> {
+    prop: T;
+}",
+    "// @flow
+function foo(a, b, ...rest) {}
+const bar = (x) => ({});
+class Baz extends Qux {
+    prop;
+}"
+);
+
+to!(
+    type_decls,
+    "type A = { a: number, b: string };
+opaque type B: string = string;
+export opaque type C = number;
+export type D = A | B;
+interface E { e: number }
+declare var f: number;
+declare function g(x: number): string;
+declare class H {}
+declare module 'i' {}
+var j = 1;",
+    "var j = 1;"
+);
+
+to!(
+    import_type,
+    "import type { A } from './a';
+import typeof B from './b';
+import type C, { type D } from './c';
+import { type E, typeof F, g } from './e';
+import type from './type';
+const h: E = g(type);
+const i: F = h;",
+    "import { g } from './e';
+import type from './type';
+const h = g(type);
+const i = h;"
+);
+
+to!(
+    type_cast,
+    "const a = (b: any);
+const c = ((d, e): [number, string]);
+const f = (g: number = 1, h?: string) => g;",
+    "const a = (b);
+const c = ((d, e));
+const f = (g = 1, h) => g;"
+);
+
+to!(
+    generic_call,
+    "const a = foo<string>(1);
+const b = new Bar<number>();",
+    "const a = foo(1);
+const b = new Bar();"
+);
@@ -3,10 +3,15 @@ function _classStaticPrivateFieldSpecSet(receiver, classConstructor, descriptor,
     throw new TypeError("Private static access of wrong provenance");
   }
 
-  if (!descriptor.writable) {
-    throw new TypeError("attempted to set read only private field");
+  if (descriptor.set) {
+    descriptor.set.call(receiver, value);
+  } else {
+    if (!descriptor.writable) {
+      throw new TypeError("attempted to set read only private field");
+    }
+
+    descriptor.value = value;
   }
 
-  descriptor.value = value;
   return value;
 }
@@ -1,6 +1,6 @@
 use crate::util::{
     options::{CM, SESSION},
-    prepend_stmts, DropSpan,
+    prepend_stmts, DropSpan, ExprFactory,
 };
 use ast::*;
 use scoped_tls::scoped_thread_local;
@@ -163,6 +163,7 @@ define_helpers!(Helpers {
     class_private_method_set: (),
     class_static_private_field_spec_get: (),
     class_static_private_field_spec_set: (),
+    class_static_private_method_get: (),
     construct: (set_prototype_of),
     create_class: (),
     decorate: (to_array, to_property_key),
@@ -235,6 +236,7 @@ impl InjectHelpers {
                         local: quote_ident!(DUMMY_SP.apply_mark(mark), "swcHelpers"),
                     })],
                     src: quote_str!("@swc/helpers"),
+                    type_only: false,
                 }))]
             } else {
                 vec![]
@@ -254,6 +256,46 @@ impl Fold<Module> for InjectHelpers {
     }
 }
 
+impl Fold<Script> for InjectHelpers {
+    fn fold(&mut self, mut script: Script) -> Script {
+        let (mark, external) = HELPERS.with(|helper| (helper.mark(), helper.external()));
+        let helpers = if external {
+            if self.is_helper_used() {
+                // Scripts can't import helpers, so `@swc/helpers` is required.
+                vec![Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(quote_ident!(DUMMY_SP.apply_mark(mark), "swcHelpers")),
+                        init: Some(box Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: quote_ident!("require").as_callee(),
+                            args: vec![Lit::Str(quote_str!("@swc/helpers")).as_arg()],
+                            type_args: Default::default(),
+                        })),
+                        definite: false,
+                    }],
+                }))]
+            } else {
+                vec![]
+            }
+        } else {
+            self.build_helpers()
+                .into_iter()
+                .filter_map(|item| match item {
+                    ModuleItem::Stmt(stmt) => Some(stmt),
+                    ModuleItem::ModuleDecl(..) => None,
+                })
+                .collect()
+        };
+
+        prepend_stmts(&mut script.body, helpers.into_iter());
+        script
+    }
+}
+
 struct Marker(Mark);
 impl Fold<Span> for Marker {
     fn fold(&mut self, sp: Span) -> Span {
@@ -162,6 +162,14 @@ impl<'a> Fold<Module> for Hygiene<'a> {
     }
 }
 
+impl<'a> Fold<Script> for Hygiene<'a> {
+    fn fold(&mut self, script: Script) -> Script {
+        let script = script.fold_children(self);
+
+        self.apply_ops(script)
+    }
+}
+
 impl<'a> Fold<TryStmt> for Hygiene<'a> {
     fn fold(&mut self, node: TryStmt) -> TryStmt {
         TryStmt {
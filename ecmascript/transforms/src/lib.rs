@@ -60,9 +60,11 @@ mod hygiene;
 pub mod compat;
 mod const_modules;
 mod fixer;
+pub mod flow;
 mod inline_globals;
 pub mod modules;
 pub mod pass;
+pub mod polyfills;
 pub mod proposals;
 pub mod react;
 mod resolver;
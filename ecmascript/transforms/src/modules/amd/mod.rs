@@ -1,6 +1,9 @@
-use super::util::{
-    self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, use_strict, Exports, ModulePass, Scope,
+use super::{
+    top_level_await::handle_top_level_await,
+    util::{
+        self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
+        local_name_for_src, make_descriptor, use_strict, Exports, ModulePass, Scope,
+    },
 };
 use crate::{
     pass::Pass,
@@ -21,6 +24,7 @@ pub fn amd(config: Config) -> impl Pass {
         in_top_level: Default::default(),
         scope: Default::default(),
         exports: Default::default(),
+        module: None,
     }
 }
 
@@ -29,6 +33,8 @@ struct Amd {
     in_top_level: bool,
     scope: Scope,
     exports: Exports,
+    /// `module` of amd. This is required only if `import.meta` is used.
+    module: Option<Ident>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -43,7 +49,7 @@ pub struct Config {
 
 impl Fold<Module> for Amd {
     fn fold(&mut self, module: Module) -> Module {
-        let items = module.body;
+        let items = handle_top_level_await(&self.config.config, "amd", module.body);
         self.in_top_level = true;
 
         // Inserted after initializing exported names to undefined.
@@ -390,6 +396,12 @@ impl Fold<Module> for Amd {
                 .push(Some(Lit::Str(quote_str!("exports")).as_arg()));
             factory_params.push(Pat::Ident(exports_ident.clone()));
         }
+        if let Some(module) = self.module.take() {
+            define_deps_arg
+                .elems
+                .push(Some(Lit::Str(quote_str!("module")).as_arg()));
+            factory_params.push(Pat::Ident(module));
+        }
 
         // Used only if export * exists
         let exported_names = {
@@ -587,5 +599,15 @@ impl ModulePass for Amd {
     fn scope_mut(&mut self) -> &mut Scope {
         &mut self.scope
     }
+
+    /// Creates `module.uri`
+    fn make_import_meta_url(&mut self) -> Expr {
+        let module = self
+            .module
+            .get_or_insert_with(|| private_ident!("module"))
+            .clone();
+
+        module.member(quote_ident!("uri"))
+    }
 }
 mark_as_nested!(Amd);
@@ -1,8 +1,8 @@
-use super::{super::util, amd, Config};
+use super::{super::util::{self, TopLevelAwait}, amd, Config};
 use crate::resolver;
 use ast::Module;
 use swc_common::Fold;
-use swc_ecma_parser::Syntax;
+use swc_ecma_parser::{EsConfig, Syntax};
 
 fn syntax() -> Syntax {
     Default::default()
@@ -1109,3 +1109,78 @@ define(["exports"], function (_exports) {
 
 "#
 );
+
+test!(
+    Syntax::Es(EsConfig {
+        import_meta: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    import_meta,
+    "
+export const url = import.meta.url;
+",
+    "
+define(['exports', 'module'], function(_exports, module) {
+    'use strict';
+    Object.defineProperty(_exports, '__esModule', {
+        value: true
+    });
+    _exports.url = void 0;
+    const url = module.uri;
+    _exports.url = url;
+});
+"
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |_| tr(Config {
+        config: util::Config {
+            top_level_await: TopLevelAwait::Wrap,
+            ..Default::default()
+        },
+        ..Default::default()
+    }),
+    top_level_await_wrap,
+    "
+const foo = await bar();
+console.log(foo);
+",
+    "
+define(['exports'], function(_exports) {
+    'use strict';
+    Object.defineProperty(_exports, '__esModule', {
+        value: true
+    });
+    _exports.__tla = void 0;
+    var foo;
+    const _tla = (async function() {
+        foo = await bar();
+        console.log(foo);
+    })();
+    _exports.__tla = _tla;
+});
+"
+);
+
+#[test]
+fn top_level_await_error() {
+    let stderr = crate::tests::test_transform_errors(
+        Syntax::Es(EsConfig {
+            top_level_await: true,
+            ..Default::default()
+        }),
+        |_| tr(Default::default()),
+        "const foo = await bar();",
+    );
+
+    assert!(
+        stderr.contains("top-level await cannot be used with amd modules"),
+        "{}",
+        stderr
+    );
+}
@@ -1,7 +1,11 @@
 pub use super::util::Config;
-use super::util::{
-    define_es_module, define_property, has_use_strict, initialize_to_undefined, make_descriptor,
-    make_require_call, use_strict, ModulePass, Scope,
+use super::{
+    import_meta::common_js_url,
+    top_level_await::handle_top_level_await,
+    util::{
+        define_es_module, define_property, has_use_strict, initialize_to_undefined,
+        make_descriptor, make_require_call, use_strict, ModulePass, Scope,
+    },
 };
 use crate::{
     pass::Pass,
@@ -30,6 +34,8 @@ struct CommonJs {
 
 impl Fold<Vec<ModuleItem>> for CommonJs {
     fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        let items = handle_top_level_await(&self.config, "commonjs", items);
+
         let mut emitted_esmodule = false;
         let mut stmts = Vec::with_capacity(items.len() + 4);
         let mut extra_stmts = Vec::with_capacity(items.len());
@@ -639,6 +645,10 @@ impl ModulePass for CommonJs {
     fn scope_mut(&mut self) -> &mut Scope {
         &mut self.scope
     }
+
+    fn make_import_meta_url(&mut self) -> Expr {
+        common_js_url()
+    }
 }
 
 mark_as_nested!(CommonJs);
@@ -1,4 +1,7 @@
-use super::{super::util::Lazy, common_js, Config};
+use super::{
+    super::util::{Lazy, TopLevelAwait},
+    common_js, Config,
+};
 use crate::{
     compat,
     fixer::fixer,
@@ -10,6 +13,7 @@ use crate::{
     typescript,
 };
 use ast::*;
+use hashbrown::HashMap;
 use swc_common::Fold;
 
 fn syntax() -> ::swc_ecma_parser::Syntax {
@@ -101,13 +105,13 @@ test!(
     |_| chain!(
         typescript::strip(),
         decorators(),
-        class_properties(),
+        class_properties(Default::default()),
         export(),
         simplifier(),
         compat::es2018(),
         compat::es2017(),
         compat::es2016(),
-        compat::es2015(),
+        compat::es2015(Default::default()),
         compat::es3(),
         super::super::import_analysis::import_analyzer(),
         InjectHelpers,
@@ -3982,3 +3986,166 @@ var _default = Test;
 exports.default = _default;
 "
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        import_meta: true,
+        ..Default::default()
+    }),
+    |_| tr(Config {
+        import_meta: {
+            let mut m = HashMap::default();
+            m.insert("env".into(), "process.env".into());
+            m
+        },
+        ..Default::default()
+    }),
+    import_meta,
+    "
+console.log(import.meta.url);
+console.log(import.meta.env.NODE_ENV);
+console.log(import.meta.foo);
+",
+    "
+'use strict';
+console.log(require('url').pathToFileURL(__filename).toString());
+console.log(process.env.NODE_ENV);
+console.log(void 0);
+"
+);
+
+#[test]
+fn invalid_import_meta() {
+    let config = Config {
+        import_meta: {
+            let mut m = HashMap::default();
+            m.insert("env".into(), "process.".into());
+            m
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        config.validate(),
+        Err("importMeta.env: `process.` is not an expression".into())
+    );
+}
+
+#[test]
+fn import_meta_trailing_input() {
+    for src in &["process.env foo", "process.env; foo", "process.env)"] {
+        let config = Config {
+            import_meta: {
+                let mut m = HashMap::default();
+                m.insert("env".into(), src.to_string());
+                m
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(format!("importMeta.env: `{}` is not an expression", src))
+        );
+    }
+
+    let config = Config {
+        import_meta: {
+            let mut m = HashMap::default();
+            m.insert("env".into(), " (process.env) ".into());
+            m
+        },
+        ..Default::default()
+    };
+    assert_eq!(config.validate(), Ok(()));
+}
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |_| tr(Config {
+        top_level_await: TopLevelAwait::Wrap,
+        ..Default::default()
+    }),
+    top_level_await_wrap,
+    "
+const foo = await bar();
+console.log(foo);
+",
+    "
+'use strict';
+Object.defineProperty(exports, '__esModule', {
+    value: true
+});
+exports.__tla = void 0;
+var foo;
+const _tla = (async function() {
+    foo = await bar();
+    console.log(foo);
+})();
+exports.__tla = _tla;
+"
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |_| tr(Config {
+        top_level_await: TopLevelAwait::Wrap,
+        ..Default::default()
+    }),
+    top_level_await_wrap_nested_var,
+    "
+if (cond) {
+    var foo = await bar();
+}
+for (var i = 0; i < 1; i++) {}
+for (var key in obj) {}
+function baz() {
+    var local = 1;
+}
+console.log(foo, i, key);
+",
+    "
+'use strict';
+Object.defineProperty(exports, '__esModule', {
+    value: true
+});
+exports.__tla = void 0;
+var foo, i, key;
+function baz() {
+    var local = 1;
+}
+const _tla = (async function() {
+    if (cond) {
+        foo = await bar();
+    }
+    for(i = 0; i < 1; i++){}
+    for(key in obj){}
+    console.log(foo, i, key);
+})();
+exports.__tla = _tla;
+"
+);
+
+#[test]
+fn top_level_await_error() {
+    let stderr = crate::tests::test_transform_errors(
+        ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+            top_level_await: true,
+            ..Default::default()
+        }),
+        |_| tr(Default::default()),
+        "const foo = await bar();",
+    );
+
+    assert!(
+        stderr.contains("top-level await cannot be used with commonjs modules"),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,179 @@
+use crate::pass::Pass;
+use ast::*;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use swc_common::{FileName, Fold, FoldWith, SourceMap};
+
+#[cfg(test)]
+mod tests;
+
+/// Keeps `import` / `export` statements, but rewrites import specifiers so
+/// that the output can be loaded by the esm loader of node.js.
+pub fn es6(cm: Arc<SourceMap>, config: Config) -> impl Pass {
+    Es6 {
+        cm,
+        config,
+        base: None,
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Extension appended to relative import specifiers without an
+    /// extension.
+    ///
+    /// e.g. `".js"` rewrites `./foo` to `./foo.js`
+    #[serde(default)]
+    pub extension: Option<String>,
+
+    /// Map from source extension to output extension.
+    ///
+    /// e.g. `{ ".ts": ".js" }` rewrites `./foo.ts` to `./foo.js`
+    #[serde(default)]
+    pub replace_extensions: HashMap<String, String>,
+
+    /// If true, imports of a directory is resolved to `index` file of the
+    /// directory.
+    ///
+    /// e.g. `./dir` is rewritten to `./dir/index.js`
+    #[serde(default)]
+    pub resolve_directories: bool,
+}
+
+/// Extensions which are kept as-is if `replace_extensions` does not contain
+/// it.
+const KNOWN_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "json", "node", "wasm"];
+
+struct Es6 {
+    cm: Arc<SourceMap>,
+    config: Config,
+    /// Directory of the file being compiled.
+    base: Option<PathBuf>,
+}
+
+impl Es6 {
+    fn rewrite_src(&self, src: Str) -> Str {
+        if !src.value.starts_with("./") && !src.value.starts_with("../") {
+            return src;
+        }
+
+        let mut value = src.value.to_string();
+
+        // `./`, `../` and `./dir/..` always refer to a directory.
+        let is_dir = value.ends_with('/') || value.ends_with("/.") || value.ends_with("/..");
+        if is_dir && self.config.extension.is_none() {
+            return src;
+        }
+
+        let is_dir = is_dir
+            || (self.config.resolve_directories
+                && match self.base {
+                    Some(ref base) => base.join(&value).is_dir(),
+                    None => false,
+                });
+        if is_dir {
+            if !value.ends_with('/') {
+                value.push('/');
+            }
+            value.push_str("index");
+        }
+
+        let ext = Path::new(&value)
+            .extension()
+            .map(|ext| format!(".{}", ext.to_string_lossy()));
+
+        match ext {
+            Some(ref ext) if self.config.replace_extensions.contains_key(ext) => {
+                value.truncate(value.len() - ext.len());
+                value.push_str(&self.config.replace_extensions[ext]);
+            }
+            Some(ref ext) if KNOWN_EXTENSIONS.contains(&&ext[1..]) => {}
+            _ => {
+                if let Some(ref new_ext) = self.config.extension {
+                    value.push_str(new_ext);
+                }
+            }
+        }
+
+        if *src.value == *value {
+            return src;
+        }
+
+        Str {
+            value: value.into(),
+            has_escape: false,
+            ..src
+        }
+    }
+}
+
+impl Fold<Module> for Es6 {
+    fn fold(&mut self, module: Module) -> Module {
+        self.base = match self.cm.span_to_filename(module.span) {
+            FileName::Real(ref path) => path.parent().map(|p| p.to_path_buf()),
+            _ => None,
+        };
+
+        module.fold_children(self)
+    }
+}
+
+impl Fold<ImportDecl> for Es6 {
+    fn fold(&mut self, import: ImportDecl) -> ImportDecl {
+        ImportDecl {
+            src: self.rewrite_src(import.src),
+            ..import
+        }
+    }
+}
+
+impl Fold<ExportAll> for Es6 {
+    fn fold(&mut self, export: ExportAll) -> ExportAll {
+        ExportAll {
+            src: self.rewrite_src(export.src),
+            ..export
+        }
+    }
+}
+
+impl Fold<NamedExport> for Es6 {
+    fn fold(&mut self, export: NamedExport) -> NamedExport {
+        NamedExport {
+            src: export.src.map(|src| self.rewrite_src(src)),
+            ..export
+        }
+    }
+}
+
+impl Fold<CallExpr> for Es6 {
+    /// Handles `import('./foo')`
+    fn fold(&mut self, e: CallExpr) -> CallExpr {
+        let mut e = e.fold_children(self);
+
+        match e.callee {
+            ExprOrSuper::Expr(box Expr::Ident(Ident {
+                sym: js_word!("import"),
+                ..
+            })) if e.args.len() == 1 => {}
+            _ => return e,
+        }
+
+        match e.args[0] {
+            ExprOrSpread {
+                spread: None,
+                expr: box Expr::Lit(Lit::Str(ref mut src)),
+            } => {
+                let s = src.clone();
+                *src = self.rewrite_src(s);
+            }
+            _ => {}
+        }
+
+        e
+    }
+}
@@ -0,0 +1,256 @@
+use super::{es6, Config};
+use crate::{resolver, typescript::strip};
+use ast::*;
+use hashbrown::HashMap;
+use std::fs::create_dir;
+use swc_common::Fold;
+use swc_ecma_parser::{EsConfig, Syntax};
+use tempfile::tempdir;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        dynamic_import: true,
+        ..Default::default()
+    })
+}
+
+fn tr(tester: &mut crate::tests::Tester, config: Config) -> impl Fold<Module> {
+    chain!(resolver(), es6(tester.cm.clone(), config))
+}
+
+/// Same order as `Options::build`.
+fn ts_tr(tester: &mut crate::tests::Tester, config: Config) -> impl Fold<Module> {
+    chain!(strip(), resolver(), es6(tester.cm.clone(), config))
+}
+
+fn config() -> Config {
+    Config {
+        extension: Some(".js".into()),
+        replace_extensions: {
+            let mut m = HashMap::default();
+            m.insert(".ts".into(), ".js".into());
+            m
+        },
+        ..Default::default()
+    }
+}
+
+test!(
+    syntax(),
+    |tester| tr(tester, config()),
+    append_extension,
+    "
+import foo from './foo';
+import bar from '../bar.ts';
+import baz from './baz.mjs';
+import React from 'react';
+export * from './reexport';
+export { a } from './a.json';
+import('./lazy').then(foo);
+",
+    "
+import foo from './foo.js';
+import bar from '../bar.js';
+import baz from './baz.mjs';
+import React from 'react';
+export * from './reexport.js';
+export { a } from './a.json';
+import('./lazy.js').then(foo);
+"
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester, Default::default()),
+    no_extension,
+    "
+import foo from './foo';
+export * from './bar.ts';
+",
+    "
+import foo from './foo';
+export * from './bar.ts';
+"
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester, config()),
+    bare_directory,
+    "
+import foo from './';
+import bar from '../';
+import baz from '../..';
+",
+    "
+import foo from './index.js';
+import bar from '../index.js';
+import baz from '../../index.js';
+"
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester, Default::default()),
+    bare_directory_no_extension,
+    "
+import foo from './';
+import bar from '../';
+",
+    "
+import foo from './';
+import bar from '../';
+"
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |tester| ts_tr(tester, config()),
+    strip_type_only_export,
+    "
+import { Props } from './props';
+import { Foo } from './foo';
+interface State {}
+type Alias = string;
+const Qux = 1;
+let p: Props;
+export { Foo, Qux, Props, State, Alias };
+export { Bar } from './bar';
+",
+    "
+import './props.js';
+import { Foo } from './foo.js';
+const Qux = 1;
+let p;
+export { Foo, Qux };
+export { Bar } from './bar.js';
+"
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |tester| ts_tr(tester, config()),
+    keep_import_used_as_value,
+    "
+import { Foo } from './foo';
+let foo: Foo = new Foo();
+export { Foo };
+",
+    "
+import { Foo } from './foo.js';
+let foo = new Foo();
+export { Foo };
+"
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |tester| ts_tr(tester, config()),
+    keep_interface_merged_with_class,
+    "
+interface Foo {}
+class Foo {}
+export { Foo };
+",
+    "
+class Foo {}
+export { Foo };
+"
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester, config()),
+    keep_default_fn_binding,
+    "
+export default function foo() {}
+export { foo as bar };
+",
+    "
+export default function foo() {}
+export { foo as bar };
+"
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester, config()),
+    keep_default_class_binding,
+    "
+export default class Foo {}
+export { Foo as Bar };
+",
+    "
+export default class Foo {}
+export { Foo as Bar };
+"
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester, config()),
+    keep_var_bindings,
+    "
+const { a, b: [c] } = obj;
+let d;
+function e() {}
+class F {}
+export { a, c, d, e, F };
+",
+    "
+const { a, b: [c] } = obj;
+let d;
+function e() {}
+class F {}
+export { a, c, d, e, F };
+"
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester, config()),
+    keep_import_binding,
+    "
+import foo, { bar } from './foo';
+import * as baz from './baz';
+export { foo, bar, baz };
+",
+    "
+import foo, { bar } from './foo.js';
+import * as baz from './baz.js';
+export { foo, bar, baz };
+"
+);
+
+#[test]
+fn resolve_directories() {
+    let dir = tempdir().unwrap();
+    create_dir(dir.path().join("dir")).unwrap();
+    let input = dir.path().join("input.js");
+
+    crate::tests::Tester::run(|tester| {
+        let pass = tr(
+            tester,
+            Config {
+                resolve_directories: true,
+                ..config()
+            },
+        );
+        let actual = tester.apply_transform(
+            pass,
+            input.to_str().unwrap(),
+            syntax(),
+            "import foo from './dir';\nimport bar from './bar';",
+        )?;
+        let expected = tester.apply_transform(
+            ::testing::DropSpan,
+            "output.js",
+            syntax(),
+            "import foo from './dir/index.js';\nimport bar from './bar.js';",
+        )?;
+
+        assert_eq!(tester.print(&actual), tester.print(&expected));
+
+        Ok(())
+    });
+}
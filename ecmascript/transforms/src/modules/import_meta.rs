@@ -0,0 +1,153 @@
+use super::util::ModulePass;
+use crate::util::{
+    drop_span,
+    options::{CM, SESSION},
+    undefined, ExprFactory,
+};
+use ast::*;
+use chashmap::CHashMap;
+use swc_atoms::JsWord;
+use swc_common::{BytePos, FileName, Spanned, DUMMY_SP};
+use swc_ecma_parser::{Parser, SourceFileInput, Syntax};
+
+/// Returns true if `e` is `import.meta`.
+pub(super) fn is_import_meta(e: &MetaPropExpr) -> bool {
+    e.meta.sym == js_word!("import") && e.prop.sym == js_word!("meta")
+}
+
+/// Lowers `import.meta.foo`.
+///
+/// Properties configured with `importMeta` take precedence over the builtin
+/// lowering of `import.meta.url`.
+pub(super) fn lower_import_meta_prop(folder: &mut impl ModulePass, prop: &JsWord) -> Expr {
+    if let Some(src) = folder.config().import_meta.get(prop) {
+        // Invalid options are rejected by `Config::validate`.
+        return parse_override(prop, src).unwrap_or_else(|| *undefined(DUMMY_SP));
+    }
+
+    if &**prop == "url" {
+        return folder.make_import_meta_url();
+    }
+
+    *undefined(DUMMY_SP)
+}
+
+/// Lowers bare `import.meta` to an object literal.
+pub(super) fn lower_import_meta(folder: &mut impl ModulePass) -> Expr {
+    let mut keys = folder
+        .config()
+        .import_meta
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    if !keys.iter().any(|k| &**k == "url") {
+        keys.push("url".into());
+    }
+    // Sort keys to make output stable.
+    keys.sort();
+
+    Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props: keys
+            .into_iter()
+            .map(|key| {
+                let value = lower_import_meta_prop(folder, &key);
+                PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                    key: PropName::Str(quote_str!(key)),
+                    value: box value,
+                }))
+            })
+            .collect(),
+    })
+}
+
+/// Creates
+///
+/// ```js
+/// require('url').pathToFileURL(__filename).toString()
+/// ```
+pub(super) fn common_js_url() -> Expr {
+    let file_url = CallExpr {
+        span: DUMMY_SP,
+        callee: super::util::make_require_call("url".into())
+            .member(quote_ident!("pathToFileURL"))
+            .as_callee(),
+        args: vec![quote_ident!("__filename").as_arg()],
+        type_args: Default::default(),
+    };
+
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: file_url.member(quote_ident!("toString")).as_callee(),
+        args: vec![],
+        type_args: Default::default(),
+    })
+}
+
+/// Creates
+///
+/// ```js
+/// typeof document === 'undefined'
+///     ? require('url').pathToFileURL(__filename).toString()
+///     : document.currentScript && document.currentScript.src || document.baseURI
+/// ```
+pub(super) fn umd_url() -> Expr {
+    let no_document = UnaryExpr {
+        span: DUMMY_SP,
+        op: op!("typeof"),
+        arg: box Expr::Ident(quote_ident!("document")),
+    }
+    .make_eq(Lit::Str(quote_str!("undefined")));
+
+    let script_src = (*member_expr!(DUMMY_SP, document.currentScript))
+        .make_bin(op!("&&"), *member_expr!(DUMMY_SP, document.currentScript.src))
+        .make_bin(op!("||"), *member_expr!(DUMMY_SP, document.baseURI));
+
+    Expr::Cond(CondExpr {
+        span: DUMMY_SP,
+        test: box no_document,
+        cons: box common_js_url(),
+        alt: box script_src,
+    })
+}
+
+/// Parses an `importMeta` option.
+///
+/// Returns `None` if `src` is not a single expression.
+pub(super) fn parse_override(name: &JsWord, src: &str) -> Option<Expr> {
+    lazy_static! {
+        static ref CACHE: CHashMap<String, Expr> = CHashMap::default();
+    }
+
+    if let Some(expr) = CACHE.get(src) {
+        return Some(expr.clone());
+    }
+
+    let fm = CM.new_source_file(
+        FileName::Custom(format!("<import-meta-{}.js>", name)),
+        src.into(),
+    );
+
+    let expr = match Parser::new(
+        *SESSION,
+        Syntax::default(),
+        SourceFileInput::from(&*fm),
+        None,
+    )
+    .parse_expr()
+    {
+        // Input after the expression, like `process.env foo`, is not allowed.
+        Ok(expr) if expr.span().hi() == fm.start_pos + BytePos(src.trim_end().len() as _) => {
+            drop_span(expr)
+        }
+        Ok(..) => return None,
+        Err(mut e) => {
+            e.cancel();
+            return None;
+        }
+    };
+
+    CACHE.insert(src.into(), *expr.clone());
+
+    Some(*expr)
+}
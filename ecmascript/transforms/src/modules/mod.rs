@@ -2,5 +2,8 @@
 mod util;
 pub mod amd;
 pub mod common_js;
+pub mod es6;
 pub mod import_analysis;
+mod import_meta;
+pub mod top_level_await;
 pub mod umd;
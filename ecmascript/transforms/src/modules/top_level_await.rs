@@ -0,0 +1,426 @@
+pub use super::util::TopLevelAwait;
+use super::util::Config;
+use crate::{
+    pass::Pass,
+    util::{var::VarCollector, ExprFactory, HANDLER},
+};
+use ast::*;
+use std::mem::replace;
+use swc_atoms::JsWord;
+use swc_common::{Fold, FoldWith, Span, SyntaxContext, Visit, VisitWith, DUMMY_SP};
+
+/// Name of the export which holds the promise of the wrapped module body.
+const PROMISE_EXPORT: &str = "__tla";
+
+/// Wraps the module body with an async function if it contains top-level
+/// await.
+///
+/// This should run before compat passes, so that the async function is
+/// lowered by them.
+pub fn wrap_top_level_await() -> impl Pass {
+    WrapTopLevelAwait
+}
+
+#[derive(Clone, Copy)]
+struct WrapTopLevelAwait;
+
+impl Fold<Module> for WrapTopLevelAwait {
+    fn fold(&mut self, module: Module) -> Module {
+        if find_top_level_await(&module.body).is_none() {
+            return module;
+        }
+
+        Module {
+            body: wrap(module.body),
+            ..module
+        }
+    }
+}
+
+/// Handles top-level await according to `config.top_level_await`.
+///
+/// This is a no-op if the module is already wrapped by
+/// [wrap_top_level_await].
+///
+/// `format` is used for the error message.
+pub(super) fn handle_top_level_await(
+    config: &Config,
+    format: &str,
+    items: Vec<ModuleItem>,
+) -> Vec<ModuleItem> {
+    let span = match find_top_level_await(&items) {
+        Some(span) => span,
+        None => return items,
+    };
+
+    match config.top_level_await {
+        TopLevelAwait::Error => {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(
+                        span,
+                        &format!(
+                            "top-level await cannot be used with {} modules. You can set \
+                             `module.topLevelAwait` to \"wrap\" to wrap the module body with an \
+                             async function",
+                            format
+                        ),
+                    )
+                    .emit()
+            });
+
+            items
+        }
+        TopLevelAwait::Wrap => wrap(items),
+    }
+}
+
+fn find_top_level_await(items: &[ModuleItem]) -> Option<Span> {
+    let mut v = TopLevelAwaitFinder { found: None };
+    items.visit_with(&mut v);
+    v.found
+}
+
+struct TopLevelAwaitFinder {
+    found: Option<Span>,
+}
+
+impl Visit<AwaitExpr> for TopLevelAwaitFinder {
+    fn visit(&mut self, e: &AwaitExpr) {
+        if self.found.is_none() {
+            self.found = Some(e.span);
+        }
+    }
+}
+
+impl Visit<ForOfStmt> for TopLevelAwaitFinder {
+    fn visit(&mut self, s: &ForOfStmt) {
+        if self.found.is_none() {
+            self.found = s.await_token;
+        }
+        s.visit_children(self);
+    }
+}
+
+macro_rules! noop {
+    ($T:path) => {
+        impl Visit<$T> for TopLevelAwaitFinder {
+            fn visit(&mut self, _: &$T) {}
+        }
+    };
+
+    ($T:path, $($rest:tt)*) => {
+        noop!($T);
+        noop!($($rest)*);
+    };
+}
+
+// `await` in these nodes are not top-level.
+noop!(Function, ArrowExpr, Constructor, GetterProp, SetterProp, ClassProp);
+
+/// Converts
+///
+/// ```js
+/// import foo from 'foo';
+/// export const bar = await foo();
+/// ```
+///
+/// to
+///
+/// ```js
+/// import foo from 'foo';
+/// export var bar;
+/// const _tla = (async function () {
+///     bar = await foo();
+/// })();
+/// export { _tla as __tla };
+/// ```
+///
+/// `var` declarations in nested blocks are hoisted out of the function, too.
+fn wrap(items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+    let mut vars = vec![];
+    let mut exported_vars = vec![];
+    let mut hoisted = vec![];
+    let mut body = vec![];
+
+    for item in items {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var),
+                ..
+            })) => {
+                var.decls.visit_with(&mut VarCollector {
+                    to: &mut exported_vars,
+                });
+                body.extend(var_to_assignments(var));
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Class(ClassDecl { ident, class, .. }),
+                ..
+            })) => {
+                exported_vars.push((ident.sym.clone(), ident.span.ctxt()));
+                body.push(assign_class(ident, class));
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Class(ClassExpr { ident, class }),
+                ..
+            })) => {
+                let ident = ident.unwrap_or_else(|| private_ident!("_default"));
+                vars.push((ident.sym.clone(), ident.span.ctxt()));
+                hoisted.push(export_as_default(ident.clone()));
+                body.push(assign_class(ident, class));
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            })) => {
+                let ident = private_ident!("_default");
+                vars.push((ident.sym.clone(), ident.span.ctxt()));
+                hoisted.push(export_as_default(ident.clone()));
+                body.push(Stmt::Expr(box Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    left: PatOrExpr::Pat(box Pat::Ident(ident)),
+                    op: op!("="),
+                    right: expr,
+                })));
+            }
+
+            ModuleItem::ModuleDecl(..) | ModuleItem::Stmt(Stmt::Decl(Decl::Fn(..))) => {
+                hoisted.push(item)
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+                var.decls.visit_with(&mut VarCollector { to: &mut vars });
+                body.extend(var_to_assignments(var));
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl { ident, class, .. }))) => {
+                vars.push((ident.sym.clone(), ident.span.ctxt()));
+                body.push(assign_class(ident, class));
+            }
+
+            ModuleItem::Stmt(stmt) => {
+                body.push(stmt.fold_with(&mut VarHoister { vars: &mut vars }))
+            }
+        }
+    }
+
+    let mut items = Vec::with_capacity(hoisted.len() + 4);
+    if !vars.is_empty() {
+        items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(declare_vars(vars)))));
+    }
+    if !exported_vars.is_empty() {
+        items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            span: DUMMY_SP,
+            decl: Decl::Var(declare_vars(exported_vars)),
+        })));
+    }
+    items.extend(hoisted);
+
+    // const _tla = (async function () { ... })();
+    // export { _tla as __tla };
+    let promise_ident = private_ident!("_tla");
+    let promise = CallExpr {
+        span: DUMMY_SP,
+        callee: FnExpr {
+            ident: None,
+            function: Function {
+                span: DUMMY_SP,
+                is_async: true,
+                is_generator: false,
+                decorators: Default::default(),
+                params: vec![],
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: body,
+                }),
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        }
+        .wrap_with_paren()
+        .as_callee(),
+        args: vec![],
+        type_args: Default::default(),
+    };
+    items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(promise_ident.clone()),
+            init: Some(box Expr::Call(promise)),
+            definite: false,
+        }],
+        declare: false,
+    }))));
+    items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        span: DUMMY_SP,
+        specifiers: vec![ExportSpecifier::Named(NamedExportSpecifier {
+            span: DUMMY_SP,
+            orig: promise_ident,
+            exported: Some(quote_ident!(PROMISE_EXPORT)),
+        })],
+        src: None,
+    })));
+
+    items
+}
+
+/// `var a = 1, { b } = c;` => `a = 1; ({ b } = c);`
+fn var_to_assignments(var: VarDecl) -> Vec<Stmt> {
+    var_to_exprs(var).into_iter().map(Stmt::Expr).collect()
+}
+
+/// `var a = 1, { b } = c` => `a = 1`, `{ b } = c`
+fn var_to_exprs(var: VarDecl) -> Vec<Box<Expr>> {
+    var.decls
+        .into_iter()
+        .filter_map(|mut decl| {
+            let init = replace(&mut decl.init, None)?;
+
+            Some(box Expr::Assign(AssignExpr {
+                span: decl.span,
+                left: PatOrExpr::Pat(box decl.name),
+                op: op!("="),
+                right: init,
+            }))
+        })
+        .collect()
+}
+
+/// Converts `var` declarations in nested blocks to assignments and collects
+/// the declared names, so that they can be declared outside of the async
+/// function.
+struct VarHoister<'a> {
+    vars: &'a mut Vec<(JsWord, SyntaxContext)>,
+}
+
+impl VarHoister<'_> {
+    fn hoist(&mut self, var: VarDecl) -> Vec<Box<Expr>> {
+        var.decls.visit_with(&mut VarCollector { to: &mut *self.vars });
+        var_to_exprs(var)
+    }
+}
+
+impl Fold<Stmt> for VarHoister<'_> {
+    fn fold(&mut self, stmt: Stmt) -> Stmt {
+        let stmt = stmt.fold_children(self);
+
+        match stmt {
+            Stmt::Decl(Decl::Var(var)) if var.kind == VarDeclKind::Var => {
+                let span = var.span;
+                let mut stmts: Vec<_> = self.hoist(var).into_iter().map(Stmt::Expr).collect();
+                match stmts.len() {
+                    0 => Stmt::Empty(EmptyStmt { span }),
+                    1 => stmts.pop().unwrap(),
+                    _ => Stmt::Block(BlockStmt { span, stmts }),
+                }
+            }
+            _ => stmt,
+        }
+    }
+}
+
+impl Fold<ForStmt> for VarHoister<'_> {
+    fn fold(&mut self, s: ForStmt) -> ForStmt {
+        let s = s.fold_children(self);
+
+        let init = match s.init {
+            Some(VarDeclOrExpr::VarDecl(var)) if var.kind == VarDeclKind::Var => {
+                let mut exprs = self.hoist(var);
+                match exprs.len() {
+                    0 => None,
+                    1 => exprs.pop().map(VarDeclOrExpr::Expr),
+                    _ => Some(VarDeclOrExpr::Expr(box Expr::Seq(SeqExpr {
+                        span: DUMMY_SP,
+                        exprs,
+                    }))),
+                }
+            }
+            init => init,
+        };
+
+        ForStmt { init, ..s }
+    }
+}
+
+impl Fold<VarDeclOrPat> for VarHoister<'_> {
+    fn fold(&mut self, n: VarDeclOrPat) -> VarDeclOrPat {
+        let n = n.fold_children(self);
+
+        match n {
+            // `for (var a of b)` => `for (a of b)`
+            VarDeclOrPat::VarDecl(var) if var.kind == VarDeclKind::Var => {
+                var.decls.visit_with(&mut VarCollector { to: &mut *self.vars });
+                let decl = var.decls.into_iter().next().unwrap();
+                VarDeclOrPat::Pat(decl.name)
+            }
+            _ => n,
+        }
+    }
+}
+
+macro_rules! noop_fold {
+    ($T:path) => {
+        impl Fold<$T> for VarHoister<'_> {
+            fn fold(&mut self, n: $T) -> $T {
+                n
+            }
+        }
+    };
+
+    ($T:path, $($rest:tt)*) => {
+        noop_fold!($T);
+        noop_fold!($($rest)*);
+    };
+}
+
+// `var` in these nodes are not hoisted to the module scope.
+noop_fold!(Function, ArrowExpr, Constructor, GetterProp, SetterProp, ClassProp);
+
+/// `Foo = class Foo {}`
+fn assign_class(ident: Ident, class: Class) -> Stmt {
+    Stmt::Expr(box Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        left: PatOrExpr::Pat(box Pat::Ident(ident.clone())),
+        op: op!("="),
+        right: box Expr::Class(ClassExpr {
+            ident: Some(ident),
+            class,
+        }),
+    }))
+}
+
+/// `export { ident as default }`
+fn export_as_default(ident: Ident) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        span: DUMMY_SP,
+        specifiers: vec![ExportSpecifier::Named(NamedExportSpecifier {
+            span: DUMMY_SP,
+            orig: ident,
+            exported: Some(quote_ident!("default")),
+        })],
+        src: None,
+    }))
+}
+
+fn declare_vars(names: Vec<(JsWord, SyntaxContext)>) -> VarDecl {
+    VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        decls: names
+            .into_iter()
+            .map(|(sym, ctxt)| VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(Ident::new(sym, DUMMY_SP.with_ctxt(ctxt))),
+                init: None,
+                definite: false,
+            })
+            .collect(),
+        declare: false,
+    }
+}
@@ -1,8 +1,13 @@
 use self::config::BuiltConfig;
 pub use self::config::Config;
-use super::util::{
-    self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, make_require_call, use_strict, Exports, ModulePass, Scope,
+use super::{
+    import_meta::umd_url,
+    top_level_await::handle_top_level_await,
+    util::{
+        self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
+        local_name_for_src, make_descriptor, make_require_call, use_strict, Exports, ModulePass,
+        Scope,
+    },
 };
 use crate::{
     pass::Pass,
@@ -42,7 +47,7 @@ impl Fold<Module> for Umd {
 
         let filename = self.cm.span_to_filename(module.span);
 
-        let items = module.body;
+        let items = handle_top_level_await(&self.config.config, "umd", module.body);
 
         // Inserted after initializing exported names to undefined.
         let mut extra_stmts = vec![];
@@ -704,5 +709,9 @@ impl ModulePass for Umd {
     fn scope_mut(&mut self) -> &mut Scope {
         &mut self.scope
     }
+
+    fn make_import_meta_url(&mut self) -> Expr {
+        umd_url()
+    }
 }
 mark_as_nested!(Umd);
@@ -1772,3 +1772,102 @@ export * from "foo";
 );
 
 // umd
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        import_meta: true,
+        ..Default::default()
+    }),
+    |tester| tr(tester, Default::default()),
+    import_meta,
+    "
+console.log(import.meta.url);
+",
+    "
+(function(global, factory) {
+    if (typeof define === 'function' && define.amd) {
+        define([], factory);
+    } else if (typeof exports !== 'undefined') {
+        factory();
+    } else {
+        var mod = {
+            exports: {
+            }
+        };
+        factory();
+        global.input = mod.exports;
+    }
+})(this, function() {
+    'use strict';
+    console.log(typeof document === 'undefined' ? require('url').pathToFileURL(__filename).toString() : document.currentScript && document.currentScript.src || document.baseURI);
+});
+"
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |tester| tr(
+        tester,
+        Config {
+            config: util::Config {
+                top_level_await: util::TopLevelAwait::Wrap,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    ),
+    top_level_await_wrap,
+    "
+const foo = await bar();
+console.log(foo);
+",
+    "
+(function(global, factory) {
+    if (typeof define === 'function' && define.amd) {
+        define(['exports'], factory);
+    } else if (typeof exports !== 'undefined') {
+        factory(exports);
+    } else {
+        var mod = {
+            exports: {
+            }
+        };
+        factory(mod.exports);
+        global.input = mod.exports;
+    }
+})(this, function(_exports) {
+    'use strict';
+    Object.defineProperty(_exports, '__esModule', {
+        value: true
+    });
+    _exports.__tla = void 0;
+    var foo;
+    const _tla = (async function() {
+        foo = await bar();
+        console.log(foo);
+    })();
+    _exports.__tla = _tla;
+});
+"
+);
+
+#[test]
+fn top_level_await_error() {
+    let stderr = crate::tests::test_transform_errors(
+        ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+            top_level_await: true,
+            ..Default::default()
+        }),
+        |tester| tr(tester, Default::default()),
+        "const foo = await bar();",
+    );
+
+    assert!(
+        stderr.contains("top-level await cannot be used with umd modules"),
+        "{}",
+        stderr
+    );
+}
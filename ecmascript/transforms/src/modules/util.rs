@@ -1,3 +1,4 @@
+use super::import_meta::{is_import_meta, lower_import_meta, lower_import_meta_prop, parse_override};
 use crate::util::{undefined, DestructuringFinder, ExprFactory};
 use ast::*;
 use hashbrown::{hash_map::Entry, HashMap, HashSet};
@@ -12,6 +13,9 @@ pub(super) trait ModulePass {
     fn config(&self) -> &Config;
     fn scope(&self) -> &Scope;
     fn scope_mut(&mut self) -> &mut Scope;
+
+    /// Creates an expression which evaluates to `import.meta.url`.
+    fn make_import_meta_url(&mut self) -> Expr;
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,6 +29,16 @@ pub struct Config {
     pub lazy: Lazy,
     #[serde(default)]
     pub no_interop: bool,
+
+    /// Maps `import.meta.*` properties to expressions.
+    ///
+    /// e.g. `{ "env": "process.env" }` replaces `import.meta.env` with
+    /// `process.env`.
+    #[serde(default)]
+    pub import_meta: HashMap<JsWord, String>,
+
+    #[serde(default)]
+    pub top_level_await: TopLevelAwait,
 }
 
 impl Default for Config {
@@ -34,14 +48,47 @@ impl Default for Config {
             strict_mode: default_strict_mode(),
             lazy: Lazy::default(),
             no_interop: false,
+            import_meta: Default::default(),
+            top_level_await: Default::default(),
         }
     }
 }
 
+impl Config {
+    /// Returns an error if an `importMeta` option is not an expression.
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, src) in &self.import_meta {
+            if parse_override(name, src).is_none() {
+                return Err(format!("importMeta.{}: `{}` is not an expression", name, src));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 const fn default_strict_mode() -> bool {
     true
 }
 
+/// Handling of top-level await for module formats which does not support it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TopLevelAwait {
+    /// Reports an error.
+    Error,
+    /// Wraps the module body with an async function.
+    ///
+    /// The promise returned by the function is exported as `__tla`.
+    Wrap,
+}
+
+impl Default for TopLevelAwait {
+    fn default() -> Self {
+        TopLevelAwait::Error
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged, deny_unknown_fields, rename_all = "camelCase")]
 pub enum Lazy {
@@ -450,6 +497,18 @@ impl Scope {
         }
 
         match expr {
+            // import.meta.url
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(box Expr::MetaProp(ref meta)),
+                ref prop,
+                computed: false,
+                ..
+            }) if is_import_meta(meta) => match **prop {
+                Expr::Ident(ref prop) => lower_import_meta_prop(folder, &prop.sym),
+                _ => unreachable!("non-computed member expression should have an ident as prop"),
+            },
+            Expr::MetaProp(ref meta) if is_import_meta(meta) => lower_import_meta(folder),
+
             Expr::This(ThisExpr { span }) if top_level => *undefined(span),
             Expr::Ident(i) => match Self::fold_ident(folder, top_level, i) {
                 Ok(expr) => expr,
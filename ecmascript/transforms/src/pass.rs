@@ -116,6 +116,8 @@ mk_trait!(
     // MetaPropExpr,
     // MethodProp,
     Module,
+    Program,
+    Script,
     /* NamedExport,
      * NewExpr,
      * Null,
@@ -201,6 +203,19 @@ where
     }
 }
 
+/// Passes joined at [Module] are applied to scripts in the same way.
+impl<A, B> Fold<Script> for JoinedPass<A, B, Module>
+where
+    Script: FoldWith<Self>,
+    A: Fold<Script>,
+    B: Fold<Script>,
+{
+    #[inline(always)]
+    fn fold(&mut self, node: Script) -> Script {
+        self.second.fold(self.first.fold(node))
+    }
+}
+
 impl<A, B, T, N> Fold<T> for JoinedPass<A, B, N>
 where
     T: FoldWith<Self>,
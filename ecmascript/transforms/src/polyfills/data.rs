@@ -0,0 +1,277 @@
+//! Map from usages of built-ins to core-js@3 modules.
+use hashbrown::HashMap;
+
+type Modules = &'static [&'static str];
+
+macro_rules! data {
+    ($($k:expr => $v:expr,)*) => {{
+        let mut m: HashMap<&'static str, Modules> = HashMap::default();
+        $(
+            m.insert($k, $v);
+        )*
+        m
+    }};
+}
+
+/// Modules required to iterate over built-in collections.
+pub(super) const ITERATOR: Modules = &[
+    "es.symbol",
+    "es.symbol.iterator",
+    "es.array.iterator",
+    "es.string.iterator",
+    "web.dom-collections.iterator",
+];
+
+/// Modules required by `async` functions.
+pub(super) const PROMISE: Modules = &["es.object.to-string", "es.promise"];
+
+const MAP: Modules = &[
+    "es.map",
+    "es.object.to-string",
+    "es.array.iterator",
+    "es.string.iterator",
+    "web.dom-collections.iterator",
+];
+
+const SET: Modules = &[
+    "es.set",
+    "es.object.to-string",
+    "es.array.iterator",
+    "es.string.iterator",
+    "web.dom-collections.iterator",
+];
+
+const WEAK_MAP: Modules = &[
+    "es.weak-map",
+    "es.object.to-string",
+    "es.array.iterator",
+    "web.dom-collections.iterator",
+];
+
+const WEAK_SET: Modules = &[
+    "es.weak-set",
+    "es.object.to-string",
+    "es.array.iterator",
+    "web.dom-collections.iterator",
+];
+
+lazy_static! {
+    /// Global identifiers.
+    pub(super) static ref BUILT_INS: HashMap<&'static str, Modules> = data!(
+        "Promise" => PROMISE,
+        "Map" => MAP,
+        "Set" => SET,
+        "WeakMap" => WEAK_MAP,
+        "WeakSet" => WEAK_SET,
+        "Symbol" => &["es.symbol", "es.symbol.description", "es.object.to-string"],
+        "ArrayBuffer" => &[
+            "es.array-buffer.constructor",
+            "es.array-buffer.slice",
+            "es.object.to-string",
+        ],
+        "DataView" => &["es.data-view", "es.array-buffer.slice", "es.object.to-string"],
+        "Int8Array" => &["es.typed-array.int8-array"],
+        "Uint8Array" => &["es.typed-array.uint8-array"],
+        "Uint8ClampedArray" => &["es.typed-array.uint8-clamped-array"],
+        "Int16Array" => &["es.typed-array.int16-array"],
+        "Uint16Array" => &["es.typed-array.uint16-array"],
+        "Int32Array" => &["es.typed-array.int32-array"],
+        "Uint32Array" => &["es.typed-array.uint32-array"],
+        "Float32Array" => &["es.typed-array.float32-array"],
+        "Float64Array" => &["es.typed-array.float64-array"],
+        "globalThis" => &["es.global-this"],
+        "URL" => &["web.url"],
+        "URLSearchParams" => &["web.url-search-params"],
+        "setImmediate" => &["web.immediate"],
+        "clearImmediate" => &["web.immediate"],
+        "queueMicrotask" => &["web.queue-microtask"],
+        "setTimeout" => &["web.timers"],
+        "setInterval" => &["web.timers"],
+        "parseFloat" => &["es.parse-float"],
+        "parseInt" => &["es.parse-int"],
+    );
+
+    /// Properties of global identifiers. e.g. `Object.assign`
+    pub(super) static ref STATIC_PROPERTIES: HashMap<&'static str, Modules> = data!(
+        "Array.from" => &["es.array.from", "es.string.iterator"],
+        "Array.isArray" => &["es.array.is-array"],
+        "Array.of" => &["es.array.of"],
+
+        "Object.assign" => &["es.object.assign"],
+        "Object.create" => &["es.object.create"],
+        "Object.defineProperties" => &["es.object.define-properties"],
+        "Object.defineProperty" => &["es.object.define-property"],
+        "Object.entries" => &["es.object.entries"],
+        "Object.freeze" => &["es.object.freeze"],
+        "Object.fromEntries" => &["es.array.iterator", "es.object.from-entries"],
+        "Object.getOwnPropertyDescriptor" => &["es.object.get-own-property-descriptor"],
+        "Object.getOwnPropertyDescriptors" => &["es.object.get-own-property-descriptors"],
+        "Object.getOwnPropertyNames" => &["es.object.get-own-property-names"],
+        "Object.getOwnPropertySymbols" => &["es.symbol"],
+        "Object.getPrototypeOf" => &["es.object.get-prototype-of"],
+        "Object.is" => &["es.object.is"],
+        "Object.isExtensible" => &["es.object.is-extensible"],
+        "Object.isFrozen" => &["es.object.is-frozen"],
+        "Object.isSealed" => &["es.object.is-sealed"],
+        "Object.keys" => &["es.object.keys"],
+        "Object.preventExtensions" => &["es.object.prevent-extensions"],
+        "Object.seal" => &["es.object.seal"],
+        "Object.setPrototypeOf" => &["es.object.set-prototype-of"],
+        "Object.values" => &["es.object.values"],
+
+        "Number.EPSILON" => &["es.number.epsilon"],
+        "Number.isFinite" => &["es.number.is-finite"],
+        "Number.isInteger" => &["es.number.is-integer"],
+        "Number.isNaN" => &["es.number.is-nan"],
+        "Number.isSafeInteger" => &["es.number.is-safe-integer"],
+        "Number.MAX_SAFE_INTEGER" => &["es.number.max-safe-integer"],
+        "Number.MIN_SAFE_INTEGER" => &["es.number.min-safe-integer"],
+        "Number.parseFloat" => &["es.number.parse-float"],
+        "Number.parseInt" => &["es.number.parse-int"],
+
+        "Math.acosh" => &["es.math.acosh"],
+        "Math.asinh" => &["es.math.asinh"],
+        "Math.atanh" => &["es.math.atanh"],
+        "Math.cbrt" => &["es.math.cbrt"],
+        "Math.clz32" => &["es.math.clz32"],
+        "Math.cosh" => &["es.math.cosh"],
+        "Math.expm1" => &["es.math.expm1"],
+        "Math.fround" => &["es.math.fround"],
+        "Math.hypot" => &["es.math.hypot"],
+        "Math.imul" => &["es.math.imul"],
+        "Math.log10" => &["es.math.log10"],
+        "Math.log1p" => &["es.math.log1p"],
+        "Math.log2" => &["es.math.log2"],
+        "Math.sign" => &["es.math.sign"],
+        "Math.sinh" => &["es.math.sinh"],
+        "Math.tanh" => &["es.math.tanh"],
+        "Math.trunc" => &["es.math.trunc"],
+
+        "Promise.all" => &[
+            "es.object.to-string",
+            "es.promise",
+            "es.array.iterator",
+            "es.string.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Promise.allSettled" => &[
+            "es.object.to-string",
+            "es.promise",
+            "es.promise.all-settled",
+            "es.array.iterator",
+            "es.string.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Promise.race" => &[
+            "es.object.to-string",
+            "es.promise",
+            "es.array.iterator",
+            "es.string.iterator",
+            "web.dom-collections.iterator",
+        ],
+
+        "String.fromCodePoint" => &["es.string.from-code-point"],
+        "String.raw" => &["es.string.raw"],
+
+        "Symbol.asyncIterator" => &["es.symbol.async-iterator"],
+        "Symbol.hasInstance" => &["es.symbol.has-instance", "es.function.has-instance"],
+        "Symbol.isConcatSpreadable" => &["es.symbol.is-concat-spreadable", "es.array.concat"],
+        "Symbol.iterator" => &["es.symbol.iterator", "es.array.iterator", "es.string.iterator"],
+        "Symbol.match" => &["es.symbol.match", "es.string.match"],
+        "Symbol.replace" => &["es.symbol.replace", "es.string.replace"],
+        "Symbol.search" => &["es.symbol.search", "es.string.search"],
+        "Symbol.species" => &["es.symbol.species", "es.array.species"],
+        "Symbol.split" => &["es.symbol.split", "es.string.split"],
+        "Symbol.toPrimitive" => &["es.symbol.to-primitive", "es.date.to-primitive"],
+        "Symbol.toStringTag" => &[
+            "es.symbol.to-string-tag",
+            "es.object.to-string",
+            "es.math.to-string-tag",
+            "es.json.to-string-tag",
+        ],
+        "Symbol.unscopables" => &["es.symbol.unscopables"],
+
+        "Reflect.apply" => &["es.reflect.apply"],
+        "Reflect.construct" => &["es.reflect.construct"],
+        "Reflect.defineProperty" => &["es.reflect.define-property"],
+        "Reflect.deleteProperty" => &["es.reflect.delete-property"],
+        "Reflect.get" => &["es.reflect.get"],
+        "Reflect.getOwnPropertyDescriptor" => &["es.reflect.get-own-property-descriptor"],
+        "Reflect.getPrototypeOf" => &["es.reflect.get-prototype-of"],
+        "Reflect.has" => &["es.reflect.has"],
+        "Reflect.isExtensible" => &["es.reflect.is-extensible"],
+        "Reflect.ownKeys" => &["es.reflect.own-keys"],
+        "Reflect.preventExtensions" => &["es.reflect.prevent-extensions"],
+        "Reflect.set" => &["es.reflect.set"],
+        "Reflect.setPrototypeOf" => &["es.reflect.set-prototype-of"],
+
+        "JSON.stringify" => &["es.json.stringify"],
+        "Date.now" => &["es.date.now"],
+    );
+
+    /// Properties of values which are not global identifiers.
+    ///
+    /// As we don't know type of the value, all modules which defines a method
+    /// with the name are imported. e.g. `includes`
+    pub(super) static ref INSTANCE_PROPERTIES: HashMap<&'static str, Modules> = data!(
+        "bind" => &["es.function.bind"],
+        "codePointAt" => &["es.string.code-point-at"],
+        "concat" => &["es.array.concat"],
+        "copyWithin" => &["es.array.copy-within"],
+        "description" => &["es.symbol", "es.symbol.description"],
+        "endsWith" => &["es.string.ends-with"],
+        "entries" => &["es.array.iterator", "web.dom-collections.iterator"],
+        "every" => &["es.array.every"],
+        "fill" => &["es.array.fill"],
+        "filter" => &["es.array.filter"],
+        "finally" => &["es.promise", "es.promise.finally"],
+        "find" => &["es.array.find"],
+        "findIndex" => &["es.array.find-index"],
+        "flat" => &["es.array.flat", "es.array.unscopables.flat"],
+        "flatMap" => &["es.array.flat-map", "es.array.unscopables.flat-map"],
+        "forEach" => &["es.array.for-each", "web.dom-collections.for-each"],
+        "includes" => &["es.array.includes", "es.string.includes"],
+        "indexOf" => &["es.array.index-of"],
+        "join" => &["es.array.join"],
+        "keys" => &["es.array.iterator", "web.dom-collections.iterator"],
+        "lastIndexOf" => &["es.array.last-index-of"],
+        "map" => &["es.array.map"],
+        "matchAll" => &["es.string.match-all"],
+        "name" => &["es.function.name"],
+        "padEnd" => &["es.string.pad-end"],
+        "padStart" => &["es.string.pad-start"],
+        "reduce" => &["es.array.reduce"],
+        "reduceRight" => &["es.array.reduce-right"],
+        "repeat" => &["es.string.repeat"],
+        "reverse" => &["es.array.reverse"],
+        "slice" => &["es.array.slice"],
+        "some" => &["es.array.some"],
+        "sort" => &["es.array.sort"],
+        "splice" => &["es.array.splice"],
+        "startsWith" => &["es.string.starts-with"],
+        "toISOString" => &["es.date.to-iso-string"],
+        "toJSON" => &["es.date.to-json"],
+        "trim" => &["es.string.trim"],
+        "trimEnd" => &["es.string.trim-end"],
+        "trimLeft" => &["es.string.trim-start"],
+        "trimRight" => &["es.string.trim-end"],
+        "trimStart" => &["es.string.trim-start"],
+        "values" => &["es.array.iterator", "web.dom-collections.iterator"],
+    );
+}
+
+/// All modules known to this pass, which is used by `entry` mode.
+pub(super) fn all() -> Vec<&'static str> {
+    let mut modules = ITERATOR
+        .iter()
+        .chain(PROMISE)
+        .chain(BUILT_INS.values().flat_map(|m| m.iter()))
+        .chain(STATIC_PROPERTIES.values().flat_map(|m| m.iter()))
+        .chain(INSTANCE_PROPERTIES.values().flat_map(|m| m.iter()))
+        .cloned()
+        .collect::<Vec<_>>();
+    modules.sort();
+    modules.dedup();
+
+    modules
+}
@@ -0,0 +1,352 @@
+//! Injects imports of core-js@3 modules.
+use crate::{pass::Pass, util::ExprFactory};
+use ast::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use swc_atoms::JsWord;
+use swc_common::{Fold, SyntaxContext, Visit, VisitWith, DUMMY_SP};
+
+mod data;
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// Imports modules used by each file.
+    Usage,
+    /// Replaces `import "core-js"` and `require("core-js")` with imports of
+    /// all modules required by targets.
+    Entry,
+}
+
+/// Injects `import "core-js/modules/..."`, or `require("core-js/modules/...")`
+/// for scripts and CommonJS modules.
+///
+/// `is_required` is called with name of core-js modules (e.g. `es.promise`)
+/// and should return false if all targets support the feature natively.
+///
+/// Note that this pass should be executed before compat passes and after the
+/// `resolver`, because it treats identifiers without syntax context as
+/// globals.
+pub fn polyfills<F>(mode: Mode, is_required: F) -> impl Pass
+where
+    F: Fn(&str) -> bool,
+{
+    Polyfills { mode, is_required }
+}
+
+struct Polyfills<F> {
+    mode: Mode,
+    is_required: F,
+}
+
+const ENTRIES: &[&str] = &["core-js", "core-js/stable"];
+
+impl<F> Fold<Vec<ModuleItem>> for Polyfills<F>
+where
+    F: Fn(&str) -> bool,
+{
+    fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        match self.mode {
+            Mode::Usage => {
+                let mut v = UsageVisitor::default();
+                items.visit_with(&mut v);
+
+                // Don't import twice.
+                let imported = items
+                    .iter()
+                    .filter_map(|item| match *item {
+                        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                            ref src, ..
+                        })) => Some(src.value.clone()),
+                        ModuleItem::Stmt(ref stmt) => required_src(stmt),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                let modules = v
+                    .modules
+                    .into_iter()
+                    .filter(|m| !imported.contains(&src(m)) && (self.is_required)(*m));
+                let mut buf = if is_common_js(&items) {
+                    modules.map(|m| ModuleItem::Stmt(require(m))).collect::<Vec<_>>()
+                } else {
+                    modules.map(import).collect::<Vec<_>>()
+                };
+                if buf.is_empty() {
+                    return items;
+                }
+
+                buf.extend(items);
+                buf
+            }
+
+            Mode::Entry => {
+                let mut buf = Vec::with_capacity(items.len());
+
+                for item in items {
+                    match item {
+                        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                            ref src,
+                            ref specifiers,
+                            ..
+                        })) if specifiers.is_empty() && ENTRIES.contains(&&*src.value) => {
+                            buf.extend(self.required_modules().into_iter().map(import))
+                        }
+
+                        ModuleItem::Stmt(Stmt::Expr(box Expr::Call(CallExpr {
+                            callee: ExprOrSuper::Expr(box Expr::Ident(ref callee)),
+                            ref args,
+                            ..
+                        }))) if is_require_of_entry(callee, args) => buf.extend(
+                            self.required_modules()
+                                .into_iter()
+                                .map(|m| ModuleItem::Stmt(require(m))),
+                        ),
+
+                        _ => buf.push(item),
+                    }
+                }
+
+                buf
+            }
+        }
+    }
+}
+
+/// Scripts can't import modules, so `require` is used instead.
+impl<F> Fold<Script> for Polyfills<F>
+where
+    F: Fn(&str) -> bool,
+{
+    fn fold(&mut self, script: Script) -> Script {
+        let body = match self.mode {
+            Mode::Usage => {
+                let mut v = UsageVisitor::default();
+                script.body.visit_with(&mut v);
+
+                // Don't require twice.
+                let required = script
+                    .body
+                    .iter()
+                    .filter_map(required_src)
+                    .collect::<Vec<_>>();
+
+                let mut buf = v
+                    .modules
+                    .into_iter()
+                    .filter(|m| !required.contains(&src(m)) && (self.is_required)(*m))
+                    .map(require)
+                    .collect::<Vec<_>>();
+                if buf.is_empty() {
+                    return script;
+                }
+
+                buf.extend(script.body);
+                buf
+            }
+
+            Mode::Entry => {
+                let mut buf = Vec::with_capacity(script.body.len());
+
+                for stmt in script.body {
+                    match stmt {
+                        Stmt::Expr(box Expr::Call(CallExpr {
+                            callee: ExprOrSuper::Expr(box Expr::Ident(ref callee)),
+                            ref args,
+                            ..
+                        })) if is_require_of_entry(callee, args) => {
+                            buf.extend(self.required_modules().into_iter().map(require))
+                        }
+
+                        _ => buf.push(stmt),
+                    }
+                }
+
+                buf
+            }
+        };
+
+        Script { body, ..script }
+    }
+}
+
+impl<F> Polyfills<F>
+where
+    F: Fn(&str) -> bool,
+{
+    fn required_modules(&self) -> Vec<&'static str> {
+        data::all()
+            .into_iter()
+            .filter(|m| (self.is_required)(*m))
+            .collect()
+    }
+}
+
+/// Returns true for `require('core-js')`.
+fn is_require_of_entry(callee: &Ident, args: &[ExprOrSpread]) -> bool {
+    if callee.sym != js_word!("require") || callee.span.ctxt() != SyntaxContext::empty() {
+        return false;
+    }
+
+    match *args {
+        [ExprOrSpread {
+            spread: None,
+            expr: box Expr::Lit(Lit::Str(Str { ref value, .. })),
+        }] => ENTRIES.contains(&&**value),
+        _ => false,
+    }
+}
+
+/// Returns true if `items` does not contain `import` nor `export`.
+///
+/// Such modules are expected to use `require` instead.
+fn is_common_js(items: &[ModuleItem]) -> bool {
+    items.iter().all(|item| match *item {
+        ModuleItem::ModuleDecl(..) => false,
+        ModuleItem::Stmt(..) => true,
+    })
+}
+
+/// Returns `"a"` for `require("a");`.
+fn required_src(stmt: &Stmt) -> Option<JsWord> {
+    match *stmt {
+        Stmt::Expr(box Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(box Expr::Ident(ref callee)),
+            ref args,
+            ..
+        })) if callee.sym == js_word!("require") => match *args {
+            [ExprOrSpread {
+                spread: None,
+                expr: box Expr::Lit(Lit::Str(Str { ref value, .. })),
+            }] => Some(value.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn src(module: &str) -> JsWord {
+    format!("core-js/modules/{}", module).into()
+}
+
+/// `import "core-js/modules/es.promise";`
+fn import(module: &str) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![],
+        src: quote_str!(src(module)),
+        type_only: false,
+    }))
+}
+
+/// `require("core-js/modules/es.promise");`
+fn require(module: &str) -> Stmt {
+    Stmt::Expr(box Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: quote_ident!("require").as_callee(),
+        args: vec![Lit::Str(quote_str!(src(module))).as_arg()],
+        type_args: Default::default(),
+    }))
+}
+
+#[derive(Default)]
+struct UsageVisitor {
+    /// Sorted to make output stable.
+    modules: BTreeSet<&'static str>,
+}
+
+impl UsageVisitor {
+    fn add(&mut self, modules: Option<&&'static [&'static str]>) {
+        if let Some(modules) = modules {
+            self.modules.extend(modules.iter());
+        }
+    }
+}
+
+impl Visit<Expr> for UsageVisitor {
+    fn visit(&mut self, e: &Expr) {
+        e.visit_children(self);
+
+        match *e {
+            Expr::Ident(ref i) if i.span.ctxt() == SyntaxContext::empty() => {
+                self.add(data::BUILT_INS.get(&*i.sym))
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Visit<MemberExpr> for UsageVisitor {
+    fn visit(&mut self, e: &MemberExpr) {
+        e.obj.visit_with(self);
+        if e.computed {
+            e.prop.visit_with(self);
+        }
+
+        let prop = match *e.prop {
+            Expr::Ident(ref i) if !e.computed => &i.sym,
+            Expr::Lit(Lit::Str(ref s)) if e.computed => &s.value,
+            _ => return,
+        };
+
+        match e.obj {
+            ExprOrSuper::Expr(box Expr::Ident(ref obj))
+                if obj.span.ctxt() == SyntaxContext::empty() =>
+            {
+                let key = format!("{}.{}", obj.sym, prop);
+                if let Some(modules) = data::STATIC_PROPERTIES.get(&*key) {
+                    self.modules.extend(modules.iter());
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        self.add(data::INSTANCE_PROPERTIES.get(&**prop))
+    }
+}
+
+impl Visit<Function> for UsageVisitor {
+    fn visit(&mut self, f: &Function) {
+        f.visit_children(self);
+
+        if f.is_async {
+            self.modules.extend(data::PROMISE.iter());
+        }
+        if f.is_generator {
+            self.modules.extend(data::ITERATOR.iter());
+        }
+    }
+}
+
+impl Visit<ArrowExpr> for UsageVisitor {
+    fn visit(&mut self, f: &ArrowExpr) {
+        f.visit_children(self);
+
+        if f.is_async {
+            self.modules.extend(data::PROMISE.iter());
+        }
+    }
+}
+
+/// Handles iteration protocol.
+macro_rules! iterator {
+    ($T:ty, |$n:ident| $cond:expr) => {
+        impl Visit<$T> for UsageVisitor {
+            fn visit(&mut self, $n: &$T) {
+                $n.visit_children(self);
+
+                if $cond {
+                    self.modules.extend(data::ITERATOR.iter());
+                }
+            }
+        }
+    };
+}
+
+iterator!(ForOfStmt, |s| true);
+iterator!(ArrayPat, |p| true);
+iterator!(ExprOrSpread, |e| e.spread.is_some());
+iterator!(YieldExpr, |e| e.delegate);
@@ -0,0 +1,210 @@
+use super::{polyfills, Mode};
+use crate::{pass::Pass, resolver, tests::Tester};
+use ast::*;
+use swc_common::{FoldWith, DUMMY_SP};
+
+fn syntax() -> ::swc_ecma_parser::Syntax {
+    Default::default()
+}
+
+fn tr(mode: Mode, is_required: fn(&str) -> bool) -> impl Pass {
+    chain!(resolver(), polyfills(mode, is_required))
+}
+
+fn all(_: &str) -> bool {
+    true
+}
+
+/// Same as `test!`, but `input` and `expected` are parsed as scripts.
+fn test_script(mode: Mode, is_required: fn(&str) -> bool, input: &str, expected: &str) {
+    Tester::run(|tester| {
+        let script = Script {
+            span: DUMMY_SP,
+            body: tester.parse_stmts("input.js", input)?,
+            shebang: None,
+        };
+        let actual = script
+            .fold_with(&mut tr(mode, is_required))
+            .fold_with(&mut ::testing::DropSpan);
+        let expected = tester
+            .parse_stmts("output.js", expected)?
+            .fold_with(&mut ::testing::DropSpan);
+
+        assert_eq!(actual.body, expected);
+        Ok(())
+    });
+}
+
+test!(
+    syntax(),
+    |_| tr(Mode::Usage, all),
+    usage,
+    "
+const a = Object.assign({}, b);
+a.includes(1);
+new Promise(() => {});
+",
+    "
+require('core-js/modules/es.array.includes');
+require('core-js/modules/es.object.assign');
+require('core-js/modules/es.object.to-string');
+require('core-js/modules/es.promise');
+require('core-js/modules/es.string.includes');
+const a = Object.assign({}, b);
+a.includes(1);
+new Promise(() => {});
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Mode::Usage, all),
+    usage_es_module,
+    "
+import foo from 'foo';
+new Promise(() => {});
+",
+    "
+import 'core-js/modules/es.object.to-string';
+import 'core-js/modules/es.promise';
+import foo from 'foo';
+new Promise(() => {});
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Mode::Usage, all),
+    usage_common_js_required,
+    "
+require('core-js/modules/es.promise');
+new Promise(() => {});
+module.exports = 1;
+",
+    "
+require('core-js/modules/es.object.to-string');
+require('core-js/modules/es.promise');
+new Promise(() => {});
+module.exports = 1;
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Mode::Usage, all),
+    usage_shadowed,
+    "
+import Promise from 'bluebird';
+function foo(Map) {
+    return new Map();
+}
+Promise.resolve();
+",
+    "
+import Promise from 'bluebird';
+function foo(Map) {
+    return new Map();
+}
+Promise.resolve();
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Mode::Usage, all),
+    usage_iterator,
+    "
+for (const a of b) {}
+",
+    "
+require('core-js/modules/es.array.iterator');
+require('core-js/modules/es.string.iterator');
+require('core-js/modules/es.symbol');
+require('core-js/modules/es.symbol.iterator');
+require('core-js/modules/web.dom-collections.iterator');
+for (const a of b) {}
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Mode::Usage, |m| m != "es.promise"),
+    usage_supported_by_targets,
+    "
+import 'core-js/modules/es.object.to-string';
+Promise.resolve();
+",
+    "
+import 'core-js/modules/es.object.to-string';
+Promise.resolve();
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Mode::Entry, |m| m.starts_with("es.promise")),
+    entry,
+    "
+import 'core-js';
+foo();
+",
+    "
+import 'core-js/modules/es.promise';
+import 'core-js/modules/es.promise.all-settled';
+import 'core-js/modules/es.promise.finally';
+foo();
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Mode::Entry, |m| m.starts_with("es.promise")),
+    entry_require,
+    "
+require('core-js/stable');
+foo();
+",
+    "
+require('core-js/modules/es.promise');
+require('core-js/modules/es.promise.all-settled');
+require('core-js/modules/es.promise.finally');
+foo();
+"
+);
+
+#[test]
+fn usage_script() {
+    test_script(
+        Mode::Usage,
+        |m| m != "es.object.to-string",
+        "
+require('core-js/modules/es.object.assign');
+const a = Object.assign({}, b);
+new Promise(() => {});
+",
+        "
+require('core-js/modules/es.promise');
+require('core-js/modules/es.object.assign');
+const a = Object.assign({}, b);
+new Promise(() => {});
+",
+    );
+}
+
+#[test]
+fn entry_script() {
+    test_script(
+        Mode::Entry,
+        |m| m.starts_with("es.promise"),
+        "
+require('core-js');
+foo();
+",
+        "
+require('core-js/modules/es.promise');
+require('core-js/modules/es.promise.all-settled');
+require('core-js/modules/es.promise.finally');
+foo();
+",
+    );
+}
@@ -4,14 +4,16 @@ use self::{
     used_name::{UsedNameCollector, UsedNameRenamer},
 };
 use crate::{
+    compat::es2015::SuperFieldAccessFolder,
     pass::Pass,
     util::{
-        alias_ident_for, constructor::inject_after_super, default_constructor, undefined,
-        ExprFactory, ModuleItemLike, StmtLike,
+        alias_ident_for, constructor::inject_after_super, default_constructor, is_valid_ident,
+        prepend, undefined, ExprFactory, ModuleItemLike, StmtLike,
     },
 };
 use ast::*;
 use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
 use swc_atoms::JsWord;
 use swc_common::{Fold, FoldWith, Mark, Spanned, VisitWith, DUMMY_SP};
 
@@ -28,12 +30,28 @@ mod used_name;
 /// # Impl note
 ///
 /// We use custom helper to handle export defaul class
-pub fn class_properties() -> impl Pass {
-    ClassProperties { mark: Mark::root() }
+pub fn class_properties(config: Config) -> impl Pass {
+    ClassProperties {
+        config,
+        mark: Mark::root(),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// If true, public fields are initialized by assignment (`this.x = v`)
+    /// instead of `_defineProperty(this, "x", v)`, like
+    /// `useDefineForClassFields: false` of typescript.
+    ///
+    /// Note that setters of super class are invoked in this mode.
+    #[serde(default)]
+    pub set_public_class_fields: bool,
 }
 
 #[derive(Clone)]
 struct ClassProperties {
+    config: Config,
     mark: Mark,
 }
 
@@ -248,6 +266,14 @@ impl Fold<BlockStmtOrExpr> for ClassProperties {
     }
 }
 
+/// Getter and setter of a private name.
+struct PrivateAccessor {
+    name: PrivateName,
+    is_static: bool,
+    get: Option<Ident>,
+    set: Option<Ident>,
+}
+
 impl ClassProperties {
     fn fold_class_as_decl(
         &mut self,
@@ -263,11 +289,69 @@ impl ClassProperties {
             (vec![], vec![], vec![], vec![], None);
         let mut used_names = vec![];
         let mut statics = HashSet::default();
+        let mut methods = HashSet::default();
+        let mut accessors: Vec<PrivateAccessor> = vec![];
+        let mut private_fns = vec![];
+        let brand = private_ident!("_brand");
+        let mut has_instance_method = false;
 
         for member in class.body {
             match member {
-                ClassMember::PrivateMethod(..) | ClassMember::TsIndexSignature(..) => {
-                    members.push(member)
+                ClassMember::TsIndexSignature(..) => members.push(member),
+
+                ClassMember::PrivateMethod(method) => {
+                    let sym = method.key.id.sym.clone();
+                    if method.is_static {
+                        statics.insert(sym.clone());
+                    }
+
+                    let fn_ident = match method.kind {
+                        MethodKind::Method => {
+                            if !method.is_static {
+                                has_instance_method = true;
+                            }
+                            methods.insert(sym.clone());
+
+                            // Same name as private fields, as `FieldAccessFolder` refers to it.
+                            Ident::new(
+                                format!("_{}", sym).into(),
+                                method.key.span.apply_mark(self.mark),
+                            )
+                        }
+                        MethodKind::Getter | MethodKind::Setter => {
+                            let is_getter = method.kind == MethodKind::Getter;
+                            let fn_ident = private_ident!(
+                                method.key.span,
+                                format!("_{}_{}", if is_getter { "get" } else { "set" }, sym)
+                            );
+
+                            let pos = match accessors.iter().position(|a| a.name.id.sym == sym) {
+                                Some(pos) => pos,
+                                None => {
+                                    accessors.push(PrivateAccessor {
+                                        name: method.key.clone(),
+                                        is_static: method.is_static,
+                                        get: None,
+                                        set: None,
+                                    });
+                                    accessors.len() - 1
+                                }
+                            };
+                            if is_getter {
+                                accessors[pos].get = Some(fn_ident.clone());
+                            } else {
+                                accessors[pos].set = Some(fn_ident.clone());
+                            }
+
+                            fn_ident
+                        }
+                    };
+
+                    private_fns.push(FnDecl {
+                        ident: fn_ident,
+                        declare: false,
+                        function: replace_super(&ident, method.is_static, method.function),
+                    });
                 }
 
                 ClassMember::Method(method) => {
@@ -293,6 +377,9 @@ impl ClassProperties {
                     members.push(ClassMember::Method(ClassMethod { key, ..method }))
                 }
 
+                // `declare foo: string;` only exists in type level.
+                ClassMember::ClassProp(ClassProp { declare: true, .. }) => {}
+
                 ClassMember::ClassProp(mut prop) => {
                     let prop_span = prop.span();
                     prop.key = prop
@@ -326,24 +413,49 @@ impl ClassProperties {
                             used_names: &mut used_names,
                         });
                     }
-                    let value = prop.value.unwrap_or_else(|| undefined(prop_span)).as_arg();
+                    let value = prop.value.unwrap_or_else(|| undefined(prop_span));
 
-                    let callee = helper!(define_property, "defineProperty");
+                    let obj = if prop.is_static {
+                        ident.clone().as_arg()
+                    } else {
+                        ThisExpr { span: DUMMY_SP }.as_arg()
+                    };
 
-                    if prop.is_static {
-                        extra_stmts.push(Stmt::Expr(box Expr::Call(CallExpr {
+                    let init = if self.config.set_public_class_fields {
+                        let (computed, key) = match *key.expr {
+                            Expr::Lit(Lit::Str(ref s))
+                                if !prop.computed && is_valid_ident(&s.value) =>
+                            {
+                                (false, box Expr::Ident(Ident::new(s.value.clone(), s.span)))
+                            }
+                            _ => (true, key.expr),
+                        };
+
+                        // this.foo = value
+                        box Expr::Assign(AssignExpr {
                             span: DUMMY_SP,
-                            callee,
-                            args: vec![ident.clone().as_arg(), key, value],
-                            type_args: Default::default(),
-                        })))
+                            left: PatOrExpr::Expr(box Expr::Member(MemberExpr {
+                                span: DUMMY_SP,
+                                obj: ExprOrSuper::Expr(obj.expr),
+                                prop: key,
+                                computed,
+                            })),
+                            op: op!("="),
+                            right: value,
+                        })
                     } else {
-                        constructor_exprs.push(box Expr::Call(CallExpr {
+                        box Expr::Call(CallExpr {
                             span: DUMMY_SP,
-                            callee,
-                            args: vec![ThisExpr { span: DUMMY_SP }.as_arg(), key, value],
+                            callee: helper!(define_property, "defineProperty"),
+                            args: vec![obj, key, value.as_arg()],
                             type_args: Default::default(),
-                        }));
+                        })
+                    };
+
+                    if prop.is_static {
+                        extra_stmts.push(Stmt::Expr(init))
+                    } else {
+                        constructor_exprs.push(init);
                     }
                 }
                 ClassMember::PrivateProp(prop) => {
@@ -433,16 +545,116 @@ impl ClassProperties {
             }
         }
 
+        // Private methods and accessors should be available before fields are
+        // initialized.
+        let mut private_stmts = vec![];
+        let mut private_exprs = vec![];
+
+        if has_instance_method {
+            // var _brand = new WeakSet();
+            private_stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    definite: false,
+                    name: Pat::Ident(brand.clone()),
+                    init: Some(box Expr::New(NewExpr {
+                        span: DUMMY_SP,
+                        callee: box Expr::Ident(quote_ident!("WeakSet")),
+                        args: Some(vec![]),
+                        type_args: Default::default(),
+                    })),
+                }],
+            })));
+
+            // _brand.add(this);
+            private_exprs.push(box Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: brand.clone().member(quote_ident!("add")).as_callee(),
+                args: vec![ThisExpr { span: DUMMY_SP }.as_arg()],
+                type_args: Default::default(),
+            }));
+        }
+
+        for accessor in accessors {
+            let ident = Ident::new(
+                format!("_{}", accessor.name.id.sym).into(),
+                accessor.name.span.apply_mark(self.mark),
+            );
+
+            // { get: _get_x, set: _set_x }
+            let desc = box Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: accessor
+                    .get
+                    .map(|f| (quote_ident!("get"), f))
+                    .into_iter()
+                    .chain(accessor.set.map(|f| (quote_ident!("set"), f)))
+                    .map(|(key, f)| {
+                        PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(key),
+                            value: box Expr::Ident(f),
+                        }))
+                    })
+                    .collect(),
+            });
+
+            let init = if accessor.is_static {
+                desc
+            } else {
+                private_exprs.push(box Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: ident.clone().member(quote_ident!("set")).as_callee(),
+                    args: vec![ThisExpr { span: DUMMY_SP }.as_arg(), desc.as_arg()],
+                    type_args: Default::default(),
+                }));
+
+                box Expr::New(NewExpr {
+                    span: DUMMY_SP,
+                    callee: box Expr::Ident(quote_ident!("WeakMap")),
+                    args: Some(vec![]),
+                    type_args: Default::default(),
+                })
+            };
+
+            private_stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    definite: false,
+                    name: Pat::Ident(ident),
+                    init: Some(init),
+                }],
+            })));
+        }
+
+        private_exprs.extend(constructor_exprs);
+        private_stmts.extend(extra_stmts);
+        let mut extra_stmts = private_stmts;
+
         let constructor =
-            self.process_constructor(constructor, has_super, &used_names, constructor_exprs);
+            self.process_constructor(constructor, has_super, &used_names, private_exprs);
         members.push(ClassMember::Constructor(constructor));
 
-        let members = members.fold_with(&mut FieldAccessFolder {
+        let mut folder = FieldAccessFolder {
             mark: self.mark,
             statics: &statics,
+            methods: &methods,
+            brand: &brand,
             vars: vec![],
             class_name: &ident,
-        });
+        };
+        let members = members.fold_with(&mut folder);
+        extra_stmts.extend(
+            private_fns
+                .fold_with(&mut folder)
+                .into_iter()
+                .map(|decl| Stmt::Decl(Decl::Fn(decl))),
+        );
 
         (
             vars,
@@ -482,3 +694,35 @@ impl ClassProperties {
         inject_after_super(constructor, constructor_exprs)
     }
 }
+
+/// Private methods are moved out of the class, so `super.foo` in them is
+/// replaced with `_get(_getPrototypeOf(Foo.prototype), 'foo', this)`.
+fn replace_super(class_name: &Ident, is_static: bool, function: Function) -> Function {
+    let mut vars = vec![];
+    let mut function = function.fold_with(&mut SuperFieldAccessFolder {
+        class_name,
+        vars: &mut vars,
+        constructor_this_mark: None,
+        is_static,
+        folding_constructor: false,
+        in_nested_scope: false,
+        in_injected_define_property_call: false,
+        this_alias_mark: None,
+    });
+
+    if !vars.is_empty() {
+        if let Some(ref mut body) = function.body {
+            prepend(
+                &mut body.stmts,
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vars,
+                })),
+            );
+        }
+    }
+
+    function
+}
@@ -3,13 +3,17 @@ use ast::*;
 use hashbrown::HashSet;
 use std::{iter, mem};
 use swc_atoms::JsWord;
-use swc_common::{Fold, FoldWith, Mark, Spanned, DUMMY_SP};
+use swc_common::{Fold, FoldWith, Mark, Span, Spanned, DUMMY_SP};
 
 pub(super) struct FieldAccessFolder<'a> {
     pub mark: Mark,
     pub class_name: &'a Ident,
     pub vars: Vec<VarDeclarator>,
     pub statics: &'a HashSet<JsWord>,
+    /// Names of private methods. Private accessors are handled like fields.
+    pub methods: &'a HashSet<JsWord>,
+    /// `WeakSet` which contains instances of the class.
+    pub brand: &'a Ident,
 }
 
 impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
@@ -47,6 +51,10 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                     ExprOrSuper::Expr(ref obj) => obj.clone(),
                 };
 
+                if self.methods.contains(&n.id.sym) {
+                    return self.method_set(span, vec![obj]);
+                }
+
                 let is_static = self.statics.contains(&n.id.sym);
                 let ident = Ident::new(
                     format!("_{}", n.id.sym).into(),
@@ -200,6 +208,10 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                     ExprOrSuper::Expr(ref obj) => obj.clone(),
                 };
 
+                if self.methods.contains(&n.id.sym) {
+                    return self.method_set(span, vec![obj, right]);
+                }
+
                 let is_static = self.statics.contains(&n.id.sym);
                 let ident = Ident::new(
                     format!("_{}", n.id.sym).into(),
@@ -317,12 +329,69 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                 }
             }
             Expr::Member(e) => self.fold_private_get(e, None).0,
+
+            // `#x in obj`
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("in"),
+                left: box Expr::PrivateName(n),
+                right,
+            }) => {
+                let right = right.fold_with(self);
+                self.brand_check(span, n, right)
+            }
+
             _ => return e.fold_children(self),
         }
     }
 }
 
 impl<'a> FieldAccessFolder<'a> {
+    /// Private methods are not writable.
+    ///
+    /// Returns `(...exprs, _classPrivateMethodSet())`
+    fn method_set(&mut self, span: Span, exprs: Vec<Box<Expr>>) -> Expr {
+        let mut exprs = exprs.fold_with(self);
+        exprs.push(box Expr::Call(CallExpr {
+            span,
+            callee: helper!(class_private_method_set, "classPrivateMethodSet"),
+            args: vec![],
+            type_args: Default::default(),
+        }));
+
+        Expr::Seq(SeqExpr { span, exprs })
+    }
+
+    /// Converts `#x in obj`.
+    fn brand_check(&mut self, span: Span, n: PrivateName, obj: Box<Expr>) -> Expr {
+        if self.statics.contains(&n.id.sym) {
+            // obj === Foo
+            return Expr::Bin(BinExpr {
+                span,
+                left: obj,
+                op: op!("==="),
+                right: box Expr::Ident(self.class_name.clone()),
+            });
+        }
+
+        // _brand.has(obj) or _x.has(obj)
+        let set = if self.methods.contains(&n.id.sym) {
+            self.brand.clone()
+        } else {
+            Ident::new(
+                format!("_{}", n.id.sym).into(),
+                n.id.span.apply_mark(self.mark),
+            )
+        };
+
+        Expr::Call(CallExpr {
+            span,
+            callee: set.member(quote_ident!("has")).as_callee(),
+            args: vec![obj.as_arg()],
+            type_args: Default::default(),
+        })
+    }
+
     /// Returns `(expr, thisObject)`
     ///
     ///   - `obj_alias`: If alias is already declared, this method will use
@@ -358,11 +427,20 @@ impl<'a> FieldAccessFolder<'a> {
             n.id.span.apply_mark(self.mark),
         );
 
+        let is_method = self.methods.contains(&n.id.sym);
+
         if is_static {
-            let get = helper!(
-                class_static_private_field_spec_get,
-                "classStaticPrivateFieldSpecGet"
-            );
+            let get = if is_method {
+                helper!(
+                    class_static_private_method_get,
+                    "classStaticPrivateMethodGet"
+                )
+            } else {
+                helper!(
+                    class_static_private_field_spec_get,
+                    "classStaticPrivateFieldSpecGet"
+                )
+            };
 
             (
                 Expr::Call(CallExpr {
@@ -378,14 +456,25 @@ impl<'a> FieldAccessFolder<'a> {
                 Some(Expr::Ident(self.class_name.clone())),
             )
         } else {
-            let get = helper!(class_private_field_get, "classPrivateFieldGet");
+            // Arguments after the receiver.
+            let (get, args) = if is_method {
+                (
+                    helper!(class_private_method_get, "classPrivateMethodGet"),
+                    vec![self.brand.clone().as_arg(), ident.as_arg()],
+                )
+            } else {
+                (
+                    helper!(class_private_field_get, "classPrivateFieldGet"),
+                    vec![ident.as_arg()],
+                )
+            };
 
             match *obj {
                 Expr::This(this) => (
                     CallExpr {
                         span: DUMMY_SP,
                         callee: get,
-                        args: vec![this.as_arg(), ident.as_arg()],
+                        args: iter::once(this.as_arg()).chain(args).collect(),
 
                         type_args: Default::default(),
                     }
@@ -404,24 +493,23 @@ impl<'a> FieldAccessFolder<'a> {
                         var
                     });
 
+                    let receiver = if is_alias_initialized {
+                        var.clone().as_arg()
+                    } else {
+                        AssignExpr {
+                            span: DUMMY_SP,
+                            left: PatOrExpr::Pat(box Pat::Ident(var.clone())),
+                            op: op!("="),
+                            right: obj,
+                        }
+                        .as_arg()
+                    };
+
                     (
                         CallExpr {
                             span: DUMMY_SP,
                             callee: get,
-                            args: vec![
-                                if is_alias_initialized {
-                                    var.clone().as_arg()
-                                } else {
-                                    AssignExpr {
-                                        span: DUMMY_SP,
-                                        left: PatOrExpr::Pat(box Pat::Ident(var.clone())),
-                                        op: op!("="),
-                                        right: obj,
-                                    }
-                                    .as_arg()
-                                },
-                                ident.as_arg(),
-                            ],
+                            args: iter::once(receiver).chain(args).collect(),
 
                             type_args: Default::default(),
                         }
@@ -11,6 +11,7 @@ use swc_ecma_parser::{EsConfig, Syntax};
 fn syntax() -> Syntax {
     Syntax::Es(EsConfig {
         class_private_props: true,
+        class_private_methods: true,
         class_props: true,
         ..Default::default()
     })
@@ -20,8 +21,8 @@ fn tr() -> impl Fold<Module> {
     chain!(
         resolver(),
         function_name(),
-        class_properties(),
-        Classes,
+        class_properties(Default::default()),
+        Classes::default(),
         block_scoping(),
         ReservedWord,
     )
@@ -2779,7 +2780,7 @@ var _x = {
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties()),
+    |_| chain!(resolver(), class_properties(Default::default())),
     issue_308,
     "function bar(props) {}
 class Foo {
@@ -2807,7 +2808,7 @@ class Foo{
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties(), Classes),
+    |_| chain!(resolver(), class_properties(Default::default()), Classes::default()),
     issue_342,
     "class Foo {
   constructor(bar) {
@@ -2830,7 +2831,7 @@ test!(
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties(), block_scoping()),
+    |_| chain!(resolver(), class_properties(Default::default()), block_scoping()),
     issue_443,
     "
 const MODE = 1;
@@ -2851,3 +2852,337 @@ class foo{
 }
 _defineProperty(foo, 'MODE', MODE);"
 );
+
+test!(
+    syntax(),
+    |_| tr(),
+    private_method,
+    r#"
+class Foo {
+  #bar() {
+    return this.x;
+  }
+
+  test(other) {
+    if (!(#bar in other)) return;
+    return this.#bar() + other.#bar();
+  }
+}
+"#,
+    r#"
+var Foo = function () {
+  function Foo() {
+    _classCallCheck(this, Foo);
+    _brand.add(this);
+  }
+
+  _createClass(Foo, [{
+    key: "test",
+    value: function test(other) {
+      var _other;
+
+      if (!_brand.has(other)) return;
+      return _classPrivateMethodGet(this, _brand, _bar).call(this) +
+        _classPrivateMethodGet(_other = other, _brand, _bar).call(_other);
+    }
+  }]);
+  return Foo;
+}();
+
+var _brand = new WeakSet();
+
+function _bar() {
+  return this.x;
+}
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_method_exec,
+    r#"
+class Foo {
+  x = 1;
+
+  #add(n) {
+    return this.x + n;
+  }
+
+  test(n) {
+    return this.#add(n);
+  }
+
+  get() {
+    return this.#add;
+  }
+
+  set() {
+    this.#add = 1;
+  }
+
+  call(other) {
+    return other.#add(1);
+  }
+}
+
+const foo = new Foo();
+expect(foo.test(2)).toBe(3);
+expect(foo.get()).toBe(new Foo().get());
+expect(() => foo.set()).toThrow(TypeError);
+expect(foo.call(new Foo())).toBe(2);
+expect(() => foo.call({})).toThrow(TypeError);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_method_in_field_initializer_exec,
+    r#"
+class Foo {
+  value = this.#init();
+
+  #init() {
+    return 'init';
+  }
+}
+
+class Bar extends Foo {
+  #answer() {
+    return 42;
+  }
+
+  answer() {
+    return this.#answer();
+  }
+}
+
+expect(new Foo().value).toBe('init');
+expect(new Bar().value).toBe('init');
+expect(new Bar().answer()).toBe(42);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_method_super_exec,
+    r#"
+class Base {
+  foo() {
+    return 1;
+  }
+
+  static foo() {
+    return 2;
+  }
+}
+
+class Foo extends Base {
+  #bar() {
+    return super.foo() + 1;
+  }
+
+  get #baz() {
+    return super.foo() + 2;
+  }
+
+  #set() {
+    super.x = 5;
+  }
+
+  static #sbar() {
+    return super.foo() + 1;
+  }
+
+  test() {
+    this.#set();
+    return [this.#bar(), this.#baz, this.x];
+  }
+
+  static test() {
+    return Foo.#sbar();
+  }
+}
+
+expect(new Foo().test()).toEqual([2, 3, 5]);
+expect(Foo.test()).toBe(3);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_static_method_exec,
+    r#"
+class Foo {
+  static #double(n) {
+    return n * 2;
+  }
+
+  static test(n) {
+    return Foo.#double(n);
+  }
+
+  static call(other) {
+    return other.#double(1);
+  }
+}
+
+class Bar extends Foo {}
+
+expect(Foo.test(2)).toBe(4);
+expect(Foo.call(Foo)).toBe(2);
+expect(() => Foo.call(Bar)).toThrow(TypeError);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_accessors_exec,
+    r#"
+class Foo {
+  #value = 1;
+  static #count = 0;
+
+  get #x() {
+    return this.#value;
+  }
+
+  set #x(v) {
+    this.#value = v;
+  }
+
+  get #readOnly() {
+    return 'read';
+  }
+
+  static get #total() {
+    return Foo.#count;
+  }
+
+  static set #total(v) {
+    Foo.#count = v;
+  }
+
+  test() {
+    this.#x = 5;
+    this.#x++;
+    this.#x += 2;
+    return this.#x;
+  }
+
+  readOnly() {
+    return this.#readOnly;
+  }
+
+  writeReadOnly() {
+    this.#readOnly = 1;
+  }
+
+  static increment() {
+    Foo.#total += 1;
+    return Foo.#total;
+  }
+}
+
+const foo = new Foo();
+expect(foo.test()).toBe(8);
+expect(foo.readOnly()).toBe('read');
+expect(() => foo.writeReadOnly()).toThrow(TypeError);
+expect(Foo.increment()).toBe(1);
+expect(Foo.increment()).toBe(2);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_in_exec,
+    r#"
+class Foo {
+  #field = 1;
+  #method() {}
+  get #accessor() {
+    return 1;
+  }
+  static #staticMethod() {}
+
+  static check(obj) {
+    return [#field in obj, #method in obj, #accessor in obj, #staticMethod in obj];
+  }
+}
+
+expect(Foo.check(new Foo())).toEqual([true, true, true, false]);
+expect(Foo.check({})).toEqual([false, false, false, false]);
+expect(Foo.check(Foo)).toEqual([false, false, false, true]);
+"#
+);
+
+test!(
+    syntax(),
+    |_| chain!(
+        resolver(),
+        class_properties(Config {
+            set_public_class_fields: true
+        })
+    ),
+    set_public_class_fields,
+    "class Foo {
+  a = 1;
+  'b-c' = 2;
+  [d] = 3;
+  e;
+  static f = 4;
+}",
+    "var _d = d;
+class Foo {
+    constructor() {
+        this.a = 1;
+        this['b-c'] = 2;
+        this[_d] = 3;
+        this.e = void 0;
+    }
+}
+Foo.f = 4;"
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(
+        resolver(),
+        class_properties(Config {
+            set_public_class_fields: true
+        })
+    ),
+    set_public_class_fields_calls_setter_exec,
+    "let called = false;
+class Base {
+  set foo(v) {
+    called = v;
+  }
+}
+class Foo extends Base {
+  foo = true;
+}
+const foo = new Foo();
+expect(called).toBe(true);
+expect(Object.prototype.hasOwnProperty.call(foo, 'foo')).toBe(false);"
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| class_properties(Default::default()),
+    declare_field,
+    "class Foo {
+  declare foo: string;
+  bar = 1;
+}",
+    "class Foo {
+    constructor() {
+        _defineProperty(this, 'bar', 1);
+    }
+}"
+);
@@ -12,12 +12,12 @@ fn syntax(decorators_before_export: bool) -> Syntax {
 }
 
 fn tr() -> impl Fold<Module> {
-    chain!(decorators(), class_properties(),)
+    chain!(decorators(), class_properties(Default::default()),)
 }
 
 /// Folder for `transformation_*` tests
 fn transformation() -> impl Fold<Module> {
-    chain!(decorators(), class_properties(),)
+    chain!(decorators(), class_properties(Default::default()),)
 }
 
 // transformation_declaration
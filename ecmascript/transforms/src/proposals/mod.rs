@@ -1,5 +1,5 @@
 pub use self::{class_properties::class_properties, decorators::decorators, export::export};
 
-mod class_properties;
+pub mod class_properties;
 mod decorators;
 mod export;
@@ -0,0 +1,687 @@
+use crate::{
+    pass::Pass,
+    util::{DestructuringFinder, StmtLike},
+};
+use ast::*;
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+};
+use swc_atoms::JsWord;
+use swc_common::{Fold, FoldWith, Mark, Spanned, SyntaxContext, Visit, VisitWith, DUMMY_SP};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-transform-react-constant-elements`
+///
+/// Hoists jsx elements which are constant to the highest possible scope, so
+/// that they are created only once.
+///
+/// [resolver](crate::resolver::resolver) should be applied before this pass.
+pub fn constant_elements() -> impl Pass {
+    ConstantElements {
+        mark: Mark::fresh(Mark::root()),
+        cnt: 0,
+        declared: Default::default(),
+        mutated: Default::default(),
+        scopes: vec![],
+    }
+}
+
+type Id = (JsWord, SyntaxContext);
+
+fn id(i: &Ident) -> Id {
+    (i.sym.clone(), i.span.ctxt())
+}
+
+/// Local name of an import.
+fn import_local(s: &ImportSpecifier) -> &Ident {
+    match *s {
+        ImportSpecifier::Specific(ImportSpecific { ref local, .. })
+        | ImportSpecifier::Default(ImportDefault { ref local, .. })
+        | ImportSpecifier::Namespace(ImportStarAs { ref local, .. }) => local,
+    }
+}
+
+fn find_ids<N>(node: &N) -> Vec<Id>
+where
+    N: for<'a> VisitWith<DestructuringFinder<'a>>,
+{
+    let mut found = vec![];
+    node.visit_with(&mut DestructuringFinder { found: &mut found });
+
+    found
+        .into_iter()
+        .map(|(sym, span)| (sym, span.ctxt()))
+        .collect()
+}
+
+struct ConstantElements {
+    mark: Mark,
+    cnt: usize,
+    /// Symbols declared anywhere in the file.
+    declared: HashSet<JsWord>,
+    /// Bindings which are assigned after declaration.
+    mutated: HashSet<Id>,
+    /// Function scopes, from the outermost one.
+    scopes: Vec<Scope>,
+}
+
+#[derive(Default)]
+struct Scope {
+    /// Bindings declared in the body, mapped to the index of the first
+    /// statement before which they are initialized.
+    bindings: HashMap<Id, usize>,
+    /// Block scoped bindings in nested blocks.
+    blocked: HashSet<Id>,
+    /// Index of the statement being folded.
+    cur: usize,
+    /// Variables for hoisted elements, and the index of statement to insert
+    /// them before.
+    hoisted: Vec<(usize, Stmt)>,
+}
+
+impl Scope {
+    fn from_params<N>(params: &N) -> Self
+    where
+        N: for<'a> VisitWith<DestructuringFinder<'a>>,
+    {
+        Scope {
+            bindings: find_ids(params).into_iter().map(|id| (id, 0)).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn add_decl(&mut self, decl: &Decl, idx: usize) {
+        match *decl {
+            // Function declarations are hoisted.
+            Decl::Fn(ref f) => {
+                self.bindings.insert(id(&f.ident), 0);
+            }
+            Decl::Class(ref c) => {
+                self.bindings.insert(id(&c.ident), idx + 1);
+            }
+            Decl::Var(ref v) => {
+                for id in find_ids(&v.decls) {
+                    self.bindings.insert(id, idx + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add_stmt(&mut self, stmt: &Stmt, idx: usize) {
+        match *stmt {
+            Stmt::Decl(ref decl) => self.add_decl(decl, idx),
+            _ => {
+                let mut v = NestedDecls {
+                    vars: vec![],
+                    blocked: vec![],
+                };
+                stmt.visit_with(&mut v);
+
+                for id in v.vars {
+                    self.bindings.insert(id, idx + 1);
+                }
+                self.blocked.extend(v.blocked);
+            }
+        }
+    }
+
+    fn add_module_item(&mut self, item: &ModuleItem, idx: usize) {
+        match *item {
+            ModuleItem::Stmt(ref stmt) => self.add_stmt(stmt, idx),
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ref import)) => {
+                for s in &import.specifiers {
+                    self.bindings.insert(id(import_local(s)), 0);
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ref export)) => {
+                self.add_decl(&export.decl, idx)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ref export)) => {
+                match export.decl {
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ref i), ..
+                    }) => {
+                        self.bindings.insert(id(i), 0);
+                    }
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ref i), ..
+                    }) => {
+                        self.bindings.insert(id(i), idx + 1);
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ConstantElements {
+    /// Returns the index of scope to hoist `el` into, and the index of
+    /// statement to insert it before.
+    fn hoist_target(&self, el: &JSXElement) -> Option<(usize, usize)> {
+        let mut refs = vec![];
+        if !is_constant_element(el, &mut refs) {
+            return None;
+        }
+
+        let mut target = 0;
+        let mut found = vec![];
+        for r in refs {
+            if self.mutated.contains(&r) {
+                return None;
+            }
+
+            match self.scopes.iter().rposition(|scope| {
+                scope.bindings.contains_key(&r) || scope.blocked.contains(&r)
+            }) {
+                Some(idx) => {
+                    if self.scopes[idx].blocked.contains(&r) {
+                        return None;
+                    }
+                    if idx > target {
+                        target = idx;
+                    }
+                    found.push((idx, r));
+                }
+                // Declared in a scope we don't track.
+                None if self.declared.contains(&r.0) => return None,
+                // Global
+                None => {}
+            }
+        }
+
+        // Hoisting to the same scope is pointless.
+        if target + 1 >= self.scopes.len() {
+            return None;
+        }
+
+        let scope = &self.scopes[target];
+        let pos = found
+            .iter()
+            .filter(|(idx, _)| *idx == target)
+            .map(|(_, r)| scope.bindings[r])
+            .fold(scope.cur, |pos, min| pos.max(min));
+
+        Some((target, pos))
+    }
+
+    /// Hoists `el` and returns the reference to it if possible.
+    fn hoist(&mut self, el: &mut Option<Box<JSXElement>>) -> Option<Ident> {
+        let (target, pos) = self.hoist_target(el.as_ref().unwrap())?;
+
+        self.cnt += 1;
+        let sym: JsWord = if self.cnt == 1 {
+            "_ref".into()
+        } else {
+            format!("_ref{}", self.cnt).into()
+        };
+        let ident = Ident::new(sym, DUMMY_SP.apply_mark(self.mark));
+
+        let decl = Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(ident.clone()),
+                init: Some(box Expr::JSXElement(el.take().unwrap())),
+                definite: false,
+            }],
+        }));
+        self.scopes[target].hoisted.push((pos, decl));
+
+        Some(ident)
+    }
+
+    fn fold_body<T>(&mut self, scope: Scope, body: Vec<T>) -> Vec<T>
+    where
+        T: StmtLike + FoldWith<Self>,
+    {
+        self.scopes.push(scope);
+
+        let mut stmts = Vec::with_capacity(body.len());
+        for (i, stmt) in body.into_iter().enumerate() {
+            self.scopes.last_mut().unwrap().cur = i;
+            stmts.push(stmt.fold_with(self));
+        }
+
+        let mut hoisted = self.scopes.pop().unwrap().hoisted;
+        if hoisted.is_empty() {
+            return stmts;
+        }
+        hoisted.sort_by_key(|(pos, _)| *pos);
+
+        let mut buf = Vec::with_capacity(stmts.len() + hoisted.len());
+        let mut hoisted = hoisted.into_iter().peekable();
+        for (i, stmt) in stmts.into_iter().enumerate() {
+            while hoisted.peek().map(|(pos, _)| *pos <= i).unwrap_or(false) {
+                buf.push(T::from_stmt(hoisted.next().unwrap().1));
+            }
+            buf.push(stmt);
+        }
+        buf.extend(hoisted.map(|(_, stmt)| T::from_stmt(stmt)));
+
+        buf
+    }
+
+    fn analyze<N>(&mut self, node: &N)
+    where
+        N: for<'a> VisitWith<Analyzer<'a>>,
+    {
+        node.visit_with(&mut Analyzer {
+            declared: &mut self.declared,
+            mutated: &mut self.mutated,
+        });
+    }
+}
+
+impl Fold<Module> for ConstantElements {
+    fn fold(&mut self, module: Module) -> Module {
+        self.analyze(&module);
+
+        let mut scope = Scope::default();
+        for (i, item) in module.body.iter().enumerate() {
+            scope.add_module_item(item, i);
+        }
+
+        let body = self.fold_body(scope, module.body);
+        Module { body, ..module }
+    }
+}
+
+impl Fold<Script> for ConstantElements {
+    fn fold(&mut self, script: Script) -> Script {
+        self.analyze(&script);
+
+        let mut scope = Scope::default();
+        for (i, stmt) in script.body.iter().enumerate() {
+            scope.add_stmt(stmt, i);
+        }
+
+        let body = self.fold_body(scope, script.body);
+        Script { body, ..script }
+    }
+}
+
+impl Fold<Function> for ConstantElements {
+    fn fold(&mut self, f: Function) -> Function {
+        let body = match f.body {
+            Some(body) => body,
+            None => return f,
+        };
+
+        let mut scope = Scope::from_params(&f.params);
+        for (i, stmt) in body.stmts.iter().enumerate() {
+            scope.add_stmt(stmt, i);
+        }
+
+        let decorators = f.decorators.fold_with(self);
+        let params = f.params.fold_with(self);
+        let stmts = self.fold_body(scope, body.stmts);
+
+        Function {
+            decorators,
+            params,
+            body: Some(BlockStmt { stmts, ..body }),
+            ..f
+        }
+    }
+}
+
+impl Fold<ArrowExpr> for ConstantElements {
+    fn fold(&mut self, f: ArrowExpr) -> ArrowExpr {
+        let mut scope = Scope::from_params(&f.params);
+        let params = f.params.fold_with(self);
+
+        let body = match f.body {
+            BlockStmtOrExpr::BlockStmt(block) => {
+                for (i, stmt) in block.stmts.iter().enumerate() {
+                    scope.add_stmt(stmt, i);
+                }
+
+                let stmts = self.fold_body(scope, block.stmts);
+                BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, ..block })
+            }
+            BlockStmtOrExpr::Expr(expr) => {
+                self.scopes.push(scope);
+                let expr = expr.fold_with(self);
+                let hoisted = self.scopes.pop().unwrap().hoisted;
+
+                if hoisted.is_empty() {
+                    BlockStmtOrExpr::Expr(expr)
+                } else {
+                    let span = expr.span();
+                    BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        span,
+                        stmts: hoisted
+                            .into_iter()
+                            .map(|(_, stmt)| stmt)
+                            .chain(iter::once(Stmt::Return(ReturnStmt {
+                                span,
+                                arg: Some(expr),
+                            })))
+                            .collect(),
+                    })
+                }
+            }
+        };
+
+        ArrowExpr { params, body, ..f }
+    }
+}
+
+impl Fold<Expr> for ConstantElements {
+    fn fold(&mut self, e: Expr) -> Expr {
+        match e {
+            Expr::JSXElement(el) => {
+                let mut el = Some(el);
+                match self.hoist(&mut el) {
+                    Some(ident) => Expr::Ident(ident),
+                    None => Expr::JSXElement(el.unwrap().fold_with(self)),
+                }
+            }
+            _ => e.fold_children(self),
+        }
+    }
+}
+
+impl Fold<JSXElementChild> for ConstantElements {
+    fn fold(&mut self, child: JSXElementChild) -> JSXElementChild {
+        match child {
+            JSXElementChild::JSXElement(el) => {
+                let mut el = Some(el);
+                match self.hoist(&mut el) {
+                    Some(ident) => JSXElementChild::JSXExprContainer(JSXExprContainer {
+                        expr: JSXExpr::Expr(box Expr::Ident(ident)),
+                    }),
+                    None => JSXElementChild::JSXElement(el.unwrap().fold_with(self)),
+                }
+            }
+            _ => child.fold_children(self),
+        }
+    }
+}
+
+/// Returns false if `el` may evaluate to a different value.
+///
+/// Bindings referenced by `el` are stored in `refs`.
+fn is_constant_element(el: &JSXElement, refs: &mut Vec<Id>) -> bool {
+    let name = match el.opening.name {
+        JSXElementName::Ident(ref i) => {
+            if i.sym == js_word!("this") {
+                return false;
+            }
+            // Intrinsic elements are converted to strings.
+            if !i.sym.starts_with(|c: char| c.is_ascii_lowercase()) {
+                refs.push(id(i));
+            }
+            true
+        }
+        JSXElementName::JSXNamespacedName(..) => true,
+        JSXElementName::JSXMemberExpr(ref e) => {
+            let mut obj = &e.obj;
+            loop {
+                match *obj {
+                    JSXObject::JSXMemberExpr(ref e) => obj = &e.obj,
+                    JSXObject::Ident(ref i) => {
+                        if i.sym == js_word!("this") {
+                            return false;
+                        }
+                        refs.push(id(i));
+                        break true;
+                    }
+                }
+            }
+        }
+    };
+
+    name && el.opening.attrs.iter().all(|attr| match *attr {
+        JSXAttrOrSpread::JSXAttr(ref attr) => {
+            // Elements with refs are not safe to hoist.
+            match attr.name {
+                JSXAttrName::Ident(ref i) if &*i.sym == "ref" => return false,
+                _ => {}
+            }
+
+            attr.value
+                .as_ref()
+                .map(|value| is_constant_expr(value, refs))
+                .unwrap_or(true)
+        }
+        JSXAttrOrSpread::SpreadElement(..) => false,
+    }) && el.children.iter().all(|child| is_constant_child(child, refs))
+}
+
+fn is_constant_child(child: &JSXElementChild, refs: &mut Vec<Id>) -> bool {
+    match *child {
+        JSXElementChild::JSXText(..) => true,
+        JSXElementChild::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::JSXEmptyExpr(..),
+        }) => true,
+        JSXElementChild::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(ref e),
+        }) => is_constant_expr(e, refs),
+        JSXElementChild::JSXElement(ref el) => is_constant_element(el, refs),
+        JSXElementChild::JSXFragment(ref f) => {
+            f.children.iter().all(|child| is_constant_child(child, refs))
+        }
+        JSXElementChild::JSXSpreadChild(..) => false,
+    }
+}
+
+/// Objects, arrays and functions are not constant as they can be mutated
+/// after render.
+fn is_constant_expr(e: &Expr, refs: &mut Vec<Id>) -> bool {
+    match *e {
+        Expr::Lit(Lit::Regex(..)) => false,
+        Expr::Lit(..) => true,
+        Expr::Ident(ref i) => {
+            if i.sym == js_word!("arguments") {
+                return false;
+            }
+            refs.push(id(i));
+            true
+        }
+        Expr::Tpl(ref tpl) => tpl.exprs.iter().all(|e| is_constant_expr(e, refs)),
+        Expr::Unary(UnaryExpr { op, ref arg, .. }) => {
+            op != op!("delete") && is_constant_expr(arg, refs)
+        }
+        Expr::Bin(BinExpr {
+            ref left,
+            ref right,
+            ..
+        }) => is_constant_expr(left, refs) && is_constant_expr(right, refs),
+        Expr::Cond(CondExpr {
+            ref test,
+            ref cons,
+            ref alt,
+            ..
+        }) => {
+            is_constant_expr(test, refs)
+                && is_constant_expr(cons, refs)
+                && is_constant_expr(alt, refs)
+        }
+        Expr::Paren(ParenExpr { ref expr, .. }) => is_constant_expr(expr, refs),
+        Expr::JSXElement(ref el) => is_constant_element(el, refs),
+        Expr::JSXFragment(ref f) => f.children.iter().all(|child| is_constant_child(child, refs)),
+        _ => false,
+    }
+}
+
+/// Collects declared symbols and reassigned bindings.
+struct Analyzer<'a> {
+    declared: &'a mut HashSet<JsWord>,
+    mutated: &'a mut HashSet<Id>,
+}
+
+impl<'a> Analyzer<'a> {
+    fn declare<N>(&mut self, node: &N)
+    where
+        N: for<'b> VisitWith<DestructuringFinder<'b>>,
+    {
+        self.declared
+            .extend(find_ids(node).into_iter().map(|(sym, _)| sym));
+    }
+
+    fn declare_ident(&mut self, i: &Ident) {
+        self.declared.insert(i.sym.clone());
+    }
+}
+
+impl<'a> Visit<VarDeclarator> for Analyzer<'a> {
+    fn visit(&mut self, d: &VarDeclarator) {
+        self.declare(&d.name);
+        d.visit_children(self);
+    }
+}
+
+impl<'a> Visit<Function> for Analyzer<'a> {
+    fn visit(&mut self, f: &Function) {
+        self.declare(&f.params);
+        f.visit_children(self);
+    }
+}
+
+impl<'a> Visit<ArrowExpr> for Analyzer<'a> {
+    fn visit(&mut self, f: &ArrowExpr) {
+        self.declare(&f.params);
+        f.visit_children(self);
+    }
+}
+
+impl<'a> Visit<Constructor> for Analyzer<'a> {
+    fn visit(&mut self, c: &Constructor) {
+        self.declare(&c.params);
+        c.visit_children(self);
+    }
+}
+
+impl<'a> Visit<SetterProp> for Analyzer<'a> {
+    fn visit(&mut self, p: &SetterProp) {
+        self.declare(&p.param);
+        p.visit_children(self);
+    }
+}
+
+impl<'a> Visit<CatchClause> for Analyzer<'a> {
+    fn visit(&mut self, c: &CatchClause) {
+        self.declare(&c.param);
+        c.visit_children(self);
+    }
+}
+
+impl<'a> Visit<ImportSpecifier> for Analyzer<'a> {
+    fn visit(&mut self, s: &ImportSpecifier) {
+        self.declare_ident(import_local(s));
+    }
+}
+
+impl<'a> Visit<FnDecl> for Analyzer<'a> {
+    fn visit(&mut self, f: &FnDecl) {
+        self.declare_ident(&f.ident);
+        f.visit_children(self);
+    }
+}
+
+impl<'a> Visit<ClassDecl> for Analyzer<'a> {
+    fn visit(&mut self, c: &ClassDecl) {
+        self.declare_ident(&c.ident);
+        c.visit_children(self);
+    }
+}
+
+impl<'a> Visit<FnExpr> for Analyzer<'a> {
+    fn visit(&mut self, f: &FnExpr) {
+        if let Some(ref i) = f.ident {
+            self.declare_ident(i);
+        }
+        f.visit_children(self);
+    }
+}
+
+impl<'a> Visit<ClassExpr> for Analyzer<'a> {
+    fn visit(&mut self, c: &ClassExpr) {
+        if let Some(ref i) = c.ident {
+            self.declare_ident(i);
+        }
+        c.visit_children(self);
+    }
+}
+
+impl<'a> Visit<AssignExpr> for Analyzer<'a> {
+    fn visit(&mut self, e: &AssignExpr) {
+        match e.left {
+            PatOrExpr::Pat(ref pat) => self.mutated.extend(find_ids(pat)),
+            PatOrExpr::Expr(box Expr::Ident(ref i)) => {
+                self.mutated.insert(id(i));
+            }
+            _ => {}
+        }
+        e.visit_children(self);
+    }
+}
+
+impl<'a> Visit<UpdateExpr> for Analyzer<'a> {
+    fn visit(&mut self, e: &UpdateExpr) {
+        if let Expr::Ident(ref i) = *e.arg {
+            self.mutated.insert(id(i));
+        }
+        e.visit_children(self);
+    }
+}
+
+impl<'a> Visit<ForInStmt> for Analyzer<'a> {
+    fn visit(&mut self, s: &ForInStmt) {
+        if let VarDeclOrPat::Pat(ref pat) = s.left {
+            self.mutated.extend(find_ids(pat));
+        }
+        s.visit_children(self);
+    }
+}
+
+impl<'a> Visit<ForOfStmt> for Analyzer<'a> {
+    fn visit(&mut self, s: &ForOfStmt) {
+        if let VarDeclOrPat::Pat(ref pat) = s.left {
+            self.mutated.extend(find_ids(pat));
+        }
+        s.visit_children(self);
+    }
+}
+
+/// Finds declarations in nested statements, excluding nested functions.
+struct NestedDecls {
+    vars: Vec<Id>,
+    blocked: Vec<Id>,
+}
+
+impl Visit<VarDecl> for NestedDecls {
+    fn visit(&mut self, v: &VarDecl) {
+        let ids = find_ids(&v.decls);
+        match v.kind {
+            VarDeclKind::Var => self.vars.extend(ids),
+            _ => self.blocked.extend(ids),
+        }
+    }
+}
+
+impl Visit<FnDecl> for NestedDecls {
+    fn visit(&mut self, f: &FnDecl) {
+        self.blocked.push(id(&f.ident));
+    }
+}
+
+impl Visit<ClassDecl> for NestedDecls {
+    fn visit(&mut self, c: &ClassDecl) {
+        self.blocked.push(id(&c.ident));
+    }
+}
+
+impl Visit<Expr> for NestedDecls {
+    /// Expressions cannot contain declarations except in functions.
+    fn visit(&mut self, _: &Expr) {}
+}
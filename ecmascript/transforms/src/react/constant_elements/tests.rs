@@ -0,0 +1,110 @@
+use super::*;
+use crate::resolver::resolver;
+
+fn tr() -> impl Fold<Module> {
+    chain!(resolver(), constant_elements())
+}
+
+fn syntax() -> ::swc_ecma_parser::Syntax {
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    basic,
+    r#"
+function render() {
+  return <div className="foo" />;
+}
+"#,
+    r#"
+var _ref = <div className="foo" />;
+function render() {
+  return _ref;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    param,
+    r#"
+function render(text) {
+  return function () {
+    return <foo>{text}</foo>;
+  };
+}
+"#,
+    r#"
+function render(text) {
+  var _ref = <foo>{text}</foo>;
+  return function () {
+    return _ref;
+  };
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    child,
+    r#"
+const Foo = () => null;
+function render() {
+  return <div onClick={this.onClick}><Foo bar={1} /></div>;
+}
+"#,
+    r#"
+const Foo = () => null;
+var _ref = <Foo bar={1} />;
+function render() {
+  return <div onClick={this.onClick}>{_ref}</div>;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    top_level,
+    r#"var foo = <div />;"#,
+    r#"var foo = <div />;"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    not_constant,
+    r#"
+function render(props) {
+  let a = 1;
+  a = 2;
+  return () => [
+    <div ref="foo" />,
+    <div {...props} />,
+    <div>{a}</div>,
+    <div style={{}} />,
+    <div>{props.children}</div>,
+  ];
+}
+"#,
+    r#"
+function render(props) {
+  let a = 1;
+  a = 2;
+  return () => [
+    <div ref="foo" />,
+    <div {...props} />,
+    <div>{a}</div>,
+    <div style={{}} />,
+    <div>{props.children}</div>,
+  ];
+}
+"#
+);
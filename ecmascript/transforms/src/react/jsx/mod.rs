@@ -3,7 +3,7 @@ use crate::{
     util::{
         drop_span,
         options::{CM, SESSION},
-        ExprFactory, HANDLER,
+        prepend_stmts, undefined, ExprFactory, COMMENTS, HANDLER,
     },
 };
 use ast::*;
@@ -11,8 +11,10 @@ use chashmap::CHashMap;
 use serde::{Deserialize, Serialize};
 use std::{iter, mem, sync::Arc};
 use swc_atoms::JsWord;
-use swc_common::{FileName, Fold, FoldWith, Spanned, DUMMY_SP};
-use swc_ecma_parser::{Parser, SourceFileInput, Syntax};
+use swc_common::{
+    comments::Comment, BytePos, FileName, Fold, FoldWith, Mark, Span, Spanned, DUMMY_SP,
+};
+use swc_ecma_parser::{PResult, Parser, SourceFileInput, Syntax};
 
 #[cfg(test)]
 mod tests;
@@ -33,6 +35,39 @@ pub struct Options {
 
     #[serde(default)]
     pub use_builtins: bool,
+
+    /// Enables React Fast Refresh.
+    #[serde(default)]
+    pub refresh: bool,
+
+    /// Hoists constant jsx elements. See
+    /// [constant_elements](crate::react::constant_elements).
+    #[serde(default)]
+    pub constant_elements: bool,
+
+    #[serde(default)]
+    pub runtime: Runtime,
+
+    /// Module which provides the automatic runtime.
+    #[serde(default = "default_import_source")]
+    pub import_source: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Runtime {
+    /// `React.createElement(type, props, ...children)`
+    #[serde(rename = "classic")]
+    Classic,
+    /// `jsx(type, { ...props, children }, key)` imported from
+    /// `react/jsx-runtime`
+    #[serde(rename = "automatic")]
+    Automatic,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Runtime::Classic
+    }
 }
 
 impl Default for Options {
@@ -43,10 +78,18 @@ impl Default for Options {
             throw_if_namespace: default_throw_if_namespace(),
             development: false,
             use_builtins: false,
+            refresh: false,
+            constant_elements: false,
+            runtime: Default::default(),
+            import_source: default_import_source(),
         }
     }
 }
 
+fn default_import_source() -> String {
+    "react".into()
+}
+
 fn default_pragma() -> String {
     "React.createElement".into()
 }
@@ -60,13 +103,23 @@ fn default_throw_if_namespace() -> bool {
 }
 
 fn parse_option(name: &str, src: String) -> Box<Expr> {
+    try_parse_option(name, src.clone()).unwrap_or_else(|mut e| {
+        e.emit();
+        panic!(
+            "faield to parse jsx option {}: '{}' is not an expression",
+            name, src,
+        )
+    })
+}
+
+fn try_parse_option(name: &str, src: String) -> PResult<'static, Box<Expr>> {
     lazy_static! {
         static ref CACHE: CHashMap<Arc<String>, Box<Expr>> = CHashMap::with_capacity(2);
     }
 
     let fm = CM.new_source_file(FileName::Custom(format!("<jsx-config-{}.js>", name)), src);
     if let Some(expr) = CACHE.get(&fm.src) {
-        return expr.clone();
+        return Ok(expr.clone());
     }
 
     let expr = Parser::new(
@@ -76,21 +129,11 @@ fn parse_option(name: &str, src: String) -> Box<Expr> {
         None,
     )
     .parse_expr()
-    .map_err(|mut e| {
-        e.emit();
-        ()
-    })
-    .map(drop_span)
-    .unwrap_or_else(|()| {
-        panic!(
-            "faield to parse jsx option {}: '{}' is not an expression",
-            name, fm.src,
-        )
-    });
+    .map(drop_span)?;
 
     CACHE.insert(fm.src.clone(), expr.clone());
 
-    expr
+    Ok(expr)
 }
 
 /// `@babel/plugin-transform-react-jsx`
@@ -105,6 +148,11 @@ pub fn jsx(options: Options) -> impl Pass {
         },
         use_builtins: options.use_builtins,
         throw_if_namespace: options.throw_if_namespace,
+        runtime: options.runtime,
+        import_source: options.import_source.into(),
+        development: options.development,
+        mark: Mark::fresh(Mark::root()),
+        imports: vec![],
     }
 }
 
@@ -113,12 +161,189 @@ struct Jsx {
     pragma_frag: ExprOrSpread,
     use_builtins: bool,
     throw_if_namespace: bool,
+    runtime: Runtime,
+    import_source: JsWord,
+    development: bool,
+    /// Mark applied to local names of imports, so they don't conflict with
+    /// user code.
+    mark: Mark,
+    /// Imports used by the automatic runtime, grouped by source.
+    imports: Vec<(JsWord, Vec<(JsWord, Ident)>)>,
+}
+
+enum Pragma {
+    Jsx(Box<Expr>),
+    JsxFrag(Box<Expr>),
+    JsxRuntime(Runtime),
+    JsxImportSource(String),
+}
+
+/// Parses pragmas like `@jsx h` in `cmt`.
+fn parse_pragmas(cmt: &Comment) -> Vec<Pragma> {
+    let mut pragmas = vec![];
+
+    let mut words = cmt.text.split_whitespace();
+    while let Some(word) = words.next() {
+        match word {
+            "@jsx" | "@jsxFrag" | "@jsxRuntime" | "@jsxImportSource" => {}
+            _ => continue,
+        }
+        let value = match words.next() {
+            Some(value) => value,
+            None => break,
+        };
+
+        pragmas.push(match word {
+            "@jsx" | "@jsxFrag" => {
+                let name = if word == "@jsx" { "pragma" } else { "pragmaFrag" };
+                let expr = match try_parse_option(name, value.into()) {
+                    Ok(expr) => expr,
+                    Err(mut e) => {
+                        e.cancel();
+                        HANDLER.with(|handler| {
+                            handler
+                                .struct_span_err(
+                                    cmt.span,
+                                    &format!(
+                                        "{} must be an expression, but got `{}`.",
+                                        word, value
+                                    ),
+                                )
+                                .emit()
+                        });
+                        continue;
+                    }
+                };
+                if word == "@jsx" {
+                    Pragma::Jsx(expr)
+                } else {
+                    Pragma::JsxFrag(expr)
+                }
+            }
+            "@jsxRuntime" => match value {
+                "classic" => Pragma::JsxRuntime(Runtime::Classic),
+                "automatic" => Pragma::JsxRuntime(Runtime::Automatic),
+                _ => {
+                    HANDLER.with(|handler| {
+                        handler
+                            .struct_span_err(
+                                cmt.span,
+                                "Runtime must be either \"classic\" or \"automatic\".",
+                            )
+                            .emit()
+                    });
+                    continue;
+                }
+            },
+            _ => Pragma::JsxImportSource(value.into()),
+        });
+    }
+
+    pragmas
 }
 
 impl Jsx {
+    /// Runs `op` with options overridden by pragma comments before `pos`, the
+    /// start of the first statement of a file.
+    ///
+    /// `@jsx` and `@jsxFrag` imply the classic runtime and `@jsxImportSource`
+    /// implies the automatic runtime, unless `@jsxRuntime` is specified.
+    fn with_pragmas<F, Ret>(&mut self, pos: Option<BytePos>, op: F) -> Ret
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        let pragmas: Vec<_> = match pos {
+            Some(pos) if COMMENTS.is_set() => COMMENTS.with(|comments| {
+                comments
+                    .leading_comments(pos)
+                    .map(|cmts| cmts.iter().flat_map(parse_pragmas).collect())
+                    .unwrap_or_default()
+            }),
+            _ => vec![],
+        };
+        if pragmas.is_empty() {
+            return op(self);
+        }
+
+        let saved = (
+            self.pragma.clone(),
+            self.pragma_frag.clone(),
+            self.runtime,
+            self.import_source.clone(),
+        );
+
+        let mut runtime = None;
+        for pragma in pragmas {
+            match pragma {
+                Pragma::Jsx(pragma) => {
+                    self.pragma = ExprOrSuper::Expr(pragma);
+                    runtime = runtime.or(Some(Runtime::Classic));
+                }
+                Pragma::JsxFrag(pragma_frag) => {
+                    self.pragma_frag = ExprOrSpread {
+                        spread: None,
+                        expr: pragma_frag,
+                    };
+                    runtime = runtime.or(Some(Runtime::Classic));
+                }
+                Pragma::JsxImportSource(import_source) => {
+                    self.import_source = import_source.into();
+                    runtime = runtime.or(Some(Runtime::Automatic));
+                }
+                Pragma::JsxRuntime(explicit) => runtime = Some(explicit),
+            }
+        }
+        if let Some(runtime) = runtime {
+            self.runtime = runtime;
+        }
+
+        let ret = op(self);
+
+        let (pragma, pragma_frag, runtime, import_source) = saved;
+        self.pragma = pragma;
+        self.pragma_frag = pragma_frag;
+        self.runtime = runtime;
+        self.import_source = import_source;
+
+        ret
+    }
+
+    /// Returns the local name of `imported` from `src`.
+    fn import(&mut self, src: JsWord, imported: &str) -> Ident {
+        let local = quote_ident!(DUMMY_SP.apply_mark(self.mark), format!("_{}", imported));
+
+        match self.imports.iter_mut().find(|(s, _)| *s == src) {
+            Some((_, names)) => {
+                if names.iter().all(|(name, _)| &**name != imported) {
+                    names.push((imported.into(), local.clone()));
+                }
+            }
+            None => self.imports.push((src, vec![(imported.into(), local.clone())])),
+        }
+
+        local
+    }
+
+    /// Imports `name` from `react/jsx-runtime`, or `react/jsx-dev-runtime` in
+    /// development mode.
+    fn import_runtime(&mut self, name: &str) -> Ident {
+        let src = if self.development {
+            format!("{}/jsx-dev-runtime", self.import_source)
+        } else {
+            format!("{}/jsx-runtime", self.import_source)
+        };
+
+        self.import(src.into(), name)
+    }
+
     fn jsx_frag_to_expr(&mut self, el: JSXFragment) -> Expr {
         let span = el.span();
 
+        if self.runtime == Runtime::Automatic {
+            let fragment = self.import_runtime("Fragment");
+            return self.automatic_call(span, box Expr::Ident(fragment), vec![], el.children);
+        }
+
         Expr::Call(CallExpr {
             span,
             callee: self.pragma.clone(),
@@ -141,6 +366,10 @@ impl Jsx {
 
         let name = self.jsx_name(el.opening.name);
 
+        if self.runtime == Runtime::Automatic {
+            return self.automatic_call(span, name, el.opening.attrs, el.children);
+        }
+
         Expr::Call(CallExpr {
             span,
             callee: self.pragma.clone(),
@@ -160,6 +389,129 @@ impl Jsx {
         })
     }
 
+    /// `<div key={a}>b</div>` => `_jsx("div", { children: "b" }, a)`
+    fn automatic_call(
+        &mut self,
+        span: Span,
+        name: Box<Expr>,
+        attrs: Vec<JSXAttrOrSpread>,
+        children: Vec<JSXElementChild>,
+    ) -> Expr {
+        // `key` after a spread element overrides the key in the spread
+        // element, so `createElement` is used instead.
+        let key_after_spread = attrs
+            .iter()
+            .skip_while(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(..) => true,
+                JSXAttrOrSpread::SpreadElement(..) => false,
+            })
+            .any(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(attr) => is_attr_named(attr, "key"),
+                JSXAttrOrSpread::SpreadElement(..) => false,
+            });
+        if key_after_spread {
+            let src = self.import_source.clone();
+            let create_element = self.import(src, "createElement");
+
+            return Expr::Call(CallExpr {
+                span,
+                callee: create_element.as_callee(),
+                args: iter::once(name.as_arg())
+                    .chain(iter::once(self.fold_attrs(attrs).as_arg()))
+                    .chain(
+                        children
+                            .into_iter()
+                            .filter_map(|c| self.jsx_elem_child_to_expr(c)),
+                    )
+                    .collect(),
+                type_args: None,
+            });
+        }
+
+        let mut key = None;
+        let mut source = None;
+        let mut this = None;
+        let mut props = vec![];
+        for attr in attrs {
+            match attr {
+                JSXAttrOrSpread::JSXAttr(attr) => {
+                    if is_attr_named(&attr, "key") {
+                        key = Some(attr_value(attr.value, attr.span));
+                    } else if self.development && is_attr_named(&attr, "__source") {
+                        source = Some(attr_value(attr.value, attr.span));
+                    } else if self.development && is_attr_named(&attr, "__self") {
+                        this = Some(attr_value(attr.value, attr.span));
+                    } else {
+                        props.push(PropOrSpread::Prop(box attr_to_prop(attr)));
+                    }
+                }
+                JSXAttrOrSpread::SpreadElement(e) => props.push(PropOrSpread::Spread(e)),
+            }
+        }
+
+        let mut children: Vec<_> = children
+            .into_iter()
+            .filter_map(|c| self.jsx_elem_child_to_expr(c))
+            .collect();
+        let is_static = children.len() > 1;
+        let children = if is_static {
+            Some(box Expr::Array(ArrayLit {
+                span: DUMMY_SP,
+                elems: children.into_iter().map(Some).collect(),
+            }))
+        } else {
+            children.pop().map(|c| c.expr)
+        };
+        if let Some(children) = children {
+            props.push(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("children")),
+                value: children,
+            })));
+        }
+
+        let callee = if self.development {
+            self.import_runtime("jsxDEV")
+        } else if is_static {
+            self.import_runtime("jsxs")
+        } else {
+            self.import_runtime("jsx")
+        };
+
+        let mut args = vec![
+            name.as_arg(),
+            ObjectLit {
+                span: DUMMY_SP,
+                props,
+            }
+            .as_arg(),
+        ];
+        if self.development {
+            args.push(key.unwrap_or_else(|| undefined(DUMMY_SP)).as_arg());
+            args.push(
+                Lit::Bool(Bool {
+                    span: DUMMY_SP,
+                    value: is_static,
+                })
+                .as_arg(),
+            );
+            if source.is_some() || this.is_some() {
+                args.push(source.unwrap_or_else(|| undefined(DUMMY_SP)).as_arg());
+            }
+            if let Some(this) = this {
+                args.push(this.as_arg());
+            }
+        } else if let Some(key) = key {
+            args.push(key.as_arg());
+        }
+
+        Expr::Call(CallExpr {
+            span,
+            callee: callee.as_callee(),
+            args,
+            type_args: None,
+        })
+    }
+
     fn jsx_elem_child_to_expr(&mut self, c: JSXElementChild) -> Option<ExprOrSpread> {
         Some(match c {
             JSXElementChild::JSXText(text) => {
@@ -284,6 +636,75 @@ impl Fold<Expr> for Jsx {
     }
 }
 
+impl Fold<Module> for Jsx {
+    fn fold(&mut self, module: Module) -> Module {
+        let pos = module.body.first().map(|item| item.span().lo());
+        let mut module = self.with_pragmas(pos, |jsx| module.fold_children(jsx));
+
+        let imports = mem::replace(&mut self.imports, vec![]);
+        prepend_stmts(
+            &mut module.body,
+            imports.into_iter().map(|(src, names)| {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers: names
+                        .into_iter()
+                        .map(|(imported, local)| {
+                            ImportSpecifier::Specific(ImportSpecific {
+                                span: DUMMY_SP,
+                                local,
+                                imported: Some(quote_ident!(imported)),
+                            })
+                        })
+                        .collect(),
+                    src: quote_str!(src),
+                    type_only: false,
+                }))
+            }),
+        );
+
+        module
+    }
+}
+
+impl Fold<Script> for Jsx {
+    fn fold(&mut self, script: Script) -> Script {
+        let pos = script.body.first().map(|stmt| stmt.span().lo());
+        let mut script = self.with_pragmas(pos, |jsx| script.fold_children(jsx));
+
+        // Scripts can't import, so `var _jsx = require("react/jsx-runtime").jsx;`
+        // is used instead.
+        let imports = mem::replace(&mut self.imports, vec![]);
+        prepend_stmts(
+            &mut script.body,
+            imports.into_iter().map(|(src, names)| {
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: names
+                        .into_iter()
+                        .map(|(imported, local)| VarDeclarator {
+                            span: DUMMY_SP,
+                            name: Pat::Ident(local),
+                            init: Some(box Expr::Call(CallExpr {
+                                span: DUMMY_SP,
+                                callee: quote_ident!("require").as_callee(),
+                                args: vec![Lit::Str(quote_str!(src.clone())).as_arg()],
+                                type_args: None,
+                            })
+                            .member(quote_ident!(imported))),
+                            definite: false,
+                        })
+                        .collect(),
+                }))
+            }),
+        );
+
+        script
+    }
+}
+
 impl Jsx {
     fn jsx_name(&self, name: JSXElementName) -> Box<Expr> {
         let span = name.span();
@@ -359,15 +780,22 @@ impl Jsx {
 
 fn attr_to_prop(a: JSXAttr) -> Prop {
     let key = to_prop_name(a.name);
-    let value = a.value.unwrap_or_else(|| {
-        box Expr::Lit(Lit::Bool(Bool {
-            span: key.span(),
-            value: true,
-        }))
-    });
+    let value = attr_value(a.value, key.span());
     Prop::KeyValue(KeyValueProp { key, value })
 }
 
+/// Value of an attribute, which is `true` if omitted.
+fn attr_value(value: Option<Box<Expr>>, span: Span) -> Box<Expr> {
+    value.unwrap_or_else(|| box Expr::Lit(Lit::Bool(Bool { span, value: true })))
+}
+
+fn is_attr_named(a: &JSXAttr, name: &str) -> bool {
+    match a.name {
+        JSXAttrName::Ident(ref i) => &*i.sym == name,
+        JSXAttrName::JSXNamespacedName(..) => false,
+    }
+}
+
 fn to_prop_name(n: JSXAttrName) -> PropName {
     let span = n.span();
 
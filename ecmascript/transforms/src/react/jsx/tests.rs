@@ -9,7 +9,7 @@ use crate::{
 };
 
 fn tr(options: Options) -> impl Fold<Module> {
-    chain!(jsx(options), display_name(), Classes, arrow(),)
+    chain!(jsx(options), display_name(), Classes::default(), arrow(),)
 }
 
 test!(
@@ -1065,3 +1065,182 @@ test!(
 var _react = _interopRequireDefault(require('react'));
 _react.default.createElement('div', null);"
 );
+
+fn automatic(options: Options) -> impl Fold<Module> {
+    jsx(Options {
+        runtime: Runtime::Automatic,
+        ..options
+    })
+}
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| automatic(Default::default()),
+    automatic_runtime,
+    r#"
+var x = <div className="a" key="k">foo</div>;
+var y = <><span />{a}{b}</>;
+"#,
+    r#"
+import { jsx as _jsx, Fragment as _Fragment, jsxs as _jsxs } from "react/jsx-runtime";
+var x = _jsx("div", { className: "a", children: "foo" }, "k");
+var y = _jsxs(_Fragment, { children: [_jsx("span", {}), a, b] });
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| automatic(Default::default()),
+    automatic_runtime_key_after_spread,
+    r#"
+var x = <div key="k" {...props} a="b" />;
+var y = <div {...props} key="k" />;
+"#,
+    r#"
+import { jsx as _jsx } from "react/jsx-runtime";
+import { createElement as _createElement } from "react";
+var x = _jsx("div", { ...props, a: "b" }, "k");
+var y = _createElement("div", _extends({}, props, { key: "k" }));
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| automatic(Options {
+        development: true,
+        ..Default::default()
+    }),
+    automatic_runtime_development,
+    r#"
+var x = <div __self={this} __source={{ fileName: "a.js" }}>{a}{b}</div>;
+var y = <div key="k" />;
+"#,
+    r#"
+import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+var x = _jsxDEV("div", { children: [a, b] }, void 0, true, { fileName: "a.js" }, this);
+var y = _jsxDEV("div", {}, "k", false);
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| automatic(Options {
+        import_source: "preact".into(),
+        ..Default::default()
+    }),
+    automatic_runtime_import_source,
+    "var x = <div />;",
+    r#"
+import { jsx as _jsx } from "preact/jsx-runtime";
+var x = _jsx("div", {});
+"#
+);
+
+#[test]
+fn pragma_comments() {
+    let syntax = ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+    let automatic = Options {
+        runtime: Runtime::Automatic,
+        ..Default::default()
+    };
+    let cases = vec![
+        ("/** @jsx h */\n<div />;", Options::default(), "h('div', null);"),
+        (
+            "/**\n * @jsx h\n * @jsxFrag Fragment\n */\n<></>;",
+            automatic.clone(),
+            "h(Fragment, null);",
+        ),
+        (
+            "/** @jsxImportSource preact */\n<div />;",
+            Options::default(),
+            "import { jsx as _jsx } from 'preact/jsx-runtime';\n_jsx('div', {});",
+        ),
+        (
+            "/** @jsxRuntime classic */\n<div />;",
+            automatic,
+            "React.createElement('div', null);",
+        ),
+    ];
+
+    for (input, options, expected) in cases {
+        crate::tests::Tester::run(|tester| {
+            let module = tester.with_parser("input.js", syntax, input, |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+            })?;
+            let actual = COMMENTS
+                .set(&tester.comments, || module.fold_with(&mut jsx(options)))
+                .fold_with(&mut ::testing::DropSpan);
+            let expected =
+                tester.apply_transform(::testing::DropSpan, "output.js", syntax, expected)?;
+
+            assert_eq!(tester.print(&actual), tester.print(&expected), "{}", input);
+            Ok(())
+        });
+    }
+}
+
+#[test]
+fn invalid_pragma_comment() {
+    let syntax = ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+
+    crate::tests::Tester::run(|tester| {
+        let module = tester.with_parser("input.js", syntax, "/** @jsx h( */\n<div />;", |p| {
+            p.parse_module().map_err(|mut e| {
+                e.emit();
+                ()
+            })
+        })?;
+        let actual = COMMENTS
+            .set(&tester.comments, || module.fold_with(&mut jsx(Default::default())))
+            .fold_with(&mut ::testing::DropSpan);
+        let expected = tester.apply_transform(
+            ::testing::DropSpan,
+            "output.js",
+            syntax,
+            "React.createElement('div', null);",
+        )?;
+
+        // The configured pragma is used instead.
+        assert!(tester.handler.has_errors());
+        assert_eq!(tester.print(&actual), tester.print(&expected));
+        Ok(())
+    });
+}
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| automatic(Options {
+        throw_if_namespace: false,
+        ..Default::default()
+    }),
+    automatic_runtime_namespace,
+    r#"var x = <svg:rect xlink:href="a" />;"#,
+    r#"
+import { jsx as _jsx } from "react/jsx-runtime";
+var x = _jsx("svg:rect", { "xlink:href": "a" });
+"#
+);
@@ -1,28 +1,47 @@
 pub use self::{
+    constant_elements::constant_elements,
     display_name::display_name,
-    jsx::{jsx, Options},
+    jsx::{jsx, Options, Runtime},
     jsx_self::jsx_self,
     jsx_src::jsx_src,
+    refresh::refresh,
+};
+use crate::{
+    pass::{Optional, Pass},
+    resolver::resolver,
 };
-use crate::pass::Pass;
 use std::sync::Arc;
 use swc_common::SourceMap;
 
+mod constant_elements;
 mod display_name;
 mod jsx;
 mod jsx_self;
 mod jsx_src;
+mod refresh;
 
 /// `@babel/preset-react`
 ///
 /// Preset for all React plugins.
 pub fn react(cm: Arc<SourceMap>, options: Options) -> impl Pass {
-    let Options { development, .. } = options;
+    let Options {
+        development,
+        refresh: refresh_enabled,
+        constant_elements: constant_elements_enabled,
+        ..
+    } = options;
 
+    // `__source` and `__self` are added before jsx elements are converted, and
+    // elements using `__self` are not hoisted as they reference `this`.
     chain!(
-        jsx(options),
-        display_name(),
+        refresh(refresh_enabled, cm.clone()),
         jsx_src(development, cm),
-        jsx_self(development)
+        jsx_self(development),
+        Optional::new(
+            chain!(resolver(), constant_elements()),
+            constant_elements_enabled
+        ),
+        jsx(options),
+        display_name()
     )
 }
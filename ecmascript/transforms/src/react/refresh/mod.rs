@@ -0,0 +1,870 @@
+use crate::{
+    pass::Pass,
+    util::{prepend_stmts, undefined, DestructuringFinder, ExprFactory},
+};
+use ast::*;
+use std::{collections::HashSet, mem, sync::Arc};
+use swc_atoms::JsWord;
+use swc_common::{Fold, FoldWith, Mark, SourceMap, Span, Spanned, Visit, VisitWith, DUMMY_SP};
+
+#[cfg(test)]
+mod tests;
+
+/// `react-refresh/babel`
+///
+/// Registers components with `$RefreshReg$` and hook signatures with
+/// `$RefreshSig$` so that React Fast Refresh can preserve state on edits.
+///
+/// Unlike the babel plugin, signatures are not hashed.
+pub fn refresh(enabled: bool, cm: Arc<SourceMap>) -> impl Pass {
+    Refresh {
+        enabled,
+        cm,
+        mark: Mark::fresh(Mark::root()),
+        registrations: vec![],
+        sig_cnt: 0,
+        scopes: vec![],
+        after: vec![],
+    }
+}
+
+struct Refresh {
+    enabled: bool,
+    cm: Arc<SourceMap>,
+    /// Mark applied to `_c` and `_s`.
+    mark: Mark,
+    /// `(handle, persistent id)`
+    registrations: Vec<(Ident, String)>,
+    sig_cnt: usize,
+    scopes: Vec<Scope>,
+    /// Statements to insert after the statement being folded.
+    after: Vec<Stmt>,
+}
+
+#[derive(Default)]
+struct Scope {
+    bindings: HashSet<JsWord>,
+    /// `_s` of functions in this scope.
+    sigs: Vec<Ident>,
+}
+
+struct Signature {
+    key: String,
+    custom_hooks: Vec<Box<Expr>>,
+}
+
+impl Refresh {
+    fn source(&self, span: Span) -> String {
+        self.cm.span_to_snippet(span).unwrap_or_default()
+    }
+
+    fn handle(&self, prefix: &str, cnt: usize) -> Ident {
+        let sym: JsWord = if cnt == 1 {
+            prefix.into()
+        } else {
+            format!("{}{}", prefix, cnt).into()
+        };
+
+        Ident::new(sym, DUMMY_SP.apply_mark(self.mark))
+    }
+
+    /// Creates `_c` for `$RefreshReg$(_c, id)`.
+    fn register(&mut self, id: String) -> Ident {
+        let handle = self.handle("_c", self.registrations.len() + 1);
+        self.registrations.push((handle.clone(), id));
+        handle
+    }
+
+    fn is_bound(&self, sym: &JsWord) -> bool {
+        self.scopes.iter().any(|scope| scope.bindings.contains(sym))
+    }
+
+    fn with_scope<F, T>(&mut self, bindings: HashSet<JsWord>, op: F) -> (T, Vec<Ident>)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        self.scopes.push(Scope {
+            bindings,
+            ..Default::default()
+        });
+        let ret = op(self);
+        let scope = self.scopes.pop().unwrap();
+
+        (ret, scope.sigs)
+    }
+
+    fn signature<N>(&self, body: &N) -> Option<Signature>
+    where
+        N: for<'a> VisitWith<HookCollector<'a>>,
+    {
+        let mut collector = HookCollector {
+            cm: &self.cm,
+            calls: vec![],
+        };
+        body.visit_with(&mut collector);
+
+        if collector.calls.is_empty() {
+            return None;
+        }
+
+        let key = collector
+            .calls
+            .iter()
+            .map(|call| format!("{}{{{}}}", call.name, call.key))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let custom_hooks = collector
+            .calls
+            .into_iter()
+            .filter(|call| !is_builtin_hook(&call.name))
+            .map(|call| call.callee)
+            .collect();
+
+        Some(Signature { key, custom_hooks })
+    }
+
+    /// Declares `_s` in the current scope.
+    fn sig_ident(&mut self) -> Ident {
+        self.sig_cnt += 1;
+        let ident = self.handle("_s", self.sig_cnt);
+        self.scopes
+            .last_mut()
+            .expect("refresh: function outside of scope")
+            .sigs
+            .push(ident.clone());
+        ident
+    }
+
+    /// Inserts `_s()` into a function using hooks.
+    fn sign_fn(&mut self, body: &mut Option<BlockStmt>) -> Option<(Ident, Signature)> {
+        let sig = self.signature(&*body)?;
+        let sig_ident = self.sig_ident();
+
+        if let Some(body) = body {
+            body.stmts.insert(0, Stmt::Expr(box call(sig_ident.clone(), vec![])));
+        }
+
+        Some((sig_ident, sig))
+    }
+
+    /// Inserts `_s()` into an arrow function using hooks.
+    fn sign_arrow(&mut self, body: &mut BlockStmtOrExpr) -> Option<(Ident, Signature)> {
+        let sig = self.signature(&*body)?;
+        let sig_ident = self.sig_ident();
+
+        let stmt = Stmt::Expr(box call(sig_ident.clone(), vec![]));
+        match body {
+            BlockStmtOrExpr::BlockStmt(block) => block.stmts.insert(0, stmt),
+            BlockStmtOrExpr::Expr(expr) => {
+                let expr = mem::replace(expr, undefined(DUMMY_SP));
+                *body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: expr.span(),
+                    stmts: vec![
+                        stmt,
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(expr),
+                        }),
+                    ],
+                })
+            }
+        }
+
+        Some((sig_ident, sig))
+    }
+
+    /// `_s(target, "useState{[foo, setFoo](0)}", forceReset, () => [useFoo])`
+    fn sig_call(&self, sig_ident: Ident, target: Expr, sig: Signature) -> Expr {
+        let mut force_reset = false;
+        let mut hooks = vec![];
+        for callee in sig.custom_hooks {
+            let bound = match *callee {
+                Expr::Ident(ref i) => self.is_bound(&i.sym),
+                Expr::Member(MemberExpr {
+                    obj: ExprOrSuper::Expr(box Expr::Ident(ref i)),
+                    ..
+                }) => self.is_bound(&i.sym),
+                _ => false,
+            };
+
+            if bound {
+                hooks.push(Some(ExprOrSpread {
+                    expr: callee,
+                    spread: None,
+                }));
+            } else {
+                // The hook may have been edited, so the component is remounted.
+                force_reset = true;
+            }
+        }
+
+        let key = Str {
+            span: DUMMY_SP,
+            has_escape: sig.key.contains('\n'),
+            value: sig.key.into(),
+        };
+        let mut args = vec![target.as_arg(), key.as_arg()];
+        if force_reset || !hooks.is_empty() {
+            args.push(
+                Lit::Bool(Bool {
+                    span: DUMMY_SP,
+                    value: force_reset,
+                })
+                .as_arg(),
+            );
+        }
+        if !hooks.is_empty() {
+            args.push(
+                FnExpr {
+                    ident: None,
+                    function: Function {
+                        span: DUMMY_SP,
+                        params: vec![],
+                        decorators: vec![],
+                        body: Some(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts: vec![Stmt::Return(ReturnStmt {
+                                span: DUMMY_SP,
+                                arg: Some(box Expr::Array(ArrayLit {
+                                    span: DUMMY_SP,
+                                    elems: hooks,
+                                })),
+                            })],
+                        }),
+                        is_generator: false,
+                        is_async: false,
+                        type_params: None,
+                        return_type: None,
+                    },
+                }
+                .as_arg(),
+            );
+        }
+
+        call(sig_ident, args)
+    }
+
+    /// Registers components declared by a top-level declaration, and returns
+    /// `_c = Foo;` to insert after it.
+    fn register_decl(&mut self, decl: &mut Decl) -> Option<Stmt> {
+        let name = match decl {
+            Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => ident.clone(),
+            Decl::Var(VarDecl { decls, .. }) if decls.len() == 1 => {
+                return self.register_var(&mut decls[0]);
+            }
+            _ => return None,
+        };
+        if !is_componentish(&name.sym) {
+            return None;
+        }
+
+        let handle = self.register(name.sym.to_string());
+        Some(Stmt::Expr(box assign(handle, box Expr::Ident(name))))
+    }
+
+    /// `const Foo = () => {}` or `const Foo = hoc(() => {})`
+    fn register_var(&mut self, decl: &mut VarDeclarator) -> Option<Stmt> {
+        let name = match decl.name {
+            Pat::Ident(ref i) if is_componentish(&i.sym) => i.clone(),
+            _ => return None,
+        };
+        let init = decl.init.as_mut()?;
+        match **init {
+            Expr::Arrow(..) | Expr::Fn(..) => {}
+            Expr::Call(CallExpr {
+                callee: ExprOrSuper::Expr(ref callee),
+                ..
+            }) => match **callee {
+                Expr::Ident(ref i)
+                    if i.sym.starts_with("require") || i.sym == js_word!("import") =>
+                {
+                    return None
+                }
+                Expr::Member(MemberExpr {
+                    obj: ExprOrSuper::Expr(box Expr::Ident(ref i)),
+                    ..
+                }) if i.sym.starts_with("require") => return None,
+                _ => {}
+            },
+            _ => return None,
+        }
+
+        // Assigning the handle after declaration preserves the inferred name.
+        let handle = self.find_inner_components(name.sym.to_string(), init)??;
+        Some(Stmt::Expr(box assign(handle, box Expr::Ident(name))))
+    }
+
+    /// Returns `None` if `expr` is not a component, and the handle if `expr`
+    /// itself should be registered.
+    ///
+    /// Components passed to higher order components are registered as
+    /// `Foo$memo`.
+    fn find_inner_components(
+        &mut self,
+        name: String,
+        expr: &mut Box<Expr>,
+    ) -> Option<Option<Ident>> {
+        match **expr {
+            // hoc(Foo)
+            Expr::Ident(ref i) => {
+                if is_componentish(&i.sym) {
+                    Some(None)
+                } else {
+                    None
+                }
+            }
+            // A higher order function
+            Expr::Arrow(ArrowExpr {
+                body: BlockStmtOrExpr::Expr(box Expr::Arrow(..)),
+                ..
+            }) => None,
+            Expr::Arrow(..) | Expr::Fn(..) => Some(Some(self.register(name))),
+            Expr::Call(ref mut call) => {
+                let callee = match call.callee {
+                    ExprOrSuper::Expr(box ref e @ Expr::Ident(..))
+                    | ExprOrSuper::Expr(box ref e @ Expr::Member(..)) => e.span(),
+                    _ => return None,
+                };
+                let inner = format!("{}${}", name, self.source(callee));
+                let arg = &mut call.args.first_mut()?.expr;
+
+                if let Some(handle) = self.find_inner_components(inner, arg)? {
+                    let target = mem::replace(arg, undefined(DUMMY_SP));
+                    *arg = box assign(handle, target);
+                }
+
+                Some(Some(self.register(name)))
+            }
+            _ => None,
+        }
+    }
+
+    fn fold_top_level<T>(&mut self, items: Vec<T>) -> Vec<T>
+    where
+        T: StmtLike + Register + FoldWith<Self>,
+    {
+        let mut buf = Vec::with_capacity(items.len());
+
+        for mut item in items {
+            let registration = item.register(self);
+            let item = item.fold_with(self);
+
+            buf.push(item);
+            buf.extend(self.after.drain(..).map(T::from_stmt));
+            buf.extend(registration.map(T::from_stmt));
+        }
+
+        buf
+    }
+
+    /// `var _c, _c2; $RefreshReg$(_c, "Foo"); $RefreshReg$(_c2, "Bar");`
+    fn registration_stmts(&mut self) -> Vec<Stmt> {
+        if self.registrations.is_empty() {
+            return vec![];
+        }
+
+        let registrations = mem::replace(&mut self.registrations, vec![]);
+        let mut stmts = vec![Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: registrations
+                .iter()
+                .map(|(handle, _)| VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(handle.clone()),
+                    init: None,
+                    definite: false,
+                })
+                .collect(),
+        }))];
+        stmts.extend(registrations.into_iter().map(|(handle, id)| {
+            Stmt::Expr(box call(
+                quote_ident!("$RefreshReg$"),
+                vec![handle.as_arg(), quote_str!(id).as_arg()],
+            ))
+        }));
+
+        stmts
+    }
+}
+
+/// `var _s = $RefreshSig$(), _s2 = $RefreshSig$();`
+fn sig_decl(sigs: Vec<Ident>) -> Option<Stmt> {
+    if sigs.is_empty() {
+        return None;
+    }
+
+    Some(Stmt::Decl(Decl::Var(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        declare: false,
+        decls: sigs
+            .into_iter()
+            .map(|sig| VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(sig),
+                init: Some(box call(quote_ident!("$RefreshSig$"), vec![])),
+                definite: false,
+            })
+            .collect(),
+    })))
+}
+
+fn call(callee: Ident, args: Vec<ExprOrSpread>) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: callee.as_callee(),
+        args,
+        type_args: None,
+    })
+}
+
+fn assign(handle: Ident, right: Box<Expr>) -> Expr {
+    Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Pat(box Pat::Ident(handle)),
+        right,
+    })
+}
+
+trait Register {
+    fn register(&mut self, refresh: &mut Refresh) -> Option<Stmt>;
+}
+
+impl Register for Stmt {
+    fn register(&mut self, refresh: &mut Refresh) -> Option<Stmt> {
+        match self {
+            Stmt::Decl(decl) => refresh.register_decl(decl),
+            _ => None,
+        }
+    }
+}
+
+impl Register for ModuleItem {
+    fn register(&mut self, refresh: &mut Refresh) -> Option<Stmt> {
+        match self {
+            ModuleItem::Stmt(stmt) => stmt.register(refresh),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                refresh.register_decl(decl)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Fn(FnExpr {
+                    ident: Some(ident), ..
+                }),
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Class(ClassExpr {
+                    ident: Some(ident), ..
+                }),
+                ..
+            })) if is_componentish(&ident.sym) => {
+                let name = ident.clone();
+                let handle = refresh.register(name.sym.to_string());
+                Some(Stmt::Expr(box assign(handle, box Expr::Ident(name))))
+            }
+            // export default hoc(Foo)
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            })) => {
+                match **expr {
+                    Expr::Call(..) => {}
+                    _ => return None,
+                }
+                if let Some(Some(handle)) =
+                    refresh.find_inner_components("%default%".into(), expr)
+                {
+                    let target = mem::replace(expr, undefined(DUMMY_SP));
+                    *expr = box assign(handle, target);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Fold<Module> for Refresh {
+    fn fold(&mut self, module: Module) -> Module {
+        if !self.enabled {
+            return module;
+        }
+
+        let bindings = bindings(&module.body);
+        let body = module.body;
+        let (mut body, sigs) = self.with_scope(bindings, |r| r.fold_top_level(body));
+
+        prepend_stmts(&mut body, sig_decl(sigs).into_iter().map(ModuleItem::Stmt));
+        body.extend(self.registration_stmts().into_iter().map(ModuleItem::Stmt));
+
+        Module { body, ..module }
+    }
+}
+
+impl Fold<Script> for Refresh {
+    fn fold(&mut self, script: Script) -> Script {
+        if !self.enabled {
+            return script;
+        }
+
+        let bindings = bindings(&script.body);
+        let body = script.body;
+        let (mut body, sigs) = self.with_scope(bindings, |r| r.fold_top_level(body));
+
+        prepend_stmts(&mut body, sig_decl(sigs).into_iter());
+        body.extend(self.registration_stmts());
+
+        Script { body, ..script }
+    }
+}
+
+impl Fold<Vec<Stmt>> for Refresh {
+    fn fold(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for stmt in stmts {
+            let old = mem::replace(&mut self.after, vec![]);
+            let stmt = stmt.fold_with(self);
+
+            buf.push(stmt);
+            buf.extend(mem::replace(&mut self.after, old));
+        }
+
+        buf
+    }
+}
+
+impl Fold<Function> for Refresh {
+    fn fold(&mut self, f: Function) -> Function {
+        let mut bindings = bindings(&f.params);
+        bindings.extend(self::bindings(&f.body));
+
+        let (mut f, sigs) = self.with_scope(bindings, |r| f.fold_children(r));
+        if let Some(body) = &mut f.body {
+            prepend_stmts(&mut body.stmts, sig_decl(sigs).into_iter());
+        }
+
+        f
+    }
+}
+
+impl Fold<ArrowExpr> for Refresh {
+    fn fold(&mut self, f: ArrowExpr) -> ArrowExpr {
+        let mut bindings = bindings(&f.params);
+        bindings.extend(self::bindings(&f.body));
+
+        let (mut f, sigs) = self.with_scope(bindings, |r| f.fold_children(r));
+        if let Some(decl) = sig_decl(sigs) {
+            match f.body {
+                BlockStmtOrExpr::BlockStmt(ref mut block) => block.stmts.insert(0, decl),
+                BlockStmtOrExpr::Expr(expr) => {
+                    f.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        span: expr.span(),
+                        stmts: vec![
+                            decl,
+                            Stmt::Return(ReturnStmt {
+                                span: DUMMY_SP,
+                                arg: Some(expr),
+                            }),
+                        ],
+                    })
+                }
+            }
+        }
+
+        f
+    }
+}
+
+impl Fold<FnDecl> for Refresh {
+    fn fold(&mut self, f: FnDecl) -> FnDecl {
+        let mut f = f.fold_children(self);
+
+        if let Some((sig_ident, sig)) = self.sign_fn(&mut f.function.body) {
+            let call = self.sig_call(sig_ident, Expr::Ident(f.ident.clone()), sig);
+            self.after.push(Stmt::Expr(box call));
+        }
+
+        f
+    }
+}
+
+impl Fold<DefaultDecl> for Refresh {
+    fn fold(&mut self, decl: DefaultDecl) -> DefaultDecl {
+        let decl = decl.fold_children(self);
+
+        match decl {
+            // Anonymous functions are not signed.
+            DefaultDecl::Fn(FnExpr {
+                ident: Some(ident),
+                mut function,
+            }) => {
+                if let Some((sig_ident, sig)) = self.sign_fn(&mut function.body) {
+                    let call = self.sig_call(sig_ident, Expr::Ident(ident.clone()), sig);
+                    self.after.push(Stmt::Expr(box call));
+                }
+
+                DefaultDecl::Fn(FnExpr {
+                    ident: Some(ident),
+                    function,
+                })
+            }
+            _ => decl,
+        }
+    }
+}
+
+impl Fold<VarDeclarator> for Refresh {
+    fn fold(&mut self, decl: VarDeclarator) -> VarDeclarator {
+        let name = match decl.name {
+            Pat::Ident(ref i) => i.clone(),
+            _ => return decl.fold_children(self),
+        };
+
+        // `_s(Foo, ...)` is called after declaration to preserve the inferred
+        // name of `Foo`.
+        let (init, signed) = match decl.init {
+            Some(box Expr::Arrow(f)) => {
+                let mut f = f.fold_with(self);
+                let signed = self.sign_arrow(&mut f.body);
+                (Some(box Expr::Arrow(f)), signed)
+            }
+            Some(box Expr::Fn(f)) => {
+                let mut f = f.fold_with(self);
+                let signed = self.sign_fn(&mut f.function.body);
+                (Some(box Expr::Fn(f)), signed)
+            }
+            init => (init.fold_with(self), None),
+        };
+
+        if let Some((sig_ident, sig)) = signed {
+            let call = self.sig_call(sig_ident, Expr::Ident(name), sig);
+            self.after.push(Stmt::Expr(box call));
+        }
+
+        VarDeclarator {
+            name: decl.name.fold_with(self),
+            init,
+            ..decl
+        }
+    }
+}
+
+impl Fold<Expr> for Refresh {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Arrow(mut f) => match self.sign_arrow(&mut f.body) {
+                Some((sig_ident, sig)) => self.sig_call(sig_ident, Expr::Arrow(f), sig),
+                None => Expr::Arrow(f),
+            },
+            Expr::Fn(mut f) => match self.sign_fn(&mut f.function.body) {
+                Some((sig_ident, sig)) => self.sig_call(sig_ident, Expr::Fn(f), sig),
+                None => Expr::Fn(f),
+            },
+            _ => e,
+        }
+    }
+}
+
+fn is_componentish(sym: &str) -> bool {
+    sym.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+fn is_hook_name(sym: &str) -> bool {
+    sym.starts_with("use") && is_componentish(&sym[3..])
+}
+
+fn is_builtin_hook(sym: &str) -> bool {
+    match sym {
+        "useState" | "useReducer" | "useEffect" | "useLayoutEffect" | "useMemo"
+        | "useCallback" | "useRef" | "useContext" | "useImperativeHandle"
+        | "useDebugValue" => true,
+        _ => false,
+    }
+}
+
+/// `useFoo()` or `React.useFoo()`
+fn hook_name(call: &CallExpr) -> Option<&JsWord> {
+    let name = match call.callee {
+        ExprOrSuper::Expr(box Expr::Ident(ref i)) => &i.sym,
+        ExprOrSuper::Expr(box Expr::Member(MemberExpr {
+            prop: box Expr::Ident(ref i),
+            computed: false,
+            ..
+        })) => &i.sym,
+        _ => return None,
+    };
+
+    if is_hook_name(name) {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+struct HookCall {
+    callee: Box<Expr>,
+    name: JsWord,
+    key: String,
+}
+
+/// Collects hook calls of a function, excluding nested functions.
+struct HookCollector<'a> {
+    cm: &'a SourceMap,
+    calls: Vec<HookCall>,
+}
+
+impl<'a> HookCollector<'a> {
+    /// `binding` is the pattern the result of the hook is assigned to.
+    fn record(&mut self, call: &CallExpr, binding: Option<Span>) {
+        let name = match hook_name(call) {
+            Some(name) => name.clone(),
+            None => return,
+        };
+        let callee = match call.callee {
+            ExprOrSuper::Expr(ref callee) => callee.clone(),
+            _ => unreachable!(),
+        };
+
+        let source = |span: Span| self.cm.span_to_snippet(span).unwrap_or_default();
+        let mut key = binding.map(&source).unwrap_or_default();
+        let arg = match &*name {
+            "useState" => call.args.get(0),
+            "useReducer" => call.args.get(1),
+            _ => None,
+        };
+        if let Some(arg) = arg {
+            key.push('(');
+            key.push_str(&source(arg.expr.span()));
+            key.push(')');
+        }
+
+        self.calls.push(HookCall { callee, name, key })
+    }
+}
+
+impl<'a> Visit<VarDeclarator> for HookCollector<'a> {
+    fn visit(&mut self, decl: &VarDeclarator) {
+        decl.name.visit_with(self);
+
+        match decl.init {
+            // const [foo, setFoo] = useState(0)
+            Some(box Expr::Call(ref call)) => {
+                self.record(call, Some(decl.name.span()));
+                call.visit_children(self);
+            }
+            _ => decl.init.visit_with(self),
+        }
+    }
+}
+
+impl<'a> Visit<CallExpr> for HookCollector<'a> {
+    fn visit(&mut self, call: &CallExpr) {
+        self.record(call, None);
+        call.visit_children(self);
+    }
+}
+
+macro_rules! noop {
+    ($T:path) => {
+        impl<'a> Visit<$T> for HookCollector<'a> {
+            fn visit(&mut self, _: &$T) {}
+        }
+    };
+
+    ($T:path, $($rest:tt)*) => {
+        noop!($T);
+        noop!($($rest)*);
+    };
+}
+
+noop!(Function, ArrowExpr, Constructor);
+
+/// Names declared in a scope.
+fn bindings<N>(node: &N) -> HashSet<JsWord>
+where
+    N: for<'a> VisitWith<BindingCollector<'a>>,
+{
+    let mut found = vec![];
+    node.visit_with(&mut BindingCollector { found: &mut found });
+
+    found.into_iter().map(|(sym, _)| sym).collect()
+}
+
+struct BindingCollector<'a> {
+    found: &'a mut Vec<(JsWord, Span)>,
+}
+
+impl<'a> Visit<Pat> for BindingCollector<'a> {
+    fn visit(&mut self, pat: &Pat) {
+        pat.visit_with(&mut DestructuringFinder {
+            found: &mut *self.found,
+        });
+    }
+}
+
+impl<'a> Visit<Ident> for BindingCollector<'a> {
+    /// Only called for declarations, as expressions are not visited.
+    fn visit(&mut self, i: &Ident) {
+        self.found.push((i.sym.clone(), i.span));
+    }
+}
+
+impl<'a> Visit<VarDeclarator> for BindingCollector<'a> {
+    fn visit(&mut self, decl: &VarDeclarator) {
+        decl.name.visit_with(self);
+    }
+}
+
+impl<'a> Visit<ImportSpecifier> for BindingCollector<'a> {
+    fn visit(&mut self, s: &ImportSpecifier) {
+        match s {
+            ImportSpecifier::Specific(ImportSpecific { local, .. })
+            | ImportSpecifier::Default(ImportDefault { local, .. })
+            | ImportSpecifier::Namespace(ImportStarAs { local, .. }) => local.visit_with(self),
+        }
+    }
+}
+
+impl<'a> Visit<FnExpr> for BindingCollector<'a> {
+    /// `export default function Foo() {}`
+    fn visit(&mut self, f: &FnExpr) {
+        f.ident.visit_with(self);
+    }
+}
+
+impl<'a> Visit<ClassExpr> for BindingCollector<'a> {
+    /// `export default class Foo {}`
+    fn visit(&mut self, c: &ClassExpr) {
+        c.ident.visit_with(self);
+    }
+}
+
+impl<'a> Visit<FnDecl> for BindingCollector<'a> {
+    fn visit(&mut self, f: &FnDecl) {
+        f.ident.visit_with(self);
+    }
+}
+
+impl<'a> Visit<ClassDecl> for BindingCollector<'a> {
+    fn visit(&mut self, c: &ClassDecl) {
+        c.ident.visit_with(self);
+    }
+}
+
+macro_rules! binding_noop {
+    ($($T:path),*) => {
+        $(
+            impl<'a> Visit<$T> for BindingCollector<'a> {
+                fn visit(&mut self, _: &$T) {}
+            }
+        )*
+    };
+}
+
+binding_noop!(Expr, Function, ArrowExpr, NamedExport, BreakStmt, ContinueStmt, TsType);
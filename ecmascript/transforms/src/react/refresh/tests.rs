@@ -0,0 +1,170 @@
+use super::*;
+
+fn tr(tester: &mut crate::tests::Tester) -> impl Fold<Module> {
+    refresh(true, tester.cm.clone())
+}
+
+fn syntax() -> ::swc_ecma_parser::Syntax {
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |tester| tr(tester),
+    function_declaration,
+    r#"
+export default function App() {
+  const [foo, setFoo] = useState(0);
+  React.useEffect(() => {});
+  return <h1>{foo}</h1>;
+}
+"#,
+    r#"
+var _s = $RefreshSig$();
+export default function App() {
+  _s();
+  const [foo, setFoo] = useState(0);
+  React.useEffect(() => {});
+  return <h1>{foo}</h1>;
+}
+_s(App, "useState{[foo, setFoo](0)}\nuseEffect{}");
+_c = App;
+var _c;
+$RefreshReg$(_c, "App");
+"#
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester),
+    arrow_and_class,
+    r#"
+const Hello = () => <div>Hello</div>;
+export class World extends React.Component {}
+"#,
+    r#"
+const Hello = () => <div>Hello</div>;
+_c = Hello;
+export class World extends React.Component {}
+_c2 = World;
+var _c, _c2;
+$RefreshReg$(_c, "Hello");
+$RefreshReg$(_c2, "World");
+"#
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester),
+    hoc,
+    r#"
+const A = memo(() => <div />);
+const B = React.forwardRef(function (props, ref) {
+  return <div ref={ref} />;
+});
+export default memo(C);
+"#,
+    r#"
+const A = memo(_c = () => <div />);
+_c2 = A;
+const B = React.forwardRef(_c3 = function (props, ref) {
+  return <div ref={ref} />;
+});
+_c4 = B;
+export default _c5 = memo(C);
+var _c, _c2, _c3, _c4, _c5;
+$RefreshReg$(_c, "A$memo");
+$RefreshReg$(_c2, "A");
+$RefreshReg$(_c3, "B$React.forwardRef");
+$RefreshReg$(_c4, "B");
+$RefreshReg$(_c5, "%default%");
+"#
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester),
+    hoc_with_hooks,
+    r#"
+export default memo(() => {
+  const [a] = useState(1);
+  return a;
+});
+"#,
+    r#"
+var _s = $RefreshSig$();
+export default _c2 = memo(_c = _s(() => {
+  _s();
+  const [a] = useState(1);
+  return a;
+}, "useState{[a](1)}"));
+var _c, _c2;
+$RefreshReg$(_c, "%default%$memo");
+$RefreshReg$(_c2, "%default%");
+"#
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester),
+    custom_hooks,
+    r#"
+import { useFoo } from './foo';
+export function useBar() {
+  const foo = useFoo();
+  return useState(foo);
+}
+const App = () => {
+  const bar = useBar();
+  useBaz();
+  return <div>{bar}</div>;
+};
+"#,
+    r#"
+var _s = $RefreshSig$(), _s2 = $RefreshSig$();
+import { useFoo } from './foo';
+export function useBar() {
+  _s();
+  const foo = useFoo();
+  return useState(foo);
+}
+_s(useBar, "useFoo{foo}\nuseState{(foo)}", false, function () {
+  return [useFoo];
+});
+const App = () => {
+  _s2();
+  const bar = useBar();
+  useBaz();
+  return <div>{bar}</div>;
+};
+_s2(App, "useBar{bar}\nuseBaz{}", true, function () {
+  return [useBar];
+});
+_c = App;
+var _c;
+$RefreshReg$(_c, "App");
+"#
+);
+
+test!(
+    syntax(),
+    |tester| tr(tester),
+    ignore_non_components,
+    r#"
+function foo() {}
+const bar = 1;
+const Baz = require('baz');
+const Qux = hoc(bar);
+const Quux = () => () => <div />;
+"#,
+    r#"
+function foo() {}
+const bar = 1;
+const Baz = require('baz');
+const Qux = hoc(bar);
+const Quux = () => () => <div />;
+"#
+);
@@ -1017,6 +1017,9 @@ where
             | Expr::TsAs(TsAsExpr { expr, .. })
             | Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => add_effects(v, expr),
             Expr::TsOptChain(e) => add_effects(v, e.expr),
+
+            // We don't know what it was.
+            Expr::Invalid(..) => v.push(box expr),
         }
     }
 
@@ -248,6 +248,38 @@ pub(crate) fn test_transform<F, P>(
     });
 }
 
+/// Returns errors emitted while applying the transform to `input`.
+pub(crate) fn test_transform_errors<F, P>(syntax: Syntax, tr: F, input: &str) -> String
+where
+    F: FnOnce(&mut Tester) -> P,
+    P: Fold<Module>,
+{
+    let out = ::testing::run_test(false, |cm, handler| {
+        crate::util::HANDLER.set(handler, || {
+            HELPERS.set(&Default::default(), || {
+                let mut tester = Tester {
+                    cm,
+                    handler,
+                    comments: Comments::default(),
+                };
+                let tr = make_tr(tr, &mut tester);
+                tester.apply_transform(tr, "input.js", syntax, input)?;
+
+                if handler.has_errors() {
+                    Err(())
+                } else {
+                    Ok(())
+                }
+            })
+        })
+    });
+
+    match out {
+        Ok(()) => panic!("expected an error:\n{}", input),
+        Err(stderr) => stderr.to_string(),
+    }
+}
+
 #[derive(PartialEq, Eq)]
 pub(crate) struct DebugUsingDisplay<'a>(pub &'a str);
 impl<'a> fmt::Debug for DebugUsingDisplay<'a> {
@@ -172,6 +172,8 @@ impl Fold<Vec<ClassMember>> for Strip {
                 function: Function { body: None, .. },
                 ..
             }) => None,
+            // `declare foo: string;` does not exist at runtime.
+            ClassMember::ClassProp(ClassProp { declare: true, .. }) => None,
 
             _ => Some(member),
         })
@@ -287,17 +289,24 @@ impl Fold<Vec<ModuleItem>> for Strip {
             ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(mut export)) => {
                 // if specifier become empty, we remove export statement.
 
-                export.specifiers.retain(|s| match *s {
-                    ExportSpecifier::Named(NamedExportSpecifier { ref orig, .. }) => {
-                        if let Some(e) = self.scope.decls.get(&(orig.sym.clone(), orig.span.ctxt()))
-                        {
-                            e.has_concrete
-                        } else {
-                            return true;
+                // `export { Foo } from 'foo'` does not refer to local bindings.
+                if export.src.is_none() {
+                    let scope = &self.scope;
+                    export.specifiers.retain(|s| match *s {
+                        ExportSpecifier::Named(NamedExportSpecifier { ref orig, .. }) => {
+                            let id = (orig.sym.clone(), orig.span.ctxt());
+                            if let Some(e) = scope.decls.get(&id) {
+                                e.has_concrete
+                            } else if let Some(e) = scope.imported_idents.get(&id) {
+                                // Type-only imports are removed.
+                                !e.has_type || e.has_concrete
+                            } else {
+                                true
+                            }
                         }
-                    }
-                    _ => true,
-                });
+                        _ => true,
+                    });
+                }
                 if export.specifiers.is_empty() {
                     return None;
                 }
@@ -361,6 +370,14 @@ impl Fold<ImportDecl> for Strip {
     }
 }
 
+impl Fold<NamedExport> for Strip {
+    /// `export { Foo }` is not an usage of `Foo`, which is required to find
+    /// type-only imports.
+    fn fold(&mut self, export: NamedExport) -> NamedExport {
+        export
+    }
+}
+
 impl Fold<Ident> for Strip {
     fn fold(&mut self, i: Ident) -> Ident {
         self.scope
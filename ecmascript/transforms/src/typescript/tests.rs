@@ -40,6 +40,15 @@ to!(
 }"
 );
 
+to!(
+    declare_field,
+    "class Foo {
+    declare foo: string;
+}",
+    "class Foo {
+}"
+);
+
 to!(export_import, "export import A = B", "export { B as A }");
 
 to!(export_equals, "export = Foo", "export default Foo");
@@ -174,3 +183,27 @@ const dict: PlainObject = {};
 import 'simplytyped';
 const dict = {};"
 );
+
+to!(
+    export_type_only_import,
+    "import { Props, Foo } from './props';
+let p: Props;
+export { Props, Foo };",
+    "import { Foo } from './props';
+let p;
+export { Foo };"
+);
+
+to!(
+    export_interface,
+    "interface Props {}
+export { Props };",
+    ""
+);
+
+to!(
+    export_from_keeps_local_type,
+    "interface Props {}
+export { Props } from './props';",
+    "export { Props } from './props';"
+);
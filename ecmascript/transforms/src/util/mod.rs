@@ -19,7 +19,8 @@ use std::{
 };
 use swc_atoms::JsWord;
 use swc_common::{
-    errors::Handler, Fold, FoldWith, Mark, Span, Spanned, Visit, VisitWith, DUMMY_SP,
+    comments::Comments, errors::Handler, Fold, FoldWith, Mark, Span, Spanned, Visit, VisitWith,
+    DUMMY_SP,
 };
 use unicode_xid::UnicodeXID;
 
@@ -652,6 +653,8 @@ pub trait ExprExt {
             | Expr::TsTypeAssertion(TsTypeAssertion { ref expr, .. })
             | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. }) => expr.may_have_side_effects(),
             Expr::TsOptChain(ref e) => e.expr.may_have_side_effects(),
+
+            Expr::Invalid(..) => true,
         }
     }
 }
@@ -1020,3 +1023,8 @@ impl<'a> UsageFinder<'a> {
 }
 
 scoped_thread_local!(pub static HANDLER: Handler);
+
+scoped_thread_local!(
+    /// Comments of the file being transformed.
+    pub static COMMENTS: Comments
+);
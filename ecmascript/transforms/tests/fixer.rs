@@ -179,7 +179,7 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                         );
 
                         let mut emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: Default::default(),
                             cm: cm.clone(),
                             wr: box swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm.clone(),
@@ -192,7 +192,7 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                             pos_of_leading_comments: Default::default(),
                         };
                         let mut expected_emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: Default::default(),
                             cm: cm.clone(),
                             wr: box swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm.clone(),
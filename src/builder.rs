@@ -1,10 +1,14 @@
-use crate::config::{GlobalPassOption, JscTarget, ModuleConfig};
+use crate::config::{
+    env::{self, Features},
+    GlobalPassOption, JscTarget, ModuleConfig,
+};
 use atoms::JsWord;
 use common::{errors::Handler, SourceMap};
 use ecmascript::{
     ast::Module,
     transforms::{
         chain_at, compat, const_modules, fixer, helpers, hygiene, modules,
+        modules::top_level_await::{wrap_top_level_await, TopLevelAwait},
         pass::{JoinedPass, Optional, Pass},
         typescript,
     },
@@ -18,6 +22,9 @@ pub struct PassBuilder<'a, 'b, P: Pass> {
     handler: &'b Handler,
     pass: P,
     target: JscTarget,
+    /// Compat passes selected by `jsc.env`.
+    env: Option<Features>,
+    loose: bool,
 }
 
 impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
@@ -27,6 +34,8 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             handler,
             pass,
             target: JscTarget::Es5,
+            env: None,
+            loose: false,
         }
     }
 
@@ -37,6 +46,8 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             handler: self.handler,
             pass,
             target: self.target,
+            env: self.env,
+            loose: self.loose,
         }
     }
 
@@ -61,6 +72,21 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
         self
     }
 
+    /// Enables only compat passes in `features`.
+    ///
+    /// This overrides `target`.
+    pub fn env(mut self, features: Features) -> Self {
+        self.env = Some(features);
+        self
+    }
+
+    /// Makes compat passes generate smaller code which does not follow the
+    /// spec strictly.
+    pub fn loose(mut self, loose: bool) -> Self {
+        self.loose = loose;
+        self
+    }
+
     /// # Arguments
     /// ## module
     ///  - Use `None` if you want swc to emit import statements.
@@ -78,18 +104,36 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             Some(ModuleConfig::CommonJs(ref c)) => !c.no_interop,
             Some(ModuleConfig::Amd(ref c)) => !c.config.no_interop,
             Some(ModuleConfig::Umd(ref c)) => !c.config.no_interop,
-            None => false,
+            Some(ModuleConfig::Es6(..)) | None => false,
+        };
+        // The async function should be lowered by compat passes.
+        let wrap_tla = match module {
+            Some(ModuleConfig::CommonJs(ref c)) => c.top_level_await == TopLevelAwait::Wrap,
+            Some(ModuleConfig::Amd(ref c)) => c.config.top_level_await == TopLevelAwait::Wrap,
+            Some(ModuleConfig::Umd(ref c)) => c.config.top_level_await == TopLevelAwait::Wrap,
+            Some(ModuleConfig::Es6(..)) | None => false,
+        };
+
+        let enable_env = self.env.is_some();
+        let target = if enable_env {
+            JscTarget::Es2019
+        } else {
+            self.target
         };
+        let features = self.env.unwrap_or_default();
+        let compat_config = compat::es2015::Config { loose: self.loose };
 
         chain_at!(
             Module,
             self.pass,
+            Optional::new(wrap_top_level_await(), wrap_tla),
             // compat
-            Optional::new(compat::es2018(), self.target <= JscTarget::Es2018),
-            Optional::new(compat::es2017(), self.target <= JscTarget::Es2017),
-            Optional::new(compat::es2016(), self.target <= JscTarget::Es2016),
-            Optional::new(compat::es2015(), self.target <= JscTarget::Es2015),
-            Optional::new(compat::es3(), self.target <= JscTarget::Es3),
+            Optional::new(compat::es2018(), target <= JscTarget::Es2018),
+            Optional::new(compat::es2017(), target <= JscTarget::Es2017),
+            Optional::new(compat::es2016(), target <= JscTarget::Es2016),
+            Optional::new(compat::es2015(compat_config), target <= JscTarget::Es2015),
+            Optional::new(compat::es3(), target <= JscTarget::Es3),
+            Optional::new(env::compat(&features, compat_config), enable_env),
             // module / helper
             Optional::new(
                 modules::import_analysis::import_analyzer(),
@@ -0,0 +1,437 @@
+//! `@babel/preset-env`-like selection of compat passes.
+//!
+//! Compat passes are enabled only if at least one of `jsc.env.targets` does
+//! not support the feature natively.
+use crate::error::Error;
+use atoms::JsWord;
+use ecmascript::{
+    ast::{Expr, Module},
+    transforms::{
+        chain_at,
+        compat::{es2015, es2016, es2017, es2018, es3},
+        pass::{Optional, Pass},
+        polyfills::{self, Mode},
+    },
+};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// `jsc.env`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct EnvConfig {
+    /// Environments to support.
+    ///
+    /// If this is `None`, all compat passes are enabled.
+    #[serde(default)]
+    pub targets: Option<Targets>,
+
+    /// Prints enabled passes and targets which requires them.
+    #[serde(default)]
+    pub debug: bool,
+
+    /// If set, imports of core-js@3 modules required by targets are injected.
+    #[serde(default)]
+    pub mode: Option<Mode>,
+}
+
+/// e.g. `{ "chrome": "58", "ios": "10" }` or `"chrome >= 58, ios 10"`
+///
+/// Only a subset of browserslist queries is supported:
+///
+///  - `<browser> <version>` (e.g. `chrome 58`)
+///  - `<browser> >= <version>` (e.g. `ios >= 10`)
+///
+/// Queries are combined with `,` or ` or `, and `<browser>` accepts the names
+/// and aliases of browserslist (e.g. `ff`, `ios_saf`). Other queries like
+/// `defaults`, `last 2 versions` and `> 0.5%` depend on the usage data of
+/// browserslist and are rejected. Use the output of `npx browserslist` for
+/// them instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Targets {
+    /// A query, e.g. `"chrome 58, ios >= 10"`.
+    Query(String),
+    /// Queries, e.g. `["chrome 58", "ios >= 10"]`.
+    Queries(Vec<String>),
+    /// Map from browser to the minimum version.
+    Versions(BTreeMap<String, String>),
+}
+
+impl Targets {
+    /// Returns minimum version of each browser.
+    fn resolve(&self) -> Result<BTreeMap<JsWord, Version>, Error> {
+        let mut versions = BTreeMap::new();
+        let mut add = |browser: &str, version: &str| -> Result<(), Error> {
+            let browser = normalize_browser(browser)?;
+            let version = Version::parse(version).ok_or_else(|| Error::InvalidTargets {
+                msg: format!("invalid version `{}` for {}", version, browser),
+            })?;
+
+            let v = versions.entry(browser).or_insert(version);
+            if version < *v {
+                *v = version;
+            }
+            Ok(())
+        };
+
+        match *self {
+            Targets::Query(ref q) => parse_query(q, &mut add)?,
+            Targets::Queries(ref qs) => {
+                for q in qs {
+                    parse_query(q, &mut add)?
+                }
+            }
+            Targets::Versions(ref m) => {
+                for (browser, version) in m {
+                    add(browser, version)?
+                }
+            }
+        }
+
+        Ok(versions)
+    }
+}
+
+/// Parses queries like `chrome 58, ios >= 10`.
+fn parse_query(
+    query: &str,
+    add: &mut impl FnMut(&str, &str) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for q in query.split(',').flat_map(|q| q.split(" or ")) {
+        let q = q.trim();
+        if q.is_empty() {
+            continue;
+        }
+
+        let tokens = q.split_whitespace().collect::<Vec<_>>();
+        match &*tokens {
+            [browser, ">=", version] if is_browser_name(browser) => add(*browser, *version)?,
+            [browser, version] if is_browser_name(browser) && version.starts_with(">=") => {
+                add(*browser, &version[2..])?
+            }
+            [browser, version] if is_browser_name(browser) => add(*browser, *version)?,
+            _ => {
+                return Err(Error::InvalidTargets {
+                    msg: format!(
+                        "unsupported query `{}`. Only `<browser> <version>` and `<browser> >= \
+                         <version>` are supported",
+                        q
+                    ),
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Rejects queries like `> 0.5%`.
+fn is_browser_name(s: &str) -> bool {
+    s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Converts browserslist names to names used by compat table.
+fn normalize_browser(name: &str) -> Result<JsWord, Error> {
+    let name = name.to_ascii_lowercase();
+    let name = match &*name {
+        "and_chr" => "chrome",
+        "ff" | "and_ff" => "firefox",
+        "explorer" => "ie",
+        "ios_saf" => "ios",
+        "op_mob" => "opera",
+        _ => &*name,
+    };
+
+    if !DATA.values().any(|browsers| browsers.contains_key(name)) {
+        return Err(Error::InvalidTargets {
+            msg: format!("unknown browser `{}`", name),
+        });
+    }
+
+    Ok(name.into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    /// Parses `10`, `10.3`, `6.5.0` or `10.0-10.2`.
+    ///
+    /// For ranges, the lower bound is used.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.split('-').next()?.trim();
+        let mut parts = s.split('.');
+
+        let major = parts.next()?.parse().ok()?;
+        let minor = match parts.next() {
+            Some(v) => v.parse().ok()?,
+            None => 0,
+        };
+        let patch = match parts.next() {
+            Some(v) => v.parse().ok()?,
+            None => 0,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Version {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+macro_rules! features {
+    ($($name:ident = $s:expr,)*) => {
+        /// A compat pass which can be enabled separately.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum Feature {
+            $($name,)*
+        }
+
+        impl Feature {
+            pub const ALL: &'static [Feature] = &[$(Feature::$name,)*];
+
+            /// Name of the babel plugin, which is used as a key of the compat
+            /// table.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Feature::$name => $s,)*
+                }
+            }
+        }
+    };
+}
+
+features!(
+    // es2018
+    ObjectRestSpread = "proposal-object-rest-spread",
+    OptionalCatchBinding = "proposal-optional-catch-binding",
+    // es2017
+    AsyncToGenerator = "transform-async-to-generator",
+    // es2016
+    Exponentiation = "transform-exponentiation-operator",
+    // es2015
+    BlockScopedFunctions = "transform-block-scoped-functions",
+    TemplateLiterals = "transform-template-literals",
+    Classes = "transform-classes",
+    Spread = "transform-spread",
+    FunctionName = "transform-function-name",
+    ArrowFunctions = "transform-arrow-functions",
+    DuplicateKeys = "transform-duplicate-keys",
+    StickyRegex = "transform-sticky-regex",
+    InstanceOf = "transform-instanceof",
+    TypeOfSymbol = "transform-typeof-symbol",
+    ShorthandProperties = "transform-shorthand-properties",
+    Parameters = "transform-parameters",
+    ForOf = "transform-for-of",
+    ComputedProperties = "transform-computed-properties",
+    Destructuring = "transform-destructuring",
+    BlockScoping = "transform-block-scoping",
+    // es3
+    PropertyLiterals = "transform-property-literals",
+    MemberExpressionLiterals = "transform-member-expression-literals",
+    ReservedWords = "transform-reserved-words",
+);
+
+type CompatTable = BTreeMap<String, BTreeMap<String, Version>>;
+
+lazy_static! {
+    /// Map from a feature to minimum versions of browsers supporting it.
+    ///
+    /// A browser which is not listed does not support the feature.
+    static ref DATA: CompatTable = parse_table(include_str!("data.json"));
+
+    /// Same as `DATA`, but for core-js modules.
+    ///
+    /// A module which is not listed is considered as required by all targets.
+    static ref CORE_JS_DATA: CompatTable = parse_table(include_str!("core_js.json"));
+}
+
+fn parse_table(json: &str) -> CompatTable {
+    let data: BTreeMap<String, BTreeMap<String, String>> =
+        serde_json::from_str(json).expect("invalid compat table");
+
+    data.into_iter()
+        .map(|(feature, browsers)| {
+            let browsers = browsers
+                .into_iter()
+                .map(|(browser, v)| {
+                    let v = Version::parse(&v).unwrap_or_else(|| {
+                        panic!("invalid version in compat table: {} {}", browser, v)
+                    });
+                    (browser, v)
+                })
+                .collect();
+            (feature, browsers)
+        })
+        .collect()
+}
+
+/// Returns targets which does not support the feature.
+fn missing_targets<'a>(
+    targets: &'a BTreeMap<JsWord, Version>,
+    supported: &'a BTreeMap<String, Version>,
+) -> impl Iterator<Item = (&'a JsWord, &'a Version)> {
+    targets
+        .iter()
+        .filter(move |&(browser, version)| match supported.get(&**browser) {
+            Some(min) => version < min,
+            None => true,
+        })
+}
+
+/// Set of compat passes to enable.
+#[derive(Debug, Default, Clone)]
+pub struct Features {
+    enabled: BTreeSet<Feature>,
+}
+
+impl Features {
+    pub fn contains(&self, feature: Feature) -> bool {
+        self.enabled.contains(&feature)
+    }
+}
+
+impl EnvConfig {
+    /// Resolves targets and returns compat passes required for them.
+    pub fn features(&self) -> Result<Features, Error> {
+        let targets = match self.targets {
+            Some(ref targets) => targets.resolve()?,
+            None => Default::default(),
+        };
+
+        if self.debug {
+            eprintln!("swc: `jsc.env.debug` option\n\nUsing targets:");
+            for (browser, version) in &targets {
+                eprintln!("  {} {}", browser, version);
+            }
+            eprintln!("\nUsing passes:");
+        }
+
+        let mut enabled = BTreeSet::new();
+        for &feature in Feature::ALL {
+            let supported = &DATA[feature.name()];
+
+            let missing = missing_targets(&targets, supported)
+                .map(|(browser, version)| format!("{} {}", browser, version))
+                .collect::<Vec<_>>();
+
+            if !targets.is_empty() && missing.is_empty() {
+                continue;
+            }
+
+            if self.debug {
+                if targets.is_empty() {
+                    eprintln!("  {} (no targets)", feature.name());
+                } else {
+                    eprintln!("  {} {{ {} }}", feature.name(), missing.join(", "));
+                }
+            }
+            enabled.insert(feature);
+        }
+
+        Ok(Features { enabled })
+    }
+}
+
+/// Compat passes enabled by `features`.
+///
+/// Order of passes is same as `compat::es2018()` ... `compat::es3()`.
+pub fn compat(features: &Features, c: es2015::Config) -> impl Pass {
+    let f = |feature| features.contains(feature);
+
+    chain_at!(
+        Module,
+        // es2018
+        Optional::new(
+            es2018::object_rest_spread(),
+            f(Feature::ObjectRestSpread)
+        ),
+        Optional::new(
+            es2018::optional_catch_binding(),
+            f(Feature::OptionalCatchBinding)
+        ),
+        // es2017
+        Optional::new(
+            es2017::async_to_generator(),
+            f(Feature::AsyncToGenerator)
+        ),
+        // es2016
+        Optional::new(es2016::exponentation(), f(Feature::Exponentiation)),
+        // es2015
+        Optional::new(es2015::BlockScopedFns, f(Feature::BlockScopedFunctions)),
+        Optional::new(es2015::template_literal(c), f(Feature::TemplateLiterals)),
+        Optional::new(es2015::classes(c), f(Feature::Classes)),
+        Optional::new(es2015::spread(c), f(Feature::Spread)),
+        Optional::new(es2015::function_name(), f(Feature::FunctionName)),
+        chain_at!(
+            Expr,
+            Optional::new(es2015::arrow(), f(Feature::ArrowFunctions)),
+            Optional::new(es2015::duplicate_keys(), f(Feature::DuplicateKeys)),
+            Optional::new(es2015::StickyRegex, f(Feature::StickyRegex)),
+            Optional::new(es2015::InstanceOf, f(Feature::InstanceOf)),
+            Optional::new(es2015::TypeOfSymbol, f(Feature::TypeOfSymbol)),
+            Optional::new(es2015::Shorthand, f(Feature::ShorthandProperties)),
+        ),
+        Optional::new(es2015::parameters(), f(Feature::Parameters)),
+        Optional::new(es2015::for_of(c), f(Feature::ForOf)),
+        Optional::new(
+            es2015::computed_properties(),
+            f(Feature::ComputedProperties)
+        ),
+        Optional::new(es2015::destructuring(c), f(Feature::Destructuring)),
+        Optional::new(es2015::block_scoping(), f(Feature::BlockScoping)),
+        // es3
+        chain_at!(
+            Expr,
+            Optional::new(es3::PropertyLiteral, f(Feature::PropertyLiterals)),
+            Optional::new(es3::MemberExprLit, f(Feature::MemberExpressionLiterals)),
+            Optional::new(es3::ReservedWord, f(Feature::ReservedWords)),
+        ),
+    )
+}
+
+/// Injects imports of core-js modules if `jsc.env.mode` is set.
+pub fn polyfills(env: Option<&EnvConfig>) -> Result<impl Pass, Error> {
+    let mode = env.and_then(|env| env.mode);
+    let targets = match env.and_then(|env| env.targets.as_ref()) {
+        Some(targets) if mode.is_some() => targets.resolve()?,
+        _ => Default::default(),
+    };
+
+    Ok(Optional::new(
+        polyfills::polyfills(mode.unwrap_or(Mode::Usage), move |module| {
+            if targets.is_empty() {
+                return true;
+            }
+
+            match CORE_JS_DATA.get(module) {
+                Some(supported) => missing_targets(&targets, supported).next().is_some(),
+                None => true,
+            }
+        }),
+        mode.is_some(),
+    ))
+}
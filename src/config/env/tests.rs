@@ -0,0 +1,149 @@
+use super::{EnvConfig, Feature, Mode, Targets, Version};
+use crate::error::Error;
+use serde_json;
+
+fn try_features(targets: Targets) -> Result<Vec<Feature>, Error> {
+    let features = EnvConfig {
+        targets: Some(targets),
+        ..Default::default()
+    }
+    .features()?;
+
+    Ok(Feature::ALL
+        .iter()
+        .cloned()
+        .filter(|&f| features.contains(f))
+        .collect())
+}
+
+fn features(targets: Targets) -> Vec<Feature> {
+    try_features(targets).expect("failed to resolve targets")
+}
+
+fn error(targets: Targets) -> String {
+    match try_features(targets) {
+        Ok(features) => panic!("expected an error, got {:?}", features),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn version() {
+    assert!(Version::parse("10.1").unwrap() > Version::parse("10").unwrap());
+    assert!(Version::parse("6.5").unwrap() < Version::parse("6.10").unwrap());
+    assert_eq!(Version::parse("10.0-10.2"), Version::parse("10"));
+    assert_eq!(Version::parse("latest"), None);
+}
+
+#[test]
+fn no_targets() {
+    let features = EnvConfig::default().features().unwrap();
+
+    for &f in Feature::ALL {
+        assert!(features.contains(f), "{:?} should be enabled", f);
+    }
+}
+
+#[test]
+fn chrome_58() {
+    assert_eq!(
+        features(Targets::Query("chrome 58".into())),
+        vec![
+            Feature::ObjectRestSpread,
+            Feature::OptionalCatchBinding,
+            Feature::AsyncToGenerator,
+        ]
+    );
+}
+
+#[test]
+fn ios_10() {
+    let features = features(Targets::Versions(
+        vec![("chrome".into(), "58".into()), ("ios".into(), "10".into())]
+            .into_iter()
+            .collect(),
+    ));
+
+    assert!(features.contains(&Feature::Exponentiation));
+    assert!(features.contains(&Feature::BlockScoping));
+    assert!(!features.contains(&Feature::ArrowFunctions));
+    assert!(!features.contains(&Feature::Classes));
+}
+
+#[test]
+fn query_uses_lowest_version() {
+    assert_eq!(
+        features(Targets::Query("chrome >= 60, chrome 58".into())),
+        features(Targets::Query("chrome 58".into()))
+    );
+}
+
+#[test]
+fn ie_requires_everything() {
+    let features = features(Targets::Queries(vec!["ie 11".into()]));
+
+    assert!(features.contains(&Feature::ArrowFunctions));
+    assert!(features.contains(&Feature::Classes));
+    assert!(!features.contains(&Feature::BlockScopedFunctions));
+    assert!(!features.contains(&Feature::PropertyLiterals));
+}
+
+#[test]
+fn unknown_browser() {
+    assert_eq!(
+        error(Targets::Query("netscape 4".into())),
+        "jsc.env.targets: unknown browser `netscape`"
+    );
+}
+
+#[test]
+fn invalid_version() {
+    assert_eq!(
+        error(Targets::Versions(
+            vec![("chrome".into(), "latest".into())]
+                .into_iter()
+                .collect(),
+        )),
+        "jsc.env.targets: invalid version `latest` for chrome"
+    );
+}
+
+#[test]
+fn unsupported_queries() {
+    for q in &["defaults", "last 2 versions", "> 0.5%"] {
+        let err = error(Targets::Query(q.to_string()));
+        assert!(
+            err.starts_with(&format!("jsc.env.targets: unsupported query `{}`", q)),
+            "{}",
+            err
+        );
+    }
+}
+
+#[test]
+fn unsupported_query_message() {
+    assert_eq!(
+        error(Targets::Query("defaults".into())),
+        "jsc.env.targets: unsupported query `defaults`. Only `<browser> <version>` and \
+         `<browser> >= <version>` are supported"
+    );
+}
+
+#[test]
+fn supported_queries() {
+    let targets = Targets::Queries(vec![
+        "ff 60, ios_saf >= 10".into(),
+        "chrome 58 or ie 11".into(),
+    ]);
+    assert!(try_features(targets).is_ok());
+}
+
+#[test]
+fn polyfill_mode() {
+    let env: EnvConfig = serde_json::from_str(
+        r#"{ "targets": "chrome 58", "mode": "usage" }"#,
+    )
+    .expect("failed to parse");
+
+    assert_eq!(env.mode, Some(Mode::Usage));
+}
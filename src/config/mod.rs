@@ -1,12 +1,14 @@
+pub use self::env::EnvConfig;
 use crate::{builder::PassBuilder, error::Error};
 use atoms::JsWord;
 use chashmap::CHashMap;
 use common::{errors::Handler, FileName, SourceMap};
 use ecmascript::{
     ast::{Expr, Module, ModuleItem, Stmt},
+    codegen, lints,
     parser::{Parser, Session as ParseSess, SourceFileInput, Syntax},
     transforms::{
-        chain_at, const_modules, modules,
+        chain_at, const_modules, flow, modules,
         pass::{noop, Optional, Pass},
         proposals::{class_properties, decorators, export},
         react, resolver, simplifier, typescript, InlineGlobals,
@@ -22,6 +24,7 @@ use std::{
     sync::Arc,
 };
 
+pub mod env;
 #[cfg(test)]
 mod tests;
 
@@ -112,7 +115,7 @@ impl Options {
         cm: &Arc<SourceMap>,
         handler: &Handler,
         config: Option<Config>,
-    ) -> BuiltConfig<impl Pass> {
+    ) -> Result<BuiltConfig<impl Pass>, Error> {
         let mut config = config.unwrap_or_else(|| Default::default());
         if let Some(ref c) = self.config {
             config.merge(c)
@@ -123,10 +126,19 @@ impl Options {
             syntax,
             external_helpers,
             target,
+            env,
+            loose,
         } = config.jsc;
 
         let syntax = syntax.unwrap_or_default();
         let transform = transform.unwrap_or_default();
+        if let Some(ref module) = config.module {
+            module.validate()?;
+        }
+        config
+            .lints
+            .validate(&lints::Registry::default())
+            .map_err(|msg| Error::InvalidLints { msg })?;
 
         let const_modules = {
             let enabled = transform.const_modules.is_some();
@@ -151,12 +163,19 @@ impl Options {
             Module,
             // handle jsx
             Optional::new(react::react(cm.clone(), transform.react), syntax.jsx()),
-            Optional::new(typescript::strip(), syntax.typescript()),
+            Optional::new(typescript::strip(), syntax.typescript() && !syntax.flow()),
+            Optional::new(flow::strip(), syntax.flow()),
             resolver(),
+            env::polyfills(env.as_ref())?,
             const_modules,
             pass,
             Optional::new(decorators(), syntax.decorators()),
-            Optional::new(class_properties(), syntax.class_props()),
+            Optional::new(
+                class_properties(transform.class_properties),
+                syntax.class_props()
+                    || syntax.class_private_props()
+                    || syntax.class_private_methods()
+            ),
             Optional::new(
                 export(),
                 syntax.export_default_from() || syntax.export_namespace_from()
@@ -164,15 +183,22 @@ impl Options {
             Optional::new(simplifier(), enable_optimizer),
         );
 
-        let pass = PassBuilder::new(&cm, &handler, pass)
+        let mut pass = PassBuilder::new(&cm, &handler, pass)
             .target(target)
-            .finalize(config.module);
+            .loose(loose);
+        if let Some(env) = env {
+            pass = pass.env(env.features()?);
+        }
+        let pass = pass.finalize(config.module);
 
-        BuiltConfig {
+        Ok(BuiltConfig {
             minify: config.minify.unwrap_or(false),
+            format: config.format,
+            is_module: config.is_module.unwrap_or_default(),
             pass,
             external_helpers,
             syntax,
+            lints: config.lints,
             source_maps: self
                 .source_maps
                 .as_ref()
@@ -182,7 +208,7 @@ impl Options {
                     SourceMapsConfig::Str(_) => true,
                 })
                 .unwrap_or(false),
-        }
+        })
     }
 }
 
@@ -297,6 +323,19 @@ pub struct Config {
 
     #[serde(default)]
     pub minify: Option<bool>,
+
+    /// Formatting of the generated code.
+    #[serde(default)]
+    pub format: FormatConfig,
+
+    /// Parses files as modules, scripts, or based on the presence of `import`
+    /// and `export` if `"unknown"`. Defaults to `true`.
+    #[serde(default)]
+    pub is_module: Option<IsModule>,
+
+    /// Severity of lint rules, used by `Compiler::lint`.
+    #[serde(default)]
+    pub lints: lints::Config,
 }
 
 impl Config {
@@ -321,13 +360,124 @@ impl Config {
     }
 }
 
+/// `isModule` of `.swcrc`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum IsModule {
+    Bool(bool),
+    Unknown(Unknown),
+}
+
+impl Default for IsModule {
+    fn default() -> Self {
+        IsModule::Bool(true)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Unknown {
+    #[serde(rename = "unknown")]
+    Unknown,
+}
+
+/// `format` of `.swcrc`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FormatConfig {
+    /// String used for each level of indentation. Defaults to four spaces.
+    #[serde(default)]
+    pub indent: Option<String>,
+
+    /// Defaults to `"single"`.
+    #[serde(default)]
+    pub quote_style: Option<QuoteStyle>,
+
+    /// Adds trailing commas to multi-line array and object literals.
+    #[serde(default)]
+    pub trailing_comma: Option<bool>,
+
+    /// If false, semicolons are omitted at the end of lines where it's safe.
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub semicolons: Option<bool>,
+
+    /// Breaks argument lists which don't fit in this width.
+    #[serde(default)]
+    pub line_width: Option<usize>,
+
+    /// Escapes non-ascii characters.
+    #[serde(default)]
+    pub ascii_only: Option<bool>,
+
+    /// Comments to keep. Defaults to `"some"` if minifying and `"all"`
+    /// otherwise.
+    #[serde(default)]
+    pub comments: Option<CommentRetention>,
+}
+
+impl FormatConfig {
+    pub fn indent_str(&self) -> &str {
+        self.indent.as_ref().map(|s| &**s).unwrap_or("    ")
+    }
+
+    pub fn codegen_config(&self, minify: bool) -> codegen::Config {
+        codegen::Config {
+            minify,
+            quote_style: match self.quote_style {
+                Some(QuoteStyle::Double) => codegen::QuoteStyle::Double,
+                Some(QuoteStyle::Single) | None => codegen::QuoteStyle::Single,
+            },
+            trailing_comma: self.trailing_comma.unwrap_or(false),
+            line_width: self.line_width,
+            ascii_only: self.ascii_only.unwrap_or(false),
+            comment_retention: match self.comments(minify) {
+                CommentRetention::All => codegen::CommentRetention::All,
+                CommentRetention::Some => codegen::CommentRetention::Some,
+                CommentRetention::None => codegen::CommentRetention::None,
+            },
+        }
+    }
+
+    pub fn comments(&self, minify: bool) -> CommentRetention {
+        match self.comments {
+            Some(comments) => comments,
+            None if minify => CommentRetention::Some,
+            None => CommentRetention::All,
+        }
+    }
+}
+
+/// `format.comments` of `.swcrc`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CommentRetention {
+    #[serde(rename = "all")]
+    All,
+    /// Block comments starting with `/*!` and comments containing `@license`,
+    /// `@preserve` or `@cc_on`.
+    #[serde(rename = "some")]
+    Some,
+    #[serde(rename = "none")]
+    None,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum QuoteStyle {
+    #[serde(rename = "single")]
+    Single,
+    #[serde(rename = "double")]
+    Double,
+}
+
 /// One `BuiltConfig` per a directory with swcrc
 pub struct BuiltConfig<P: Pass> {
     pub pass: P,
     pub syntax: Syntax,
+    pub is_module: IsModule,
     pub minify: bool,
+    pub format: FormatConfig,
     pub external_helpers: bool,
     pub source_maps: bool,
+    pub lints: lints::Config,
 }
 
 #[derive(Default, Clone, Serialize, Deserialize)]
@@ -344,6 +494,16 @@ pub struct JscConfig {
 
     #[serde(default)]
     pub target: JscTarget,
+
+    /// If set, compat passes are selected based on `env.targets` and
+    /// `target` is ignored.
+    #[serde(default)]
+    pub env: Option<EnvConfig>,
+
+    /// Makes compat passes generate smaller code which does not follow the
+    /// spec strictly.
+    #[serde(default)]
+    pub loose: bool,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord, PartialEq, Eq)]
@@ -380,15 +540,32 @@ pub enum ModuleConfig {
     Umd(modules::umd::Config),
     #[serde(rename = "amd")]
     Amd(modules::amd::Config),
+    #[serde(rename = "es6")]
+    Es6(modules::es6::Config),
 }
 
 impl ModuleConfig {
+    /// Returns an error if an option is invalid.
+    pub fn validate(&self) -> Result<(), Error> {
+        let config = match *self {
+            ModuleConfig::CommonJs(ref config) => config,
+            ModuleConfig::Umd(ref config) => &config.config,
+            ModuleConfig::Amd(ref config) => &config.config,
+            ModuleConfig::Es6(..) => return Ok(()),
+        };
+
+        config
+            .validate()
+            .map_err(|msg| Error::InvalidModuleConfig { msg })
+    }
+
     pub fn build(cm: Arc<SourceMap>, config: Option<ModuleConfig>) -> Box<dyn Pass> {
         match config {
             None => box noop(),
             Some(ModuleConfig::CommonJs(config)) => box modules::common_js::common_js(config),
             Some(ModuleConfig::Umd(config)) => box modules::umd::umd(cm, config),
             Some(ModuleConfig::Amd(config)) => box modules::amd::amd(config),
+            Some(ModuleConfig::Es6(config)) => box modules::es6::es6(cm, config),
         }
     }
 }
@@ -404,6 +581,9 @@ pub struct TransformConfig {
 
     #[serde(default)]
     pub optimizer: Option<OptimizerConfig>,
+
+    #[serde(default)]
+    pub class_properties: class_properties::Config,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -534,7 +714,10 @@ impl Merge for Config {
     fn merge(&mut self, from: &Self) {
         self.jsc.merge(&from.jsc);
         self.module.merge(&from.module);
-        self.minify.merge(&from.minify)
+        self.minify.merge(&from.minify);
+        self.format.merge(&from.format);
+        self.is_module.merge(&from.is_module);
+        self.lints.merge(&from.lints);
     }
 }
 
@@ -544,6 +727,8 @@ impl Merge for JscConfig {
         self.transform.merge(&from.transform);
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
+        self.env.merge(&from.env);
+        self.loose.merge(&from.loose);
     }
 }
 
@@ -570,6 +755,12 @@ impl Merge for bool {
     }
 }
 
+impl Merge for IsModule {
+    fn merge(&mut self, from: &Self) {
+        *self = *from;
+    }
+}
+
 impl Merge for Syntax {
     fn merge(&mut self, from: &Self) {
         *self = *from;
@@ -581,6 +772,7 @@ impl Merge for TransformConfig {
         self.optimizer.merge(&from.optimizer);
         self.const_modules.merge(&from.const_modules);
         self.react.merge(&from.react);
+        self.class_properties.merge(&from.class_properties);
     }
 }
 
@@ -596,12 +788,60 @@ impl Merge for GlobalPassOption {
     }
 }
 
+impl Merge for EnvConfig {
+    fn merge(&mut self, from: &Self) {
+        *self = from.clone();
+    }
+}
+
 impl Merge for react::Options {
     fn merge(&mut self, from: &Self) {
         *self = from.clone();
     }
 }
 
+impl Merge for class_properties::Config {
+    fn merge(&mut self, from: &Self) {
+        self.set_public_class_fields.merge(&from.set_public_class_fields);
+    }
+}
+
+impl Merge for lints::Config {
+    fn merge(&mut self, from: &Self) {
+        self.env.extend(from.env.iter().cloned());
+        self.globals.extend(from.globals.iter().cloned());
+        for (rule, severity) in &from.rules {
+            self.rules.insert(rule.clone(), *severity);
+        }
+    }
+}
+
+impl Merge for FormatConfig {
+    fn merge(&mut self, from: &Self) {
+        if from.indent.is_some() {
+            self.indent = from.indent.clone();
+        }
+        if from.quote_style.is_some() {
+            self.quote_style = from.quote_style;
+        }
+        if from.trailing_comma.is_some() {
+            self.trailing_comma = from.trailing_comma;
+        }
+        if from.semicolons.is_some() {
+            self.semicolons = from.semicolons;
+        }
+        if from.line_width.is_some() {
+            self.line_width = from.line_width;
+        }
+        if from.ascii_only.is_some() {
+            self.ascii_only = from.ascii_only;
+        }
+        if from.comments.is_some() {
+            self.comments = from.comments;
+        }
+    }
+}
+
 impl Merge for ConstModulesConfig {
     fn merge(&mut self, from: &Self) {
         *self = from.clone()
@@ -1,5 +1,26 @@
-use super::Rc;
+use super::{IsModule, Options, Rc, Unknown};
+use crate::Compiler;
+use common::{
+    errors::{ColorConfig, Handler},
+    FileName, FilePathMapping, SourceMap,
+};
 use serde_json;
+use std::sync::Arc;
+
+fn compiler() -> Compiler {
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm.clone()));
+    Compiler::new(cm, handler)
+}
+
+/// Compiles `src` with `options`, which is parsed as json.
+fn compile(src: &str, options: &str) -> String {
+    let c = compiler();
+    let opts: Options = serde_json::from_str(options).expect("failed to parse options");
+    let fm = c.cm.new_source_file(FileName::Anon, src.into());
+
+    c.process_js_file(fm, opts).expect("failed to compile").code
+}
 
 #[test]
 fn object() {
@@ -10,3 +31,146 @@ fn object() {
 fn array() {
     let _: Rc = serde_json::from_str(include_str!("array.json")).expect("failed to parse");
 }
+
+#[test]
+fn env_targets() {
+    let _: Rc = serde_json::from_str(
+        r#"{ "jsc": { "env": { "targets": { "chrome": "58", "ios": "10" }, "debug": true } } }"#,
+    )
+    .expect("failed to parse");
+
+    let _: Rc = serde_json::from_str(r#"{ "jsc": { "env": { "targets": "chrome >= 58" } } }"#)
+        .expect("failed to parse");
+}
+
+#[test]
+fn set_public_class_fields() {
+    let _: Rc = serde_json::from_str(
+        r#"{ "jsc": { "transform": { "classProperties": { "setPublicClassFields": true } } } }"#,
+    )
+    .expect("failed to parse");
+}
+
+#[test]
+fn lints() {
+    let _: Rc = serde_json::from_str(r#"{ "lints": { "no-debugger": "error", "no-with": "off" } }"#)
+        .expect("failed to parse");
+}
+
+#[test]
+fn lints_unknown_rule() {
+    let rc: Rc = serde_json::from_str(r#"{ "lints": { "no-debuger": "error", "no-with": "off" } }"#)
+        .expect("failed to parse");
+    let config = rc.into_config(None).unwrap();
+
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm.clone()));
+    let err = match Options::default().build(&cm, &handler, Some(config)) {
+        Ok(..) => panic!("unknown lint rules should be rejected"),
+        Err(err) => err.to_string(),
+    };
+    assert_eq!(err, "invalid lints config: unknown lint rules: `no-debuger`");
+}
+
+#[test]
+fn lints_env() {
+    let _: Rc = serde_json::from_str(
+        r#"{ "lints": { "env": ["browser", "es2017"], "globals": ["$"], "no-undef": "error" } }"#,
+    )
+    .expect("failed to parse");
+}
+
+#[test]
+fn is_module() {
+    let rc: Rc = serde_json::from_str(r#"{ "isModule": "unknown" }"#).expect("failed to parse");
+    let config = rc.into_config(None).unwrap();
+    assert_eq!(config.is_module, Some(IsModule::Unknown(Unknown::Unknown)));
+
+    let rc: Rc = serde_json::from_str(r#"{ "isModule": false }"#).expect("failed to parse");
+    let config = rc.into_config(None).unwrap();
+    assert_eq!(config.is_module, Some(IsModule::Bool(false)));
+
+    serde_json::from_str::<Rc>(r#"{ "isModule": "script" }"#)
+        .err()
+        .expect("should fail to parse");
+}
+
+#[test]
+fn top_level_await_wrap_is_lowered() {
+    let code = compile(
+        "const foo = await bar(); console.log(foo);",
+        r#"{
+            "swcrc": false,
+            "jsc": { "parser": { "syntax": "ecmascript", "topLevelAwait": true }, "target": "es5" },
+            "module": { "type": "commonjs", "topLevelAwait": "wrap" }
+        }"#,
+    );
+
+    assert!(!code.contains("async function"), "{}", code);
+    assert!(!code.contains("await "), "{}", code);
+    assert!(code.contains("exports.__tla = _tla"), "{}", code);
+}
+
+#[test]
+fn lint_script() {
+    let c = compiler();
+    let fm = c.cm.new_source_file(FileName::Anon, "var obj; with (obj) {}".into());
+
+    for is_module in &["false", r#""unknown""#] {
+        let opts: Options = serde_json::from_str(&format!(
+            r#"{{ "swcrc": false, "isModule": {} }}"#,
+            is_module
+        ))
+        .expect("failed to parse options");
+
+        let results = c.lint(fm.clone(), &opts).expect("should be parsed as a script");
+        assert!(results.iter().any(|r| r.rule == "no-with"), "{:?}", results);
+    }
+}
+
+#[test]
+fn fix_script() {
+    let c = compiler();
+    let fm = c.cm.new_source_file(FileName::Anon, "with (obj) { a == null; }".into());
+    let opts: Options = serde_json::from_str(r#"{ "swcrc": false, "isModule": false }"#)
+        .expect("failed to parse options");
+
+    c.fix(fm, &opts).expect("should be parsed as a script");
+}
+
+#[test]
+fn script() {
+    let code = compile(
+        "@dec class A {} new Promise(function () {});",
+        r#"{
+            "swcrc": false,
+            "isModule": false,
+            "jsc": {
+                "parser": { "syntax": "ecmascript", "decorators": true },
+                "transform": { "constModules": { "globals": { "flags": { "a": "true" } } } },
+                "env": { "mode": "usage", "targets": { "ie": "11" } }
+            }
+        }"#,
+    );
+
+    assert!(code.contains("require('core-js/modules/es.promise')"), "{}", code);
+    assert!(!code.contains("import "), "{}", code);
+    assert!(!code.contains("@dec"), "{}", code);
+}
+
+#[test]
+fn react_constant_elements() {
+    let code = compile(
+        r#"function render() { return <div className="foo" />; }"#,
+        r#"{
+            "swcrc": false,
+            "jsc": {
+                "parser": { "syntax": "ecmascript", "jsx": true },
+                "transform": { "react": { "constantElements": true } }
+            }
+        }"#,
+    );
+
+    assert!(code.contains("var _ref = React.createElement("), "{}", code);
+    assert!(code.contains("return _ref;"), "{}", code);
+}
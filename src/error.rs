@@ -31,6 +31,15 @@ pub enum Error {
     #[fail(display = "invalid regexp: {}", err)]
     InvalidRegex { err: regex::Error },
 
+    #[fail(display = "jsc.env.targets: {}", msg)]
+    InvalidTargets { msg: String },
+
+    #[fail(display = "invalid module config: {}", msg)]
+    InvalidModuleConfig { msg: String },
+
+    #[fail(display = "invalid lints config: {}", msg)]
+    InvalidLints { msg: String },
+
     /* #[fail(display = "generated code is not utf8: {}", err)]
      * GeneratedCodeNotUtf8 { err: FromUtf8Error }, */
     /// This means `test` field in .swcrc file did not matched the compiling
@@ -11,7 +11,10 @@ pub mod error;
 
 pub use crate::builder::PassBuilder;
 use crate::{
-    config::{BuiltConfig, ConfigFile, Merge, Options, Rc, RootMode},
+    config::{
+        BuiltConfig, CommentRetention, ConfigFile, FormatConfig, IsModule, Merge, Options, Rc,
+        RootMode,
+    },
     error::Error,
 };
 use common::{
@@ -19,8 +22,9 @@ use common::{
     GLOBALS,
 };
 use ecmascript::{
-    ast::Module,
+    ast::Program,
     codegen::{self, Emitter},
+    lints::{self, Fixed, LintResult, Registry},
     parser::{Parser, Session as ParseSess, Syntax},
     transforms::{
         helpers::{self, Helpers},
@@ -65,33 +69,43 @@ impl Compiler {
     }
 
     /// This method parses a javascript / typescript file
+    ///
+    /// If `is_module` is `"unknown"`, the file is parsed as a module only if
+    /// it's not a valid script.
     pub fn parse_js(
         &self,
         fm: Arc<SourceFile>,
         syntax: Syntax,
+        is_module: IsModule,
         comments: Option<&Comments>,
-    ) -> Result<Module, Error> {
+    ) -> Result<Program, Error> {
         self.run(|| {
             let session = ParseSess {
                 handler: &self.handler,
             };
             let mut parser = Parser::new(session, syntax, SourceFileInput::from(&*fm), comments);
-            let module = parser.parse_module().map_err(|mut e| {
+            let program = match is_module {
+                IsModule::Bool(true) => parser.parse_module().map(Program::Module),
+                IsModule::Bool(false) => parser.parse_script().map(Program::Script),
+                IsModule::Unknown(..) => parser.parse_program(),
+            }
+            .map_err(|mut e| {
                 e.emit();
                 Error::FailedToParseModule {}
             })?;
 
-            Ok(module)
+            Ok(program)
         })
     }
 
     pub fn print(
         &self,
-        module: &Module,
+        program: &Program,
         fm: Arc<SourceFile>,
         comments: &Comments,
         source_map: bool,
         minify: bool,
+        format: &FormatConfig,
     ) -> Result<TransformOutput, Error> {
         self.run(|| {
             let mut src_map_builder = SourceMapBuilder::new(None);
@@ -108,26 +122,35 @@ impl Compiler {
                 let mut buf = vec![];
                 {
                     let handlers = box MyHandlers;
+                    let wr = codegen::text_writer::JsWriter::new(
+                        self.cm.clone(),
+                        "\n",
+                        &mut buf,
+                        if source_map {
+                            Some(&mut src_map_builder)
+                        } else {
+                            None
+                        },
+                    )
+                    .with_indent_str(format.indent_str());
                     let mut emitter = Emitter {
-                        cfg: codegen::Config { minify },
-                        comments: if minify { None } else { Some(&comments) },
+                        cfg: format.codegen_config(minify),
+                        comments: match format.comments(minify) {
+                            CommentRetention::None => None,
+                            _ => Some(&comments),
+                        },
                         cm: self.cm.clone(),
-                        wr: box codegen::text_writer::JsWriter::new(
-                            self.cm.clone(),
-                            "\n",
-                            &mut buf,
-                            if source_map {
-                                Some(&mut src_map_builder)
-                            } else {
-                                None
-                            },
-                        ),
+                        wr: if format.semicolons.unwrap_or(true) {
+                            box wr
+                        } else {
+                            box codegen::text_writer::omit_semi(wr)
+                        },
                         handlers,
                         pos_of_leading_comments: Default::default(),
                     };
 
                     emitter
-                        .emit_module(&module)
+                        .emit_program(&program)
                         .map_err(|err| Error::FailedToEmitModule { err })?;
                 }
                 // Invalid utf8 is valid in javascript world.
@@ -154,6 +177,8 @@ impl Compiler {
 
 /// High-level apis.
 impl Compiler {
+    /// `handler` decides how errors are reported. Use
+    /// `Handler::with_json_emitter` for machine-readable errors.
     pub fn new(cm: Arc<SourceMap>, handler: Handler) -> Self {
         Compiler {
             cm,
@@ -206,7 +231,7 @@ impl Compiler {
                             if let Some(config_file) = config_file {
                                 config.merge(&config_file.into_config(Some(path))?)
                             }
-                            let built = opts.build(&self.cm, &self.handler, Some(config));
+                            let built = opts.build(&self.cm, &self.handler, Some(config))?;
                             return Ok(built);
                         }
 
@@ -222,7 +247,7 @@ impl Compiler {
                         &self.cm,
                         &self.handler,
                         Some(config_file.into_config(Some(path))?),
-                    );
+                    )?;
                     return Ok(built);
                 }
             }
@@ -236,7 +261,7 @@ impl Compiler {
                 Some(config_file) => Some(config_file.into_config(None)?),
                 None => None,
             },
-        );
+        )?;
         Ok(built)
     }
 
@@ -250,6 +275,63 @@ impl Compiler {
         self.process_js(fm, config)
     }
 
+    /// Runs lint rules configured by `lints` in `.swcrc` without
+    /// transforming the file.
+    pub fn lint(&self, fm: Arc<SourceFile>, opts: &Options) -> Result<Vec<LintResult>, Error> {
+        self.run(|| {
+            let config = self.config_for_file(opts, &*fm)?;
+
+            let comments = Default::default();
+            let program = match config.is_module {
+                // Syntax errors are emitted, and lint rules are applied to the
+                // remaining part of the module.
+                IsModule::Bool(true) => Program::Module(
+                    Parser::new(
+                        ParseSess {
+                            handler: &self.handler,
+                        },
+                        config.syntax,
+                        SourceFileInput::from(&*fm),
+                        Some(&comments),
+                    )
+                    .parse_module_recovering(),
+                ),
+                is_module => self.parse_js(fm.clone(), config.syntax, is_module, Some(&comments))?,
+            };
+
+            Ok(lints::lint_program(
+                &self.cm,
+                &comments,
+                &Registry::default(),
+                &config.lints,
+                &program,
+            ))
+        })
+    }
+
+    /// Applies fixes of lint rules until no fix is applicable.
+    pub fn fix(&self, fm: Arc<SourceFile>, opts: &Options) -> Result<Fixed, Error> {
+        self.run(|| {
+            let config = self.config_for_file(opts, &*fm)?;
+
+            let is_module = match config.is_module {
+                IsModule::Bool(b) => Some(b),
+                IsModule::Unknown(..) => None,
+            };
+
+            lints::fix(
+                &self.cm,
+                &self.handler,
+                config.syntax,
+                is_module,
+                &Registry::default(),
+                &config.lints,
+                fm,
+            )
+            .map_err(|()| Error::FailedToParseModule {})
+        })
+    }
+
     /// You can use custom pass with this method.
     ///
     /// There exists a [PassBuilder] to help building custom passes.
@@ -263,21 +345,29 @@ impl Compiler {
                 eprintln!("processing js file: {:?}", fm)
             }
 
+            // Comments are collected even if minifying, because transforms like
+            // jsx read pragma comments.
             let comments = Default::default();
-            let module = self.parse_js(
-                fm.clone(),
-                config.syntax,
-                if config.minify { None } else { Some(&comments) },
-            )?;
+            let program =
+                self.parse_js(fm.clone(), config.syntax, config.is_module, Some(&comments))?;
             let mut pass = config.pass;
-            let module = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
+            let program = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                 util::HANDLER.set(&self.handler, || {
-                    // Fold module
-                    module.fold_with(&mut pass)
+                    util::COMMENTS.set(&comments, || {
+                        // Fold program
+                        program.fold_with(&mut pass)
+                    })
                 })
             });
 
-            self.print(&module, fm, &comments, config.source_maps, config.minify)
+            self.print(
+                &program,
+                fm,
+                &comments,
+                config.source_maps,
+                config.minify,
+                &config.format,
+            )
         })
     }
 }